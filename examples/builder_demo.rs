@@ -293,7 +293,9 @@ impl SpliceCommandExt for scte35::types::SpliceCommand {
     fn get_event_id(&self) -> Option<u32> {
         match self {
             scte35::types::SpliceCommand::SpliceInsert(si) => Some(si.splice_event_id),
-            scte35::types::SpliceCommand::SpliceSchedule(ss) => Some(ss.splice_event_id),
+            scte35::types::SpliceCommand::SpliceSchedule(ss) => {
+                ss.events.first().map(|event| event.splice_event_id)
+            }
             _ => None,
         }
     }
@@ -3,6 +3,7 @@
 //! This module provides the `BitReader` struct which enables reading arbitrary
 //! numbers of bits from a byte buffer, as required by the SCTE-35 specification.
 
+use crate::parser::TracedField;
 use std::io::{self, ErrorKind};
 
 /// A reader that can extract values at the bit level from a byte buffer.
@@ -10,9 +11,20 @@ use std::io::{self, ErrorKind};
 /// SCTE-35 messages contain fields that are not byte-aligned, requiring
 /// bit-level parsing. This reader maintains a bit offset and provides
 /// methods to read various bit-width values.
+///
+/// The named read methods can optionally record every field they read (see
+/// [`BitReader::with_trace`]), which powers
+/// [`trace_splice_info_section`](crate::parser::trace_splice_info_section).
 pub(crate) struct BitReader<'a> {
     buffer: &'a [u8],
     offset: usize,
+    trace: Option<Trace>,
+}
+
+/// Fields recorded so far, plus the indices of the groups still open.
+struct Trace {
+    fields: Vec<TracedField>,
+    open_groups: Vec<usize>,
 }
 
 impl<'a> BitReader<'a> {
@@ -20,7 +32,23 @@ impl<'a> BitReader<'a> {
     ///
     /// The reader starts at bit offset 0.
     pub fn new(buffer: &'a [u8]) -> Self {
-        BitReader { buffer, offset: 0 }
+        BitReader {
+            buffer,
+            offset: 0,
+            trace: None,
+        }
+    }
+
+    /// Creates a `BitReader` that records every named field it reads.
+    pub fn with_trace(buffer: &'a [u8]) -> Self {
+        BitReader {
+            buffer,
+            offset: 0,
+            trace: Some(Trace {
+                fields: Vec::new(),
+                open_groups: Vec::new(),
+            }),
+        }
     }
 
     /// Reads a specified number of bits from the buffer.
@@ -66,15 +94,15 @@ impl<'a> BitReader<'a> {
     /// Reads an unsigned integer with a specified number of bits (MSB first).
     ///
     /// UIMSBF: Unsigned Integer, Most Significant Bit First.
-    pub fn read_uimsbf(&mut self, num_bits: usize) -> Result<u64, io::Error> {
-        self.read_bits(num_bits)
+    pub fn read_uimsbf(&mut self, name: &'static str, num_bits: usize) -> Result<u64, io::Error> {
+        self.read_field(name, num_bits)
     }
 
     /// Reads a bit string with a specified number of bits (MSB first).
     ///
     /// BSLBF: Bit String, Left Bit First.
-    pub fn read_bslbf(&mut self, num_bits: usize) -> Result<u64, io::Error> {
-        self.read_bits(num_bits)
+    pub fn read_bslbf(&mut self, name: &'static str, num_bits: usize) -> Result<u64, io::Error> {
+        self.read_field(name, num_bits)
     }
 
     /// Reads a reserved field with a specified number of bits.
@@ -82,8 +110,18 @@ impl<'a> BitReader<'a> {
     /// RPCHOF: Reserved for future use, set to '1'.
     /// Note: RPCHOF typically implies LSB first within the byte, but SCTE-35 spec
     /// doesn't explicitly state this. Assuming standard MSB first based on other fields.
-    pub fn read_rpchof(&mut self, num_bits: usize) -> Result<u64, io::Error> {
-        self.read_bits(num_bits)
+    pub fn read_rpchof(&mut self, name: &'static str, num_bits: usize) -> Result<u64, io::Error> {
+        self.read_field(name, num_bits)
+    }
+
+    /// Reads `count` whole bytes as a single field.
+    pub fn read_bytes(&mut self, name: &'static str, count: usize) -> Result<Vec<u8>, io::Error> {
+        let start = self.offset;
+        let bytes = (0..count)
+            .map(|_| self.read_bits(8).map(|byte| byte as u8))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.record(name, start, count * 8, None);
+        Ok(bytes)
     }
 
     /// Skips a specified number of bits in the buffer.
@@ -93,7 +131,7 @@ impl<'a> BitReader<'a> {
     /// # Errors
     ///
     /// Returns an error if skipping would exceed the buffer bounds.
-    pub fn skip_bits(&mut self, num_bits: usize) -> Result<(), io::Error> {
+    pub fn skip_bits(&mut self, name: &'static str, num_bits: usize) -> Result<(), io::Error> {
         let new_offset = self.offset + num_bits;
         if new_offset / 8 > self.buffer.len() {
             return Err(io::Error::new(
@@ -101,6 +139,7 @@ impl<'a> BitReader<'a> {
                 "Buffer underflow while skipping bits",
             ));
         }
+        self.record(name, self.offset, num_bits, None);
         self.offset = new_offset;
         Ok(())
     }
@@ -109,6 +148,71 @@ impl<'a> BitReader<'a> {
     pub fn get_offset(&self) -> usize {
         self.offset
     }
+
+    /// Opens a group of fields, such as `splice_insert()`, in the trace.
+    pub fn begin(&mut self, name: &'static str) {
+        let offset = self.offset;
+        if let Some(trace) = &mut self.trace {
+            trace.open_groups.push(trace.fields.len());
+            trace.fields.push(TracedField {
+                name,
+                depth: trace.open_groups.len() - 1,
+                bit_offset: offset,
+                bit_width: 0,
+                value: None,
+                group: true,
+            });
+        }
+    }
+
+    /// Closes the innermost group opened with [`begin`](Self::begin).
+    pub fn end(&mut self) {
+        let offset = self.offset;
+        if let Some(trace) = &mut self.trace
+            && let Some(index) = trace.open_groups.pop()
+        {
+            let group = &mut trace.fields[index];
+            group.bit_width = offset - group.bit_offset;
+        }
+    }
+
+    /// Returns the recorded fields, closing any groups left open by a parse error.
+    pub fn into_trace(mut self) -> Vec<TracedField> {
+        while self
+            .trace
+            .as_ref()
+            .is_some_and(|trace| !trace.open_groups.is_empty())
+        {
+            self.end();
+        }
+        self.trace.map(|trace| trace.fields).unwrap_or_default()
+    }
+
+    fn read_field(&mut self, name: &'static str, num_bits: usize) -> Result<u64, io::Error> {
+        let start = self.offset;
+        let value = self.read_bits(num_bits)?;
+        self.record(name, start, num_bits, Some(value));
+        Ok(value)
+    }
+
+    fn record(
+        &mut self,
+        name: &'static str,
+        bit_offset: usize,
+        bit_width: usize,
+        value: Option<u64>,
+    ) {
+        if let Some(trace) = &mut self.trace {
+            trace.fields.push(TracedField {
+                name,
+                depth: trace.open_groups.len(),
+                bit_offset,
+                bit_width,
+                value,
+                group: false,
+            });
+        }
+    }
 }
 
 #[cfg(test)]
@@ -148,7 +252,7 @@ mod tests {
         let mut reader = BitReader::new(&buffer);
 
         // Skip 4 bits
-        reader.skip_bits(4).unwrap();
+        reader.skip_bits("skipped", 4).unwrap();
 
         // Read 4 bits: should get 0b1010 = 10
         assert_eq!(reader.read_bits(4).unwrap(), 10);
@@ -162,4 +266,49 @@ mod tests {
         // Try to read more bits than available
         assert!(reader.read_bits(16).is_err());
     }
+
+    #[test]
+    fn test_bit_reader_trace() {
+        let buffer = vec![0b1010_1010, 0b1111_0000, 0x01, 0x02];
+        let mut reader = BitReader::with_trace(&buffer);
+
+        reader.begin("outer()");
+        reader.read_bslbf("flag", 1).unwrap();
+        reader.read_uimsbf("value", 7).unwrap();
+        reader.begin("inner()");
+        reader.read_rpchof("reserved", 8).unwrap();
+        reader.read_bytes("data", 2).unwrap();
+        // Left open: into_trace closes it
+
+        let trace = reader.into_trace();
+        let summary: Vec<_> = trace
+            .iter()
+            .map(|field| {
+                (
+                    field.name,
+                    field.depth,
+                    field.bit_offset,
+                    field.bit_width,
+                    field.value,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("outer()", 0, 0, 32, None),
+                ("flag", 1, 0, 1, Some(1)),
+                ("value", 1, 1, 7, Some(0b010_1010)),
+                ("inner()", 1, 8, 24, None),
+                ("reserved", 2, 8, 8, Some(0b1111_0000)),
+                ("data", 2, 16, 16, None),
+            ]
+        );
+        assert!(trace[0].group && !trace[1].group);
+
+        // Untraced readers record nothing
+        let mut reader = BitReader::new(&buffer);
+        reader.read_bslbf("flag", 1).unwrap();
+        assert!(reader.into_trace().is_empty());
+    }
 }
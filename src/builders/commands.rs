@@ -1,9 +1,13 @@
 //! Builders for SCTE-35 splice commands.
 
 use super::error::{BuilderError, BuilderResult, DurationExt};
-use crate::time::{BreakDuration, SpliceTime};
-use crate::types::{SpliceInsert, SpliceInsertComponent, TimeSignal};
-use std::time::Duration;
+use crate::registry::PrivateCommandPayload;
+use crate::time::{BreakDuration, LeapSeconds, SpliceTime};
+use crate::types::{
+    ComponentSplice, PrivateCommand, SpliceInsert, SpliceInsertComponent, SpliceSchedule,
+    SpliceScheduleEvent, TimeSignal,
+};
+use std::time::{Duration, SystemTime};
 
 /// Builder for creating splice insert commands.
 ///
@@ -271,3 +275,210 @@ impl Default for TimeSignalBuilder {
         Self::new()
     }
 }
+
+/// Builder for creating splice schedule commands.
+///
+/// Splice times are given as UTC and converted to seconds since the GPS epoch using
+/// the leap-second table set with [`SpliceScheduleBuilder::leap_seconds`]
+/// (the built-in table by default).
+#[derive(Debug)]
+pub struct SpliceScheduleBuilder {
+    splice_event_id: Option<u32>,
+    out_of_network: bool,
+    splice_time: Option<SystemTime>,
+    components: Vec<(u8, SystemTime)>,
+    unique_program_id: u16,
+    leap_seconds: LeapSeconds,
+}
+
+impl SpliceScheduleBuilder {
+    /// Create a new splice schedule builder with the given event ID.
+    pub fn new(splice_event_id: u32) -> Self {
+        Self {
+            splice_event_id: Some(splice_event_id),
+            out_of_network: true,
+            splice_time: None,
+            components: Vec::new(),
+            unique_program_id: 0,
+            leap_seconds: LeapSeconds::default(),
+        }
+    }
+
+    /// Mark this event as cancelled.
+    pub fn cancel_event(mut self) -> Self {
+        self.splice_event_id = None;
+        self
+    }
+
+    /// Set whether the splice is out of network.
+    pub fn out_of_network(mut self, out: bool) -> Self {
+        self.out_of_network = out;
+        self
+    }
+
+    /// Set the UTC time at which the program splice occurs.
+    ///
+    /// A program splice cannot be combined with [`SpliceScheduleBuilder::component_splice`].
+    pub fn at_utc(mut self, time: SystemTime) -> Self {
+        self.splice_time = Some(time);
+        self
+    }
+
+    /// Add a component splice occurring at the given UTC time.
+    ///
+    /// Component splices replace the program splice time set with
+    /// [`SpliceScheduleBuilder::at_utc`]; setting both fails to build.
+    pub fn component_splice(mut self, component_tag: u8, time: SystemTime) -> Self {
+        self.components.push((component_tag, time));
+        self
+    }
+
+    /// Set the unique program ID.
+    pub fn unique_program_id(mut self, id: u16) -> Self {
+        self.unique_program_id = id;
+        self
+    }
+
+    /// Set the leap-second table used to convert UTC to GPS time.
+    pub fn leap_seconds(mut self, leap_seconds: LeapSeconds) -> Self {
+        self.leap_seconds = leap_seconds;
+        self
+    }
+
+    /// Build a splice schedule command carrying a single event.
+    pub fn build(self) -> BuilderResult<SpliceSchedule> {
+        let (splice_event_id, cancel) = match self.splice_event_id {
+            Some(id) => (id, 0),
+            None => (0, 1), // Cancellation
+        };
+
+        if cancel == 0 && self.splice_time.is_none() && self.components.is_empty() {
+            return Err(BuilderError::MissingRequiredField("utc_splice_time"));
+        }
+
+        if self.splice_time.is_some() && !self.components.is_empty() {
+            return Err(BuilderError::InvalidValue {
+                field: "utc_splice_time",
+                reason: "a program splice time cannot be combined with component splices"
+                    .to_string(),
+            });
+        }
+
+        if self.components.len() > 255 {
+            return Err(BuilderError::InvalidComponentCount {
+                max: 255,
+                actual: self.components.len(),
+            });
+        }
+
+        let utc_splice_time = match self.splice_time {
+            Some(time) => Some(to_gps_seconds(&self.leap_seconds, time, "utc_splice_time")?),
+            None => None,
+        };
+
+        let mut components = Vec::new();
+        for (component_tag, time) in self.components {
+            components.push(ComponentSplice {
+                component_tag,
+                utc_splice_time: to_gps_seconds(
+                    &self.leap_seconds,
+                    time,
+                    "component_utc_splice_time",
+                )?,
+            });
+        }
+
+        // Nothing after the cancel indicator is sent for a cancelled event
+        let transmitted = cancel == 0;
+        let event = SpliceScheduleEvent {
+            splice_event_id,
+            splice_event_cancel_indicator: cancel,
+            reserved: 0x7F, // All 1s for 7-bit reserved field
+            out_of_network_indicator: (transmitted && self.out_of_network) as u8,
+            program_splice_flag: utc_splice_time.is_some() as u8,
+            duration_flag: 0,
+            reserved2: if transmitted { 0x1F } else { 0 }, // All 1s for 5-bit reserved field
+            utc_splice_time,
+            component_count: components.len() as u8,
+            components,
+            break_duration: None,
+            unique_program_id: if transmitted {
+                self.unique_program_id
+            } else {
+                0
+            },
+            avail_num: 0,
+            avails_expected: 0,
+        };
+
+        Ok(SpliceSchedule {
+            splice_count: 1,
+            events: vec![event],
+        })
+    }
+}
+
+/// Builder for creating private commands.
+///
+/// The private bytes can be given raw or encoded from a typed
+/// [`PrivateCommandPayload`], the same type a registered decoder produces.
+#[derive(Debug)]
+pub struct PrivateCommandBuilder {
+    private_command_id: u16,
+    private_bytes: Vec<u8>,
+}
+
+impl PrivateCommandBuilder {
+    /// Create a new private command builder with the given private command ID.
+    pub fn new(private_command_id: u16) -> Self {
+        Self {
+            private_command_id,
+            private_bytes: Vec::new(),
+        }
+    }
+
+    /// Set the raw private bytes.
+    pub fn private_bytes(mut self, private_bytes: Vec<u8>) -> Self {
+        self.private_bytes = private_bytes;
+        self
+    }
+
+    /// Set the private bytes by encoding a typed payload.
+    pub fn payload(mut self, payload: &dyn PrivateCommandPayload) -> Self {
+        self.private_bytes = payload.private_bytes();
+        self
+    }
+
+    /// Build the private command.
+    pub fn build(self) -> BuilderResult<PrivateCommand> {
+        if self.private_bytes.len() > 255 {
+            return Err(BuilderError::InvalidValue {
+                field: "private_bytes",
+                reason: format!(
+                    "Private command data must be <= 255 bytes. Got {} bytes",
+                    self.private_bytes.len()
+                ),
+            });
+        }
+
+        Ok(PrivateCommand {
+            private_command_id: self.private_command_id,
+            private_command_length: self.private_bytes.len() as u8,
+            private_bytes: self.private_bytes,
+        })
+    }
+}
+
+fn to_gps_seconds(
+    leap_seconds: &LeapSeconds,
+    time: SystemTime,
+    field: &'static str,
+) -> BuilderResult<u32> {
+    leap_seconds
+        .utc_to_gps(time)
+        .ok_or_else(|| BuilderError::InvalidValue {
+            field,
+            reason: "time must be between 1980-01-06 and 2116-02-12 (32-bit GPS seconds)"
+                .to_string(),
+        })
+}
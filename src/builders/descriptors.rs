@@ -60,32 +60,32 @@ pub struct SubSegmentation {
 }
 
 /// UPID (Unique Program Identifier) types for segmentation descriptors.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Upid {
     /// No UPID specified.
     None,
     /// User-defined UPID (deprecated).
     UserDefinedDeprecated(Vec<u8>),
-    /// ISCI (Industry Standard Commercial Identifier).
+    /// 8-character ISCI (Industry Standard Commercial Identifier), e.g. `"ABCD1234"`.
     Isci(String),
-    /// 12-character ASCII Ad ID.
+    /// 12-character Ad-ID, e.g. `"ABCD0001000H"`.
     AdId(String),
-    /// 32-byte UMID.
+    /// 32-byte basic UMID (see [`Upid::new_umid`] for the text form).
     Umid([u8; 32]),
-    /// ISAN (International Standard Audiovisual Number) - deprecated.
-    IsanDeprecated([u8; 12]),
-    /// 12-byte ISAN.
+    /// 8-byte ISAN without version (deprecated UPID type 0x05).
+    IsanDeprecated([u8; 8]),
+    /// 12-byte V-ISAN (see [`Upid::new_isan`] for the text form).
     Isan([u8; 12]),
-    /// 12-character ASCII TID.
+    /// 12-character TID, e.g. `"EP0123456789"` (see [`Upid::new_tid`]).
     Tid(String),
-    /// 8-byte Airing ID.
+    /// 8-byte Airing ID (see [`Upid::new_airing_id`] for the hex text form).
     AiringId(u64),
     /// ADI (Advertising Digital Identification).
     Adi(Vec<u8>),
-    /// 12-byte EIDR.
+    /// 12-byte EIDR (see [`Upid::new_eidr`] for the text form).
     Eidr([u8; 12]),
     /// ATSC Content Identifier.
-    AtscContentIdentifier(Vec<u8>),
+    AtscContentIdentifier(crate::upid::AtscContentIdentifier),
     /// MPU (Media Processing Unit) with format identifier and private data.
     Mpu {
         /// 32-bit format identifier registered with SMPTE
@@ -93,8 +93,10 @@ pub enum Upid {
         /// Variable-length private data as defined by format identifier owner
         private_data: Vec<u8>,
     },
-    /// MID (Media Identifier).
-    Mid(Vec<u8>),
+    /// MID (Multiple Identifiers) combining several UPIDs.
+    ///
+    /// Members must not themselves be MID UPIDs.
+    Mid(Vec<Upid>),
     /// ADS Information.
     AdsInformation(Vec<u8>),
     /// Variable-length URI.
@@ -145,6 +147,250 @@ impl Upid {
             private_data: data.as_bytes().to_vec(),
         }
     }
+
+    /// Creates an EIDR UPID from its canonical text form.
+    ///
+    /// The text must be `10.<prefix>/XXXX-XXXX-XXXX-XXXX-XXXX-C` with a valid
+    /// ISO 7064 Mod 37,36 check character.
+    ///
+    /// # Example
+    /// ```rust
+    /// use scte35::builders::Upid;
+    ///
+    /// let eidr = Upid::new_eidr("10.5240/7791-8534-2C23-9030-8610-5").unwrap();
+    /// assert!(matches!(eidr, Upid::Eidr(_)));
+    /// assert!(Upid::new_eidr("10.5240/7791-8534-2C23-9030-8610-0").is_err());
+    /// ```
+    pub fn new_eidr(text: &str) -> BuilderResult<Self> {
+        crate::upid::parse_eidr(text)
+            .map(Upid::Eidr)
+            .map_err(|e| BuilderError::InvalidValue {
+                field: "eidr_upid",
+                reason: e.reason,
+            })
+    }
+
+    /// Creates a TID UPID, checking its 2-letter prefix and 10-digit structure.
+    ///
+    /// # Example
+    /// ```rust
+    /// use scte35::builders::Upid;
+    ///
+    /// assert_eq!(
+    ///     Upid::new_tid("EP0123456789").unwrap(),
+    ///     Upid::Tid("EP0123456789".to_string())
+    /// );
+    /// assert!(Upid::new_tid("EP-123456789").is_err());
+    /// ```
+    pub fn new_tid(text: &str) -> BuilderResult<Self> {
+        let upid = Upid::Tid(text.trim().to_string());
+        upid.validate()?;
+        Ok(upid)
+    }
+
+    /// Creates an Airing ID UPID from its hex text form, e.g. `"0x000000002CA0A18A"`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use scte35::builders::Upid;
+    ///
+    /// assert_eq!(
+    ///     Upid::new_airing_id("0x000000002CA0A18A").unwrap(),
+    ///     Upid::AiringId(0x2CA0A18A)
+    /// );
+    /// assert!(Upid::new_airing_id("airing").is_err());
+    /// ```
+    pub fn new_airing_id(text: &str) -> BuilderResult<Self> {
+        crate::upid::parse_airing_id(text)
+            .map(Upid::AiringId)
+            .map_err(|e| BuilderError::InvalidValue {
+                field: "airing_id_upid",
+                reason: e.reason,
+            })
+    }
+
+    /// Creates a UMID UPID from its hex-dotted text form.
+    ///
+    /// # Example
+    /// ```rust
+    /// use scte35::builders::Upid;
+    ///
+    /// let umid = Upid::new_umid(
+    ///     "060A2B34.01010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2.D718BFDA",
+    /// )
+    /// .unwrap();
+    /// assert!(matches!(umid, Upid::Umid(_)));
+    /// assert!(Upid::new_umid("060A2B34").is_err());
+    /// ```
+    pub fn new_umid(text: &str) -> BuilderResult<Self> {
+        crate::upid::parse_umid(text)
+            .map(Upid::Umid)
+            .map_err(|e| BuilderError::InvalidValue {
+                field: "umid_upid",
+                reason: e.reason,
+            })
+    }
+
+    /// Creates an ISAN UPID from its human-readable text form.
+    ///
+    /// A V-ISAN with version (`XXXX-XXXX-XXXX-XXXX-C-XXXX-XXXX-C`) yields [`Upid::Isan`];
+    /// an ISAN without version (`XXXX-XXXX-XXXX-XXXX-C`) yields the deprecated
+    /// [`Upid::IsanDeprecated`]. Check characters are optional but verified when present.
+    ///
+    /// # Example
+    /// ```rust
+    /// use scte35::builders::Upid;
+    ///
+    /// let isan = Upid::new_isan("0000-0000-D07A-0090-Q-0000-0000-X").unwrap();
+    /// assert!(matches!(isan, Upid::Isan(_)));
+    /// let isan = Upid::new_isan("0000-0000-D07A-0090-Q").unwrap();
+    /// assert!(matches!(isan, Upid::IsanDeprecated(_)));
+    /// assert!(Upid::new_isan("0000-0000-D07A-0090-A").is_err());
+    /// ```
+    pub fn new_isan(text: &str) -> BuilderResult<Self> {
+        let bytes = crate::upid::parse_isan(text).map_err(|e| BuilderError::InvalidValue {
+            field: "isan_upid",
+            reason: e.reason,
+        })?;
+        Upid::from_parts(
+            if bytes.len() == 12 {
+                SegmentationUpidType::ISAN
+            } else {
+                SegmentationUpidType::ISANDeprecated
+            },
+            &bytes,
+        )
+    }
+
+    /// Validates the UPID against the constraints of its type.
+    fn validate(&self) -> BuilderResult<()> {
+        match self {
+            Upid::AdId(s) => {
+                if s.len() != 12 {
+                    return Err(BuilderError::InvalidUpidLength {
+                        expected: 12,
+                        actual: s.len(),
+                    });
+                }
+                crate::upid::validate_ad_id(s).map_err(|e| BuilderError::InvalidAdId {
+                    value: s.clone(),
+                    reason: e.reason,
+                })?;
+            }
+            Upid::Isci(s) => {
+                if s.len() != 8 {
+                    return Err(BuilderError::InvalidUpidLength {
+                        expected: 8,
+                        actual: s.len(),
+                    });
+                }
+                crate::upid::validate_isci(s).map_err(|e| BuilderError::InvalidIsci {
+                    value: s.clone(),
+                    reason: e.reason,
+                })?;
+            }
+            Upid::Tid(s) => {
+                if s.len() != 12 {
+                    return Err(BuilderError::InvalidUpidLength {
+                        expected: 12,
+                        actual: s.len(),
+                    });
+                }
+                crate::upid::validate_tid(s).map_err(|e| BuilderError::InvalidTid {
+                    value: s.clone(),
+                    reason: e.reason,
+                })?;
+            }
+            Upid::Mpu {
+                format_identifier: _,
+                private_data,
+            } if private_data.len() > 251 => {
+                return Err(BuilderError::InvalidValue {
+                    field: "mpu_private_data",
+                    reason: format!(
+                        "MPU private data must be <= 251 bytes (4 bytes reserved for format_identifier). Got {} bytes",
+                        private_data.len()
+                    ),
+                });
+            }
+            Upid::Uri(s) if s.is_empty() || s.len() > 255 => {
+                return Err(BuilderError::InvalidValue {
+                    field: "uri",
+                    reason: "URI must be 1-255 bytes".to_string(),
+                });
+            }
+            Upid::Mid(members) => {
+                let mut encoded_length = 0;
+                for member in members {
+                    if matches!(member, Upid::Mid(_)) {
+                        return Err(BuilderError::InvalidValue {
+                            field: "mid_upid",
+                            reason: "MID members cannot themselves be MID UPIDs".to_string(),
+                        });
+                    }
+                    member.validate()?;
+                    // upid_type + upid_length + upid bytes
+                    encoded_length += 2 + member.encoded_length();
+                }
+                if encoded_length > 255 {
+                    return Err(BuilderError::InvalidValue {
+                        field: "mid_upid",
+                        reason: format!(
+                            "MID UPID must be <= 255 bytes including member headers. Got {encoded_length} bytes"
+                        ),
+                    });
+                }
+            }
+            Upid::AtscContentIdentifier(id) => {
+                if id.end_of_day > 23 {
+                    return Err(BuilderError::InvalidValue {
+                        field: "end_of_day",
+                        reason: format!("end_of_day must be an hour 0-23. Got {}", id.end_of_day),
+                    });
+                }
+                if id.unique_for > 0x1FF {
+                    return Err(BuilderError::InvalidValue {
+                        field: "unique_for",
+                        reason: format!("unique_for must fit in 9 bits. Got {}", id.unique_for),
+                    });
+                }
+                if id.content_id.len() > 251 {
+                    return Err(BuilderError::InvalidValue {
+                        field: "content_id",
+                        reason: format!(
+                            "ATSC content_id must be <= 251 bytes (4 bytes reserved for header). Got {} bytes",
+                            id.content_id.len()
+                        ),
+                    });
+                }
+            }
+            Upid::UserDefinedDeprecated(data)
+            | Upid::Adi(data)
+            | Upid::AdsInformation(data)
+            | Upid::Scr(data)
+                if data.len() > 255 =>
+            {
+                return Err(BuilderError::InvalidValue {
+                    field: "upid_data",
+                    reason: "UPID data must be <= 255 bytes".to_string(),
+                });
+            }
+            Upid::Reserved(_, data) if data.len() > 255 => {
+                return Err(BuilderError::InvalidValue {
+                    field: "reserved_upid_data",
+                    reason: "Reserved UPID data must be <= 255 bytes".to_string(),
+                });
+            }
+            _ => {} // Other types have fixed sizes or are within bounds
+        }
+        Ok(())
+    }
+
+    /// Returns the number of bytes this UPID occupies on the wire.
+    fn encoded_length(&self) -> usize {
+        let (_, bytes): (SegmentationUpidType, Vec<u8>) = self.clone().into();
+        bytes.len()
+    }
 }
 
 impl SegmentationDescriptorBuilder {
@@ -196,61 +442,7 @@ impl SegmentationDescriptorBuilder {
 
     /// Set the UPID for this segment.
     pub fn upid(mut self, upid: Upid) -> BuilderResult<Self> {
-        // Validate UPID based on type
-        match &upid {
-            Upid::Isci(s) | Upid::AdId(s) | Upid::Tid(s) => {
-                if s.len() != 12 {
-                    return Err(BuilderError::InvalidUpidLength {
-                        expected: 12,
-                        actual: s.len(),
-                    });
-                }
-            }
-            Upid::Mpu {
-                format_identifier: _,
-                private_data,
-            } => {
-                if private_data.len() > 251 {
-                    return Err(BuilderError::InvalidValue {
-                        field: "mpu_private_data",
-                        reason: format!(
-                            "MPU private data must be <= 251 bytes (4 bytes reserved for format_identifier). Got {} bytes",
-                            private_data.len()
-                        ),
-                    });
-                }
-            }
-            Upid::Uri(s) => {
-                if s.is_empty() || s.len() > 255 {
-                    return Err(BuilderError::InvalidValue {
-                        field: "uri",
-                        reason: "URI must be 1-255 bytes".to_string(),
-                    });
-                }
-            }
-            Upid::UserDefinedDeprecated(data)
-            | Upid::Adi(data)
-            | Upid::AtscContentIdentifier(data)
-            | Upid::Mid(data)
-            | Upid::AdsInformation(data)
-            | Upid::Scr(data) => {
-                if data.len() > 255 {
-                    return Err(BuilderError::InvalidValue {
-                        field: "upid_data",
-                        reason: "UPID data must be <= 255 bytes".to_string(),
-                    });
-                }
-            }
-            Upid::Reserved(_, data) => {
-                if data.len() > 255 {
-                    return Err(BuilderError::InvalidValue {
-                        field: "reserved_upid_data",
-                        reason: "Reserved UPID data must be <= 255 bytes".to_string(),
-                    });
-                }
-            }
-            _ => {} // Other types have fixed sizes
-        }
+        upid.validate()?;
         self.upid = Some(upid);
         Ok(self)
     }
@@ -263,6 +455,9 @@ impl SegmentationDescriptorBuilder {
     }
 
     /// Set sub-segment information.
+    ///
+    /// Only segmentation types that carry sub-segment fields accept this (see
+    /// [`SegmentationType::has_sub_segments`]); [`build`](Self::build) fails otherwise.
     pub fn sub_segment(mut self, num: u8, expected: u8) -> Self {
         self.sub_segmentation = Some(SubSegmentation {
             sub_segment_num: num,
@@ -290,6 +485,17 @@ impl SegmentationDescriptorBuilder {
                 ),
             };
 
+        if self.sub_segmentation.is_some() && !self.segmentation_type.has_sub_segments() {
+            return Err(BuilderError::InvalidValue {
+                field: "sub_segment_num",
+                reason: format!(
+                    "segmentation type {} (0x{:02X}) does not carry sub-segment fields",
+                    self.segmentation_type,
+                    self.segmentation_type.id()
+                ),
+            });
+        }
+
         let (upid_type, upid_bytes) = self.upid.unwrap_or(Upid::None).into();
 
         let duration_ticks = match self.duration {
@@ -360,8 +566,8 @@ impl From<Upid> for (SegmentationUpidType, Vec<u8>) {
             Upid::AiringId(id) => (SegmentationUpidType::AiringID, id.to_be_bytes().to_vec()),
             Upid::Adi(data) => (SegmentationUpidType::ADI, data),
             Upid::Eidr(bytes) => (SegmentationUpidType::EIDR, bytes.to_vec()),
-            Upid::AtscContentIdentifier(data) => {
-                (SegmentationUpidType::ATSCContentIdentifier, data)
+            Upid::AtscContentIdentifier(id) => {
+                (SegmentationUpidType::ATSCContentIdentifier, id.to_bytes())
             }
             Upid::Mpu {
                 format_identifier,
@@ -371,7 +577,16 @@ impl From<Upid> for (SegmentationUpidType, Vec<u8>) {
                 bytes.extend(private_data);
                 (SegmentationUpidType::MPU, bytes)
             }
-            Upid::Mid(data) => (SegmentationUpidType::MID, data),
+            Upid::Mid(members) => {
+                let mut bytes = Vec::new();
+                for member in members {
+                    let (upid_type, upid_bytes): (SegmentationUpidType, Vec<u8>) = member.into();
+                    bytes.push(u8::from(upid_type));
+                    bytes.push(upid_bytes.len() as u8);
+                    bytes.extend(upid_bytes);
+                }
+                (SegmentationUpidType::MID, bytes)
+            }
             Upid::AdsInformation(data) => (SegmentationUpidType::ADSInformation, data),
             Upid::Uri(s) => (SegmentationUpidType::URI, s.into_bytes()),
             Upid::Uuid(bytes) => (SegmentationUpidType::UUID, bytes.to_vec()),
@@ -391,14 +606,31 @@ impl TryFrom<(&crate::descriptors::SegmentationDescriptor,)> for Upid {
     fn try_from(
         (descriptor,): (&crate::descriptors::SegmentationDescriptor,),
     ) -> Result<Self, Self::Error> {
-        use crate::upid::SegmentationUpidType;
-
-        let upid_bytes = &descriptor.segmentation_upid;
+        Upid::from_parts(
+            descriptor.segmentation_upid_type,
+            &descriptor.segmentation_upid,
+        )
+    }
+}
 
-        match descriptor.segmentation_upid_type {
+impl Upid {
+    /// Creates a UPID from its type and raw bytes, as found in a segmentation descriptor.
+    ///
+    /// MID UPIDs are decoded into their member UPIDs.
+    ///
+    /// # Example
+    /// ```rust
+    /// use scte35::builders::Upid;
+    /// use scte35::upid::SegmentationUpidType;
+    ///
+    /// let upid = Upid::from_parts(SegmentationUpidType::AdID, b"ABCD0001000H").unwrap();
+    /// assert_eq!(upid.to_string(), "AdID(\"ABCD0001000H\")");
+    /// ```
+    pub fn from_parts(upid_type: SegmentationUpidType, upid_bytes: &[u8]) -> BuilderResult<Self> {
+        match upid_type {
             SegmentationUpidType::NotUsed => Ok(Upid::None),
             SegmentationUpidType::UserDefinedDeprecated => {
-                Ok(Upid::UserDefinedDeprecated(upid_bytes.clone()))
+                Ok(Upid::UserDefinedDeprecated(upid_bytes.to_vec()))
             }
             SegmentationUpidType::ISCI => {
                 let s =
@@ -428,13 +660,13 @@ impl TryFrom<(&crate::descriptors::SegmentationDescriptor,)> for Upid {
                 Ok(Upid::Umid(umid_array))
             }
             SegmentationUpidType::ISANDeprecated => {
-                if upid_bytes.len() != 12 {
+                if upid_bytes.len() != 8 {
                     return Err(BuilderError::InvalidValue {
                         field: "isan_deprecated_upid",
-                        reason: "ISAN (deprecated) UPID must be exactly 12 bytes".to_string(),
+                        reason: "ISAN (deprecated) UPID must be exactly 8 bytes".to_string(),
                     });
                 }
-                let mut isan_array = [0u8; 12];
+                let mut isan_array = [0u8; 8];
                 isan_array.copy_from_slice(upid_bytes);
                 Ok(Upid::IsanDeprecated(isan_array))
             }
@@ -476,7 +708,7 @@ impl TryFrom<(&crate::descriptors::SegmentationDescriptor,)> for Upid {
                 ]);
                 Ok(Upid::AiringId(airing_id))
             }
-            SegmentationUpidType::ADI => Ok(Upid::Adi(upid_bytes.clone())),
+            SegmentationUpidType::ADI => Ok(Upid::Adi(upid_bytes.to_vec())),
             SegmentationUpidType::EIDR => {
                 if upid_bytes.len() != 12 {
                     return Err(BuilderError::InvalidValue {
@@ -489,7 +721,13 @@ impl TryFrom<(&crate::descriptors::SegmentationDescriptor,)> for Upid {
                 Ok(Upid::Eidr(eidr_array))
             }
             SegmentationUpidType::ATSCContentIdentifier => {
-                Ok(Upid::AtscContentIdentifier(upid_bytes.clone()))
+                crate::upid::AtscContentIdentifier::from_bytes(upid_bytes)
+                    .map(Upid::AtscContentIdentifier)
+                    .ok_or_else(|| BuilderError::InvalidValue {
+                        field: "atsc_content_identifier_upid",
+                        reason: "ATSC Content Identifier UPID must have at least 4 bytes"
+                            .to_string(),
+                    })
             }
             SegmentationUpidType::MPU => {
                 if upid_bytes.len() < 4 {
@@ -511,8 +749,28 @@ impl TryFrom<(&crate::descriptors::SegmentationDescriptor,)> for Upid {
                     private_data,
                 })
             }
-            SegmentationUpidType::MID => Ok(Upid::Mid(upid_bytes.clone())),
-            SegmentationUpidType::ADSInformation => Ok(Upid::AdsInformation(upid_bytes.clone())),
+            SegmentationUpidType::MID => {
+                let members = crate::upid::parse_mid(upid_bytes).ok_or_else(|| {
+                    BuilderError::InvalidValue {
+                        field: "mid_upid",
+                        reason: "MID UPID member length exceeds the available data".to_string(),
+                    }
+                })?;
+                members
+                    .iter()
+                    .map(|member| {
+                        if member.upid_type == SegmentationUpidType::MID {
+                            return Err(BuilderError::InvalidValue {
+                                field: "mid_upid",
+                                reason: "MID members cannot themselves be MID UPIDs".to_string(),
+                            });
+                        }
+                        Upid::from_parts(member.upid_type, &member.upid)
+                    })
+                    .collect::<BuilderResult<Vec<_>>>()
+                    .map(Upid::Mid)
+            }
+            SegmentationUpidType::ADSInformation => Ok(Upid::AdsInformation(upid_bytes.to_vec())),
             SegmentationUpidType::URI => {
                 let s =
                     std::str::from_utf8(upid_bytes).map_err(|_| BuilderError::InvalidValue {
@@ -532,9 +790,9 @@ impl TryFrom<(&crate::descriptors::SegmentationDescriptor,)> for Upid {
                 uuid_array.copy_from_slice(upid_bytes);
                 Ok(Upid::Uuid(uuid_array))
             }
-            SegmentationUpidType::SCR => Ok(Upid::Scr(upid_bytes.clone())),
+            SegmentationUpidType::SCR => Ok(Upid::Scr(upid_bytes.to_vec())),
             SegmentationUpidType::Reserved(type_id) => {
-                Ok(Upid::Reserved(type_id, upid_bytes.clone()))
+                Ok(Upid::Reserved(type_id, upid_bytes.to_vec()))
             }
         }
     }
@@ -549,15 +807,17 @@ impl std::fmt::Display for Upid {
             }
             Upid::Isci(s) => write!(f, "ISCI(\"{s}\")"),
             Upid::AdId(s) => write!(f, "AdID(\"{s}\")"),
-            Upid::Umid(bytes) => write!(f, "UMID({} bytes)", bytes.len()),
-            Upid::IsanDeprecated(bytes) => write!(f, "ISANDeprecated({} bytes)", bytes.len()),
-            Upid::Isan(bytes) => write!(f, "ISAN({} bytes)", bytes.len()),
+            Upid::Umid(bytes) => write!(f, "UMID({})", crate::upid::format_umid(bytes)),
+            Upid::IsanDeprecated(bytes) => {
+                write!(f, "ISANDeprecated({})", crate::upid::format_isan(bytes))
+            }
+            Upid::Isan(bytes) => write!(f, "ISAN({})", crate::upid::format_isan(bytes)),
             Upid::Tid(s) => write!(f, "TID(\"{s}\")"),
-            Upid::AiringId(id) => write!(f, "AiringID({id})"),
+            Upid::AiringId(id) => write!(f, "AiringID({})", crate::upid::format_airing_id(*id)),
             Upid::Adi(data) => write!(f, "ADI({} bytes)", data.len()),
-            Upid::Eidr(bytes) => write!(f, "EIDR({} bytes)", bytes.len()),
-            Upid::AtscContentIdentifier(data) => {
-                write!(f, "ATSCContentIdentifier({} bytes)", data.len())
+            Upid::Eidr(bytes) => write!(f, "EIDR({})", crate::upid::format_eidr(bytes)),
+            Upid::AtscContentIdentifier(id) => {
+                write!(f, "{id}")
             }
             Upid::Mpu {
                 format_identifier,
@@ -567,7 +827,16 @@ impl std::fmt::Display for Upid {
                 let data_str = format_private_data(private_data);
                 write!(f, "MPU(format: {format_str}, data: {data_str})")
             }
-            Upid::Mid(data) => write!(f, "MID({} bytes)", data.len()),
+            Upid::Mid(members) => {
+                write!(f, "MID(")?;
+                for (index, member) in members.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{member}")?;
+                }
+                write!(f, ")")
+            }
             Upid::AdsInformation(data) => write!(f, "ADSInformation({} bytes)", data.len()),
             Upid::Uri(s) => write!(f, "URI(\"{s}\")"),
             Upid::Uuid(bytes) => write!(f, "UUID({} bytes)", bytes.len()),
@@ -30,6 +30,27 @@ pub enum BuilderError {
         /// The actual length provided.
         actual: usize,
     },
+    /// An Ad-ID UPID is not a well-formed Ad-ID code.
+    InvalidAdId {
+        /// The rejected Ad-ID.
+        value: String,
+        /// A description of why the Ad-ID is invalid.
+        reason: String,
+    },
+    /// An ISCI UPID is not a well-formed ISCI code.
+    InvalidIsci {
+        /// The rejected ISCI code.
+        value: String,
+        /// A description of why the ISCI code is invalid.
+        reason: String,
+    },
+    /// A TID UPID is not a well-formed TID.
+    InvalidTid {
+        /// The rejected TID.
+        value: String,
+        /// A description of why the TID is invalid.
+        reason: String,
+    },
     /// Too many components were specified.
     InvalidComponentCount {
         /// The maximum number of components allowed.
@@ -56,6 +77,15 @@ impl fmt::Display for BuilderError {
                 f,
                 "Invalid UPID length: expected {expected} bytes, got {actual}"
             ),
+            BuilderError::InvalidAdId { value, reason } => {
+                write!(f, "Invalid Ad-ID '{value}': {reason}")
+            }
+            BuilderError::InvalidIsci { value, reason } => {
+                write!(f, "Invalid ISCI '{value}': {reason}")
+            }
+            BuilderError::InvalidTid { value, reason } => {
+                write!(f, "Invalid TID '{value}': {reason}")
+            }
             BuilderError::InvalidComponentCount { max, actual } => {
                 write!(f, "Too many components: maximum {max}, got {actual}")
             }
@@ -76,6 +106,6 @@ pub(crate) trait DurationExt {
 
 impl DurationExt for Duration {
     fn to_pts_ticks(&self) -> u64 {
-        self.as_secs() * 90_000 + (self.subsec_nanos() as u64 * 90_000 / 1_000_000_000)
+        crate::time::duration_to_ticks(self)
     }
 }
@@ -67,6 +67,12 @@ impl SpliceInfoSectionBuilder {
         self
     }
 
+    /// Set a splice schedule command.
+    pub fn splice_schedule(mut self, schedule: crate::types::SpliceSchedule) -> Self {
+        self.splice_command = Some(SpliceCommand::SpliceSchedule(schedule));
+        self
+    }
+
     /// Set a splice insert command.
     pub fn splice_insert(mut self, insert: crate::types::SpliceInsert) -> Self {
         self.splice_command = Some(SpliceCommand::SpliceInsert(insert));
@@ -79,6 +85,12 @@ impl SpliceInfoSectionBuilder {
         self
     }
 
+    /// Set a private command.
+    pub fn private_command(mut self, command: crate::types::PrivateCommand) -> Self {
+        self.splice_command = Some(SpliceCommand::PrivateCommand(command));
+        self
+    }
+
     /// Add a descriptor to the message.
     pub fn add_descriptor(mut self, descriptor: SpliceDescriptor) -> Self {
         self.descriptors.push(descriptor);
@@ -2,7 +2,7 @@
 
 use super::*;
 use crate::types::SegmentationType;
-use crate::upid::SegmentationUpidType;
+use crate::upid::{AtscContentIdentifier, SegmentationUpidType};
 use data_encoding::BASE64;
 use std::time::Duration;
 
@@ -315,6 +315,150 @@ mod builder_tests {
         assert_eq!(time_signal.splice_time.pts_time, Some(30 * 90_000));
     }
 
+    #[test]
+    fn test_splice_schedule_builder_gps_time() {
+        use crate::time::LeapSeconds;
+        use std::time::UNIX_EPOCH;
+
+        // 2024-01-01T00:00:00Z
+        let start = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        let schedule = SpliceScheduleBuilder::new(42)
+            .at_utc(start)
+            .unique_program_id(7)
+            .build()
+            .unwrap();
+
+        assert_eq!(schedule.splice_count, 1);
+        let event = &schedule.events[0];
+        assert_eq!(event.splice_event_id, 42);
+        assert_eq!(event.splice_event_cancel_indicator, 0);
+        assert_eq!(event.program_splice_flag, 1);
+        assert_eq!(event.unique_program_id, 7);
+        // 1_704_067_200 - 315_964_800 + 18 leap seconds
+        assert_eq!(event.utc_splice_time, Some(1_388_102_418));
+        let leap_seconds = LeapSeconds::default();
+        assert_eq!(event.splice_system_time(&leap_seconds), Some(start));
+
+        let schedule = SpliceScheduleBuilder::new(42)
+            .component_splice(0x01, start + Duration::from_secs(2))
+            .build()
+            .unwrap();
+        let event = &schedule.events[0];
+        assert_eq!(event.program_splice_flag, 0);
+        assert_eq!(event.utc_splice_time, None);
+        assert_eq!(event.component_count, 1);
+        assert_eq!(event.components[0].utc_splice_time, 1_388_102_420);
+        assert_eq!(
+            event.components[0].splice_system_time(&leap_seconds),
+            start + Duration::from_secs(2)
+        );
+
+        // A custom table changes the encoded GPS seconds
+        let schedule = SpliceScheduleBuilder::new(42)
+            .at_utc(start)
+            .leap_seconds(LeapSeconds::none())
+            .build()
+            .unwrap();
+        assert_eq!(schedule.events[0].utc_splice_time, Some(1_388_102_400));
+    }
+
+    #[test]
+    fn test_splice_schedule_round_trip() {
+        use crate::types::SpliceCommand;
+        use std::time::UNIX_EPOCH;
+
+        let start = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        let schedules = [
+            SpliceScheduleBuilder::new(42)
+                .at_utc(start)
+                .unique_program_id(7)
+                .build()
+                .unwrap(),
+            SpliceScheduleBuilder::new(43)
+                .out_of_network(false)
+                .component_splice(0x01, start)
+                .component_splice(0x02, start + Duration::from_secs(2))
+                .build()
+                .unwrap(),
+            SpliceScheduleBuilder::new(44)
+                .cancel_event()
+                .build()
+                .unwrap(),
+        ];
+
+        for schedule in schedules {
+            let section = SpliceInfoSectionBuilder::new()
+                .splice_schedule(schedule)
+                .build()
+                .unwrap();
+            #[cfg(feature = "crc-validation")]
+            let bytes = {
+                use crate::encoding::CrcEncodable;
+                section.encode_with_crc().unwrap()
+            };
+            #[cfg(not(feature = "crc-validation"))]
+            let bytes = {
+                use crate::encoding::Encodable;
+                section.encode_to_vec().unwrap()
+            };
+            let parsed = crate::parser::parse_splice_info_section(&bytes).unwrap();
+            assert_eq!(parsed.splice_command_length, section.splice_command_length);
+            match (&parsed.splice_command, &section.splice_command) {
+                (SpliceCommand::SpliceSchedule(parsed), SpliceCommand::SpliceSchedule(built)) => {
+                    assert_eq!(parsed, built)
+                }
+                other => panic!("Expected splice schedules, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_splice_schedule_builder_validation() {
+        use std::time::UNIX_EPOCH;
+
+        assert_eq!(
+            SpliceScheduleBuilder::new(1).build().unwrap_err(),
+            BuilderError::MissingRequiredField("utc_splice_time")
+        );
+
+        // Before the GPS epoch
+        let result = SpliceScheduleBuilder::new(1).at_utc(UNIX_EPOCH).build();
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidValue {
+                field: "utc_splice_time",
+                ..
+            })
+        ));
+
+        // A program splice time and component splices are mutually exclusive
+        let start = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        let result = SpliceScheduleBuilder::new(1)
+            .at_utc(start)
+            .component_splice(0x01, start)
+            .build();
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidValue {
+                field: "utc_splice_time",
+                ..
+            })
+        ));
+
+        let cancelled = SpliceScheduleBuilder::new(1)
+            .cancel_event()
+            .build()
+            .unwrap();
+        assert_eq!(cancelled.events[0].splice_event_cancel_indicator, 1);
+        assert_eq!(cancelled.events[0].utc_splice_time, None);
+
+        let section = SpliceInfoSectionBuilder::new()
+            .splice_schedule(cancelled)
+            .build()
+            .unwrap();
+        assert_eq!(section.splice_command_type, 0x04);
+    }
+
     #[test]
     fn test_segmentation_descriptor_builder_basic() {
         let descriptor = SegmentationDescriptorBuilder::new(5678, SegmentationType::ProgramStart)
@@ -411,11 +555,14 @@ mod builder_tests {
 
     #[test]
     fn test_segmentation_descriptor_builder_sub_segments() {
-        let descriptor = SegmentationDescriptorBuilder::new(2222, SegmentationType::ChapterStart)
-            .segment(3, 10)
-            .sub_segment(2, 5)
-            .build()
-            .unwrap();
+        let descriptor = SegmentationDescriptorBuilder::new(
+            2222,
+            SegmentationType::ProviderPlacementOpportunityStart,
+        )
+        .segment(3, 10)
+        .sub_segment(2, 5)
+        .build()
+        .unwrap();
 
         assert_eq!(descriptor.segment_num, 3);
         assert_eq!(descriptor.segments_expected, 10);
@@ -423,6 +570,319 @@ mod builder_tests {
         assert_eq!(descriptor.sub_segments_expected, Some(5));
     }
 
+    #[test]
+    fn test_segmentation_descriptor_builder_rejects_sub_segments() {
+        let result = SegmentationDescriptorBuilder::new(2222, SegmentationType::ChapterStart)
+            .sub_segment(2, 5)
+            .build();
+
+        match result.unwrap_err() {
+            BuilderError::InvalidValue { field, reason } => {
+                assert_eq!(field, "sub_segment_num");
+                assert!(reason.contains("0x20"));
+            }
+            other => panic!("Expected InvalidValue error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_sub_segments_round_trip() {
+        for segmentation_type in [
+            SegmentationType::ProviderPlacementOpportunityStart,
+            SegmentationType::DistributorPlacementOpportunityStart,
+            SegmentationType::ProviderOverlayPlacementOpportunityStart,
+            SegmentationType::DistributorOverlayPlacementOpportunityStart,
+            SegmentationType::ProviderAdBlockStart,
+            SegmentationType::DistributorAdBlockStart,
+        ] {
+            let descriptor = SegmentationDescriptorBuilder::new(1, segmentation_type)
+                .sub_segment(1, 3)
+                .build()
+                .unwrap();
+            let section = SpliceInfoSectionBuilder::new()
+                .time_signal(TimeSignalBuilder::new().build().unwrap())
+                .add_segmentation_descriptor(descriptor)
+                .build()
+                .unwrap();
+
+            #[cfg(feature = "crc-validation")]
+            let bytes = {
+                use crate::encoding::CrcEncodable;
+                section.encode_with_crc().unwrap()
+            };
+            #[cfg(not(feature = "crc-validation"))]
+            let bytes = {
+                use crate::encoding::Encodable;
+                section.encode_to_vec().unwrap()
+            };
+            let parsed = crate::parser::parse_splice_info_section(&bytes).unwrap();
+            match &parsed.splice_descriptors[0] {
+                crate::descriptors::SpliceDescriptor::Segmentation(seg) => {
+                    assert_eq!(seg.segmentation_type, segmentation_type);
+                    assert_eq!(seg.sub_segment_num, Some(1));
+                    assert_eq!(seg.sub_segments_expected, Some(3));
+                }
+                other => panic!("Expected segmentation descriptor, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_mid_upid_round_trip() {
+        let eidr = [
+            0x14, 0x78, 0x00, 0x00, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0,
+        ];
+        let mid = Upid::Mid(vec![
+            Upid::AdId("ABCD0001000H".to_string()),
+            Upid::Eidr(eidr),
+        ]);
+        let descriptor = SegmentationDescriptorBuilder::new(
+            0x1234,
+            SegmentationType::ProviderAdvertisementStart,
+        )
+        .upid(mid.clone())
+        .unwrap()
+        .build()
+        .unwrap();
+
+        assert_eq!(descriptor.segmentation_upid_type, SegmentationUpidType::MID);
+        // (type + length + 12 bytes) for each member
+        assert_eq!(descriptor.segmentation_upid_length, 28);
+        assert_eq!(&descriptor.segmentation_upid[..2], &[0x03, 12]);
+        assert_eq!(&descriptor.segmentation_upid[14..16], &[0x0A, 12]);
+
+        let members = descriptor.mid_upids().unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].upid_type, SegmentationUpidType::AdID);
+        assert_eq!(members[0].upid, b"ABCD0001000H");
+        assert_eq!(members[1].upid_type, SegmentationUpidType::EIDR);
+        assert_eq!(members[1].upid, eidr);
+
+        let converted = Upid::try_from((&descriptor,)).unwrap();
+        assert_eq!(converted, mid);
+        assert!(
+            descriptor
+                .upid_as_string()
+                .unwrap()
+                .starts_with("MID(AdID: ABCD0001000H; EIDR: ")
+        );
+        // Members are separated the same way in the Display form
+        assert!(
+            mid.to_string()
+                .starts_with("MID(AdID(\"ABCD0001000H\"); EIDR(")
+        );
+    }
+
+    #[test]
+    fn test_mid_upid_validation() {
+        // Members are validated like top-level UPIDs
+        let result = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::Mid(vec![Upid::AdId("SHORT".to_string())]));
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidUpidLength {
+                expected: 12,
+                actual: 5
+            })
+        ));
+
+        // Nested MIDs are rejected
+        let result = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::Mid(vec![Upid::Mid(vec![])]));
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidValue {
+                field: "mid_upid",
+                ..
+            })
+        ));
+
+        // Member headers count towards the 255 byte limit
+        let members = vec![Upid::Uri("u".repeat(126)), Upid::Uri("u".repeat(126))];
+        let result = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::Mid(members));
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidValue {
+                field: "mid_upid",
+                ..
+            })
+        ));
+
+        // Malformed MID bytes cannot be converted
+        assert!(Upid::from_parts(SegmentationUpidType::MID, &[0x03, 12, b'A']).is_err());
+        // Nor can MID bytes that nest another MID
+        assert!(matches!(
+            Upid::from_parts(SegmentationUpidType::MID, &[0x0D, 0x00]),
+            Err(BuilderError::InvalidValue {
+                field: "mid_upid",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_umid_upid_text_round_trip() {
+        let text = "060A2B34.01010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2.D718BFDA";
+        let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::new_umid(text).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(descriptor.upid_as_string().as_deref(), Some(text));
+        let umid = descriptor.umid().unwrap();
+        assert_eq!(umid.length, 0x13);
+        assert_eq!(umid.to_string(), text);
+        assert_eq!(
+            Upid::try_from((&descriptor,)).unwrap().to_string(),
+            format!("UMID({text})")
+        );
+
+        assert!(matches!(
+            Upid::new_umid("not a umid"),
+            Err(BuilderError::InvalidValue {
+                field: "umid_upid",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_atsc_content_identifier_upid_round_trip() {
+        let id = AtscContentIdentifier {
+            tsid: 0x1234,
+            end_of_day: 5,
+            unique_for: 300,
+            content_id: b"EP0001".to_vec(),
+        };
+        let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::AtscContentIdentifier(id.clone()))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(descriptor.segmentation_upid_length, 10);
+        assert_eq!(descriptor.atsc_content_identifier(), Some(id.clone()));
+        assert_eq!(
+            descriptor.upid_as_string().as_deref(),
+            Some("ATSC(tsid: 0x1234, end_of_day: 5, unique_for: 300, content_id: \"EP0001\")")
+        );
+        assert_eq!(
+            Upid::try_from((&descriptor,)).unwrap(),
+            Upid::AtscContentIdentifier(id.clone())
+        );
+
+        let result = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart).upid(
+            Upid::AtscContentIdentifier(AtscContentIdentifier {
+                end_of_day: 24,
+                ..id.clone()
+            }),
+        );
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidValue {
+                field: "end_of_day",
+                ..
+            })
+        ));
+        let result = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart).upid(
+            Upid::AtscContentIdentifier(AtscContentIdentifier {
+                unique_for: 512,
+                ..id
+            }),
+        );
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidValue {
+                field: "unique_for",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_airing_id_and_tid_upid_text_round_trip() {
+        let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::new_airing_id("0x000000002CA0A18A").unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let text = descriptor.upid_as_string().unwrap();
+        assert_eq!(text, "0x000000002CA0A18A");
+        assert_eq!(
+            Upid::new_airing_id(&text).unwrap(),
+            Upid::try_from((&descriptor,)).unwrap()
+        );
+        assert_eq!(
+            Upid::AiringId(0x2CA0A18A).to_string(),
+            "AiringID(0x000000002CA0A18A)"
+        );
+
+        let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::new_tid("MV0012345678").unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(descriptor.upid_as_string().as_deref(), Some("MV0012345678"));
+
+        let result = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::Tid("MV00123456AB".to_string()));
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidTid { ref value, .. }) if value == "MV00123456AB"
+        ));
+        assert!(matches!(
+            Upid::new_tid("MV001"),
+            Err(BuilderError::InvalidUpidLength {
+                expected: 12,
+                actual: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn test_isan_upid_text_round_trip() {
+        let isan = Upid::new_isan("0000-0000-D07A-0090-Q-0000-0000-X").unwrap();
+        let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(isan.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(descriptor.segmentation_upid_length, 12);
+        assert_eq!(
+            descriptor.upid_as_string().as_deref(),
+            Some("0000-0000-D07A-0090-Q-0000-0000-X")
+        );
+        assert_eq!(isan.to_string(), "ISAN(0000-0000-D07A-0090-Q-0000-0000-X)");
+
+        // Without a version the deprecated 8-byte form is used
+        let isan = Upid::new_isan("0000-0000-D07A-0090").unwrap();
+        let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(isan)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            descriptor.segmentation_upid_type,
+            SegmentationUpidType::ISANDeprecated
+        );
+        assert_eq!(descriptor.segmentation_upid_length, 8);
+        assert_eq!(
+            descriptor.upid_as_string().as_deref(),
+            Some("0000-0000-D07A-0090-Q")
+        );
+
+        assert!(matches!(
+            Upid::new_isan("0000-0000-D07A-0090-Q-0000-0000-Y"),
+            Err(BuilderError::InvalidValue {
+                field: "isan_upid",
+                ..
+            })
+        ));
+        assert!(Upid::from_parts(SegmentationUpidType::ISANDeprecated, &[0; 12]).is_err());
+    }
+
     #[test]
     fn test_segmentation_descriptor_builder_invalid_upid_length() {
         let result = SegmentationDescriptorBuilder::new(1234, SegmentationType::ProgramStart)
@@ -537,6 +997,23 @@ mod builder_tests {
         }
     }
 
+    #[test]
+    fn test_duration_beyond_u64_ticks_is_rejected() {
+        // Tick conversion saturates instead of overflowing or wrapping into range
+        let result = SpliceInsertBuilder::new(1234)
+            .at_pts(Duration::MAX)
+            .unwrap()
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(BuilderError::DurationTooLarge {
+                field: "splice_time",
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn test_pts_adjustment_masking() {
         // Test that PTS adjustment is properly masked to 33 bits
@@ -597,6 +1074,31 @@ mod builder_tests {
             .upid(Upid::AdId("SHORT".to_string()));
         assert!(result.is_err());
 
+        // Test malformed AdID characters
+        let result = SegmentationDescriptorBuilder::new(1234, SegmentationType::ProgramStart)
+            .upid(Upid::AdId("abcd0001000h".to_string()));
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidAdId { ref value, .. }) if value == "abcd0001000h"
+        ));
+
+        // Test ISCI structure: 4 letters followed by 4 digits
+        let result = SegmentationDescriptorBuilder::new(1234, SegmentationType::ProgramStart)
+            .upid(Upid::Isci("ABCD1234".to_string()));
+        assert!(result.is_ok());
+        let result = SegmentationDescriptorBuilder::new(1234, SegmentationType::ProgramStart)
+            .upid(Upid::Isci("ABCD12345678".to_string()));
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidUpidLength {
+                expected: 8,
+                actual: 12
+            })
+        ));
+        let result = SegmentationDescriptorBuilder::new(1234, SegmentationType::ProgramStart)
+            .upid(Upid::Isci("ABC01234".to_string()));
+        assert!(matches!(result, Err(BuilderError::InvalidIsci { .. })));
+
         // Test valid URI
         let result = SegmentationDescriptorBuilder::new(1234, SegmentationType::ProgramStart)
             .upid(Upid::Uri("https://example.com/content/123".to_string()));
@@ -0,0 +1,251 @@
+//! Batch processing: one payload per input line, reported as NDJSON or CSV.
+
+use clap::ValueEnum;
+use scte35::input::{InputFormat, decode_input};
+use scte35::{
+    SegmentationType, SpliceCommand, SpliceDescriptor, SpliceInfoSection, parse_splice_info_section,
+};
+use std::io::{self, Write};
+
+/// A CSV output column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "snake_case")]
+pub enum Column {
+    /// 1-based input line number
+    Line,
+    /// The payload text as read
+    Payload,
+    /// `success` or `error`, as in the NDJSON `status` field
+    Status,
+    /// splice_event_id, or the first segmentation_event_id for other commands
+    EventId,
+    /// Splice command name
+    CommandType,
+    /// Program splice PTS in 90kHz ticks, with pts_adjustment applied
+    Pts,
+    /// Break or segmentation duration in seconds
+    Duration,
+    /// Segmentation types of all segmentation descriptors, separated by ` | `
+    SegmentationType,
+    /// UPIDs of all segmentation descriptors, separated by ` | `
+    Upid,
+    /// CRC-32 check result: `valid`, `invalid` or `error`; empty if the payload did not decode
+    Crc,
+    /// Error message for lines that failed to decode or parse
+    Error,
+}
+
+/// Separates the values of descriptors in one cell; MID UPIDs already use `; `.
+const LIST_SEPARATOR: &str = " | ";
+
+/// Columns written when `--columns` is not given.
+pub const DEFAULT_COLUMNS: &[Column] = &[
+    Column::Line,
+    Column::Status,
+    Column::EventId,
+    Column::CommandType,
+    Column::Pts,
+    Column::Duration,
+    Column::SegmentationType,
+    Column::Upid,
+    Column::Crc,
+    Column::Error,
+];
+
+/// Batch output format.
+pub enum BatchFormat<'a> {
+    /// One JSON object per line
+    Ndjson,
+    /// A header row followed by one row per payload
+    Csv(&'a [Column]),
+}
+
+/// The outcome of processing one input line.
+struct Entry<'a> {
+    line: usize,
+    payload: &'a str,
+    /// The decoded bytes, also for payloads that fail to parse
+    buffer: Option<Vec<u8>>,
+    result: Result<SpliceInfoSection, String>,
+}
+
+/// Decodes and parses every non-blank line of `input`, writing one record per line.
+///
+/// Failures are reported in the output and do not stop processing. Returns the number
+/// of lines that failed.
+pub fn run(
+    input: &[u8],
+    input_format: InputFormat,
+    format: BatchFormat<'_>,
+    out: &mut impl Write,
+) -> io::Result<usize> {
+    let text = String::from_utf8_lossy(input);
+    let mut failures = 0;
+
+    if let BatchFormat::Csv(columns) = format {
+        let header: Vec<String> = columns.iter().map(|column| column_name(*column)).collect();
+        writeln!(out, "{}", header.join(","))?;
+    }
+
+    for (index, line) in text.lines().enumerate() {
+        let payload = line.trim();
+        if payload.is_empty() {
+            continue;
+        }
+
+        let buffer = decode(payload, input_format);
+        let result = buffer
+            .as_ref()
+            .map_err(Clone::clone)
+            .and_then(|buffer| parse(buffer));
+        let entry = Entry {
+            line: index + 1,
+            payload,
+            buffer: buffer.ok(),
+            result,
+        };
+        if entry.result.is_err() {
+            failures += 1;
+        }
+
+        match format {
+            BatchFormat::Ndjson => writeln!(out, "{}", ndjson_record(&entry))?,
+            BatchFormat::Csv(columns) => {
+                let row: Vec<String> = columns
+                    .iter()
+                    .map(|column| csv_escape(&column_value(&entry, *column)))
+                    .collect();
+                writeln!(out, "{}", row.join(","))?;
+            }
+        }
+    }
+
+    out.flush()?;
+    Ok(failures)
+}
+
+/// Decodes and parses one payload, returning the section and its bytes.
+pub fn process(
+    payload: &str,
+    input_format: InputFormat,
+) -> Result<(SpliceInfoSection, Vec<u8>), String> {
+    let buffer = decode(payload, input_format)?;
+    let section = parse(&buffer)?;
+    Ok((section, buffer))
+}
+
+fn decode(payload: &str, input_format: InputFormat) -> Result<Vec<u8>, String> {
+    decode_input(payload.as_bytes(), input_format)
+        .map_err(|e| format!("Error decoding {} string: {}", e.format, e.reason))
+}
+
+fn parse(buffer: &[u8]) -> Result<SpliceInfoSection, String> {
+    parse_splice_info_section(buffer).map_err(|e| format!("Error parsing SpliceInfoSection: {e}"))
+}
+
+fn ndjson_record(entry: &Entry<'_>) -> serde_json::Value {
+    use serde_json::json;
+
+    let crc_validation = entry.buffer.as_deref().map(super::crc_validation_json);
+    match &entry.result {
+        Ok(section) => json!({
+            "line": entry.line,
+            "status": "success",
+            "data": section,
+            "crc_validation": crc_validation
+        }),
+        Err(error) => json!({
+            "line": entry.line,
+            "status": "error",
+            "payload": entry.payload,
+            "error": error,
+            "crc_validation": crc_validation
+        }),
+    }
+}
+
+fn column_name(column: Column) -> String {
+    column
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+fn column_value(entry: &Entry<'_>, column: Column) -> String {
+    let section = entry.result.as_ref().ok();
+    let segmentation = || {
+        section.into_iter().flat_map(|section| {
+            section
+                .splice_descriptors
+                .iter()
+                .filter_map(|descriptor| match descriptor {
+                    SpliceDescriptor::Segmentation(segmentation) => Some(segmentation),
+                    _ => None,
+                })
+        })
+    };
+
+    match column {
+        Column::Line => entry.line.to_string(),
+        Column::Payload => entry.payload.to_string(),
+        Column::Status => match entry.result {
+            Ok(_) => "success".to_string(),
+            Err(_) => "error".to_string(),
+        },
+        Column::EventId => {
+            let event_id = match section.map(|section| &section.splice_command) {
+                Some(SpliceCommand::SpliceInsert(insert)) => Some(insert.splice_event_id),
+                _ => segmentation()
+                    .next()
+                    .map(|descriptor| descriptor.segmentation_event_id),
+            };
+            event_id.map(|id| format!("0x{id:08x}")).unwrap_or_default()
+        }
+        Column::CommandType => section
+            .map(|section| section.splice_command.name().to_string())
+            .unwrap_or_default(),
+        Column::Pts => section
+            .and_then(SpliceInfoSection::effective_program_pts)
+            .map(|pts| pts.ticks().to_string())
+            .unwrap_or_default(),
+        Column::Duration => {
+            let duration = match section.map(|section| &section.splice_command) {
+                Some(SpliceCommand::SpliceInsert(insert)) => insert
+                    .break_duration
+                    .as_ref()
+                    .map(|duration| duration.to_duration()),
+                _ => segmentation().find_map(|descriptor| descriptor.duration()),
+            };
+            duration
+                .map(|duration| format!("{:.3}", duration.as_secs_f64()))
+                .unwrap_or_default()
+        }
+        Column::SegmentationType => segmentation()
+            .map(|descriptor| {
+                SegmentationType::from_id(descriptor.segmentation_type_id).to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(LIST_SEPARATOR),
+        Column::Upid => segmentation()
+            .filter_map(|descriptor| descriptor.upid_as_string())
+            .collect::<Vec<_>>()
+            .join(LIST_SEPARATOR),
+        // Checked on the decoded bytes, since a CRC mismatch also fails the parse
+        Column::Crc => match entry.buffer.as_deref().map(scte35::validate_scte35_crc) {
+            Some(Ok(true)) => "valid".to_string(),
+            Some(Ok(false)) => "invalid".to_string(),
+            Some(Err(_)) => "error".to_string(),
+            None => String::new(),
+        },
+        Column::Error => entry.result.as_ref().err().cloned().unwrap_or_default(),
+    }
+}
+
+/// Quotes a CSV field when it contains a delimiter, quote or line break (RFC 4180).
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
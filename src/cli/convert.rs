@@ -0,0 +1,136 @@
+//! The `convert` subcommand: translates cues between carriage formats.
+
+use clap::Args;
+use scte35::convert::{EncodeOptions, Format, decode, encode};
+use scte35::fmt::parse_utc_time;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Arguments of `scte35 convert`.
+#[derive(Debug, Args)]
+pub struct ConvertArgs {
+    /// Cue to convert; read from stdin when omitted
+    #[arg(value_name = "INPUT", conflicts_with = "file")]
+    input: Option<String>,
+
+    /// Read the input from a file instead of the command line
+    #[arg(short = 'f', long = "file", value_name = "PATH")]
+    file: Option<PathBuf>,
+
+    /// Format of the input
+    #[arg(long = "from", value_enum)]
+    from: Format,
+
+    /// Format to write
+    #[arg(long = "to", value_enum)]
+    to: Format,
+
+    /// ID of written HLS tags and DASH events; defaults to the event id
+    #[arg(long = "id", value_name = "ID")]
+    id: Option<String>,
+
+    /// START-DATE of written HLS tags, e.g. `2024-01-01T00:00:00Z`; defaults to now
+    #[arg(long = "start-date", value_name = "TIME", value_parser = parse_start_date)]
+    start_date: Option<SystemTime>,
+}
+
+/// Runs the `convert` subcommand.
+///
+/// Line-oriented input (base64, hex and HLS tags) is converted line by line as it is
+/// read, so the command can sit in a pipeline; other input is read in full first.
+/// Inputs that fail to convert are reported on stderr and the rest are still written.
+pub fn run(args: &ConvertArgs) -> Result<(), String> {
+    let options = EncodeOptions {
+        id: args.id.clone(),
+        start_date: args.start_date,
+    };
+    let mut converter = Converter {
+        args,
+        options,
+        stdout: io::stdout().lock(),
+        converted: 0,
+        failed: 0,
+    };
+
+    if let Some(input) = &args.input {
+        converter.convert(input, None)?;
+    } else {
+        let reader: Box<dyn Read> = match &args.file {
+            Some(path) => Box::new(
+                File::open(path).map_err(|e| format!("Error opening {}: {e}", path.display()))?,
+            ),
+            None => Box::new(io::stdin().lock()),
+        };
+        let mut reader = BufReader::new(reader);
+        if args.from.is_line_oriented() {
+            for (index, line) in reader.lines().enumerate() {
+                let line = line.map_err(|e| format!("Error reading input: {e}"))?;
+                converter.convert(&line, Some(index + 1))?;
+            }
+        } else {
+            let mut input = String::new();
+            reader
+                .read_to_string(&mut input)
+                .map_err(|e| format!("Error reading input: {e}"))?;
+            converter.convert(&input, None)?;
+        }
+    }
+
+    match (converter.converted, converter.failed) {
+        (0, 0) => Err(format!("No cues found in {} input", args.from)),
+        (_, 0) => Ok(()),
+        (converted, failed) => Err(format!(
+            "{failed} of {} inputs could not be converted",
+            converted + failed
+        )),
+    }
+}
+
+struct Converter<'a> {
+    args: &'a ConvertArgs,
+    options: EncodeOptions,
+    stdout: io::StdoutLock<'static>,
+    converted: usize,
+    failed: usize,
+}
+
+impl Converter<'_> {
+    /// Converts every cue in `input` and writes each as soon as it is ready.
+    ///
+    /// Conversion errors are reported and counted; only write errors are returned.
+    fn convert(&mut self, input: &str, line: Option<usize>) -> Result<(), String> {
+        let location = line
+            .map(|line| format!("line {line}: "))
+            .unwrap_or_default();
+        let cues = match decode(input, self.args.from) {
+            Ok(cues) => cues,
+            Err(e) => {
+                eprintln!("{location}{e}");
+                self.failed += 1;
+                return Ok(());
+            }
+        };
+
+        for bytes in cues {
+            match encode(&bytes, self.args.to, &self.options) {
+                Ok(output) => {
+                    writeln!(self.stdout, "{output}")
+                        .and_then(|()| self.stdout.flush())
+                        .map_err(|e| format!("Error writing output: {e}"))?;
+                    self.converted += 1;
+                }
+                Err(e) => {
+                    eprintln!("{location}{e}");
+                    self.failed += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_start_date(text: &str) -> Result<SystemTime, String> {
+    parse_utc_time(text).ok_or_else(|| format!("invalid UTC time '{text}'"))
+}
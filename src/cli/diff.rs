@@ -0,0 +1,79 @@
+//! The `diff` subcommand: field-level comparison of two messages.
+
+use clap::{Args, ValueEnum};
+use scte35::diff::{Change, diff};
+use scte35::input::{InputFormat, decode_input};
+use scte35::{SpliceInfoSection, parse_splice_info_section};
+
+/// Output format of `scte35 diff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DiffFormat {
+    /// One line per change: `~` modified, `+` added, `-` removed
+    Text,
+    /// A JSON object with the list of changes
+    Json,
+}
+
+/// Arguments of `scte35 diff`.
+#[derive(Debug, Args)]
+pub struct DiffArgs {
+    /// First SCTE-35 payload (base64, URL-safe base64 or hex)
+    #[arg(value_name = "A")]
+    old: String,
+
+    /// Second SCTE-35 payload
+    #[arg(value_name = "B")]
+    new: String,
+
+    /// Encoding of both payloads
+    #[arg(short = 'i', long = "input-format", value_enum, default_value_t = InputFormat::Auto)]
+    input_format: InputFormat,
+
+    /// Output format
+    #[arg(short = 'o', long = "output", value_enum, default_value_t = DiffFormat::Text)]
+    output: DiffFormat,
+
+    /// Ignore CRC-32 and length fields, which change whenever the content does
+    #[arg(long = "ignore-lengths")]
+    ignore_lengths: bool,
+}
+
+/// Runs the `diff` subcommand, returning whether the two messages are identical.
+pub fn run(args: &DiffArgs) -> Result<bool, String> {
+    let old = parse(&args.old, args.input_format, "A")?;
+    let new = parse(&args.new, args.input_format, "B")?;
+
+    let changes: Vec<Change> = diff(&old, &new)
+        .into_iter()
+        .filter(|change| !(args.ignore_lengths && change.is_derived()))
+        .collect();
+
+    match args.output {
+        DiffFormat::Text => {
+            for change in &changes {
+                println!("{change}");
+            }
+        }
+        DiffFormat::Json => {
+            let output = serde_json::json!({
+                "identical": changes.is_empty(),
+                "changes": changes,
+            });
+            let output = serde_json::to_string_pretty(&output)
+                .map_err(|e| format!("Error serializing to JSON: {e}"))?;
+            println!("{output}");
+        }
+    }
+
+    Ok(changes.is_empty())
+}
+
+fn parse(payload: &str, format: InputFormat, label: &str) -> Result<SpliceInfoSection, String> {
+    let buffer = decode_input(payload.as_bytes(), format).map_err(|e| {
+        format!(
+            "Error decoding {label} as {} string: {}",
+            e.format, e.reason
+        )
+    })?;
+    parse_splice_info_section(&buffer).map_err(|e| format!("Error parsing {label}: {e}"))
+}
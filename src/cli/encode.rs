@@ -0,0 +1,200 @@
+//! The `encode` subcommand: builds messages from JSON documents or command-line flags.
+
+use super::PayloadEncoding;
+use clap::Args;
+use scte35::builders::{
+    SegmentationDescriptorBuilder, SpliceInfoSectionBuilder, TimeSignalBuilder, Upid,
+};
+use scte35::encoding::CrcEncodable;
+use scte35::{SegmentationType, SpliceInfoSection};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Arguments of `scte35 encode`.
+#[derive(Debug, Args)]
+pub struct EncodeArgs {
+    /// Read the JSON document from a file instead of stdin
+    #[arg(
+        short = 'f',
+        long = "file",
+        value_name = "PATH",
+        conflicts_with = "time_signal"
+    )]
+    file: Option<PathBuf>,
+
+    /// Output encoding
+    #[arg(short = 'o', long = "output", value_enum, default_value_t = PayloadEncoding::Base64)]
+    output: PayloadEncoding,
+
+    /// Build a time_signal() message from flags instead of reading JSON
+    #[arg(long = "time-signal")]
+    time_signal: bool,
+
+    /// Splice time, e.g. `10s`, `1500ms` or `900000t` (90kHz ticks); immediate when omitted
+    #[arg(long = "pts", value_name = "TIME", value_parser = parse_time, requires = "time_signal")]
+    pts: Option<Duration>,
+
+    /// Add a segmentation descriptor with this segmentation_type_id, e.g. `0x34`
+    #[arg(long = "seg-type", value_name = "ID", value_parser = parse_segmentation_type, requires = "time_signal")]
+    seg_type: Option<SegmentationType>,
+
+    /// segmentation_event_id of the segmentation descriptor
+    #[arg(long = "event-id", value_name = "ID", value_parser = parse_u32, default_value = "1", requires = "seg_type")]
+    event_id: u32,
+
+    /// Segmentation duration, e.g. `30s`
+    #[arg(long = "duration", value_name = "TIME", value_parser = parse_time, requires = "seg_type")]
+    duration: Option<Duration>,
+
+    /// Segmentation UPID as `type:value`, e.g. `adid:ABCD0001000H`; repeat for a MID UPID
+    #[arg(long = "upid", value_name = "TYPE:VALUE", value_parser = parse_upid, requires = "seg_type")]
+    upids: Vec<Upid>,
+}
+
+/// Runs the `encode` subcommand.
+pub fn run(args: &EncodeArgs) -> Result<(), String> {
+    let section = if args.time_signal {
+        build_time_signal(args).map_err(|e| format!("Error building message: {e}"))?
+    } else {
+        read_document(args)?
+    };
+
+    let bytes = section
+        .encode_with_crc()
+        .map_err(|e| format!("Error encoding message: {e}"))?;
+
+    let mut stdout = io::stdout().lock();
+    stdout
+        .write_all(&args.output.encode(&bytes))
+        .and_then(|()| match args.output {
+            PayloadEncoding::Binary => Ok(()),
+            _ => writeln!(stdout),
+        })
+        .map_err(|e| format!("Error writing output: {e}"))
+}
+
+fn build_time_signal(args: &EncodeArgs) -> scte35::builders::BuilderResult<SpliceInfoSection> {
+    let time_signal = match args.pts {
+        Some(pts) => TimeSignalBuilder::new().at_pts(pts)?,
+        None => TimeSignalBuilder::new().immediate(),
+    }
+    .build()?;
+    let mut section = SpliceInfoSectionBuilder::new().time_signal(time_signal);
+
+    if let Some(segmentation_type) = args.seg_type {
+        let mut descriptor = SegmentationDescriptorBuilder::new(args.event_id, segmentation_type);
+        if let Some(duration) = args.duration {
+            descriptor = descriptor.duration(duration)?;
+        }
+        descriptor = match args.upids.as_slice() {
+            [] => descriptor,
+            [upid] => descriptor.upid(upid.clone())?,
+            upids => descriptor.upid(Upid::Mid(upids.to_vec()))?,
+        };
+        section = section.add_segmentation_descriptor(descriptor.build()?);
+    }
+
+    section.build()
+}
+
+/// Reads a splice_info_section from JSON.
+///
+/// Accepts both the bare section and the `{"status": ..., "data": ...}` envelope written
+/// by `scte35 -o json`.
+fn read_document(args: &EncodeArgs) -> Result<SpliceInfoSection, String> {
+    let input = match &args.file {
+        Some(path) => std::fs::read(path),
+        None => {
+            let mut input = Vec::new();
+            io::stdin().read_to_end(&mut input).map(|_| input)
+        }
+    }
+    .map_err(|e| format!("Error reading input: {e}"))?;
+
+    let mut document: serde_json::Value =
+        serde_json::from_slice(&input).map_err(|e| format!("Error parsing JSON: {e}"))?;
+    if document.get("status").is_some()
+        && let Some(data) = document.get_mut("data")
+    {
+        document = data.take();
+    }
+
+    serde_json::from_value(document).map_err(|e| format!("Invalid splice_info_section: {e}"))
+}
+
+/// Parses a time such as `10s`, `1.5s`, `1500ms`, `2m`, `1h` or `900000t` (90kHz ticks).
+///
+/// A bare number is taken as seconds.
+fn parse_time(text: &str) -> Result<Duration, String> {
+    let text = text.trim();
+    if let Some(ticks) = text.strip_suffix('t') {
+        let ticks: u64 = ticks
+            .parse()
+            .map_err(|_| format!("invalid tick count '{ticks}'"))?;
+        let nanos = ticks
+            .checked_mul(100_000)
+            .ok_or_else(|| format!("tick count '{ticks}' is too large"))?;
+        return Ok(Duration::from_nanos(nanos / 9));
+    }
+
+    let (number, scale) = if let Some(number) = text.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = text.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = text.strip_suffix('m') {
+        (number, 60.0)
+    } else if let Some(number) = text.strip_suffix('h') {
+        (number, 3600.0)
+    } else {
+        (text, 1.0)
+    };
+
+    let value: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid time '{text}'"))?;
+    Duration::try_from_secs_f64(value * scale).map_err(|_| format!("invalid time '{text}'"))
+}
+
+fn parse_u32(text: &str) -> Result<u32, String> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => text.parse(),
+    }
+    .map_err(|_| format!("invalid number '{text}'"))
+}
+
+fn parse_segmentation_type(text: &str) -> Result<SegmentationType, String> {
+    let id = parse_u32(text)?;
+    let id = u8::try_from(id).map_err(|_| format!("segmentation_type_id {text} exceeds 0xFF"))?;
+    let segmentation_type = SegmentationType::from_id(id);
+    if segmentation_type == SegmentationType::NotIndicated && id != 0 {
+        return Err(format!("segmentation_type_id 0x{id:02X} is not defined"));
+    }
+    Ok(segmentation_type)
+}
+
+/// Parses a `type:value` UPID, e.g. `adid:ABCD0001000H` or `eidr:10.5240/0000-...`.
+fn parse_upid(text: &str) -> Result<Upid, String> {
+    let (kind, value) = text
+        .split_once(':')
+        .ok_or_else(|| format!("expected TYPE:VALUE, got '{text}'"))?;
+
+    let upid = match kind.to_ascii_lowercase().as_str() {
+        "adid" => Upid::AdId(value.to_string()),
+        "isci" => Upid::Isci(value.to_string()),
+        "tid" => Upid::new_tid(value).map_err(|e| e.to_string())?,
+        "eidr" => Upid::new_eidr(value).map_err(|e| e.to_string())?,
+        "isan" => Upid::new_isan(value).map_err(|e| e.to_string())?,
+        "umid" => Upid::new_umid(value).map_err(|e| e.to_string())?,
+        "airing" | "airingid" => Upid::new_airing_id(value).map_err(|e| e.to_string())?,
+        "uri" => Upid::Uri(value.to_string()),
+        "adi" => Upid::Adi(value.as_bytes().to_vec()),
+        _ => {
+            return Err(format!(
+                "unknown UPID type '{kind}' (expected adid, isci, tid, eidr, isan, umid, airing, uri or adi)"
+            ));
+        }
+    };
+    Ok(upid)
+}
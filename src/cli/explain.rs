@@ -0,0 +1,131 @@
+//! The `explain` subcommand: an annotated, bit-level dump of a message.
+
+use super::InputArgs;
+use clap::Args;
+use scte35::SegmentationType;
+use scte35::fmt::{format_identifier_to_string, format_private_data};
+use scte35::parser::{TracedField, trace_splice_info_section};
+use scte35::upid::{SegmentationUpidType, format_upid};
+
+/// Raw bits longer than this are abbreviated.
+const MAX_RAW_BITS: usize = 40;
+
+/// Arguments of `scte35 explain`.
+#[derive(Debug, Args)]
+pub struct ExplainArgs {
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// Runs the `explain` subcommand.
+pub fn run(args: &ExplainArgs) -> Result<(), String> {
+    let buffer = args.input.read()?;
+    let trace = trace_splice_info_section(&buffer);
+
+    println!(
+        "{:>6} {:>5}  {:<44} {:<42} VALUE",
+        "BIT", "WIDTH", "FIELD", "RAW"
+    );
+    let mut upid_type = SegmentationUpidType::NotUsed;
+    for field in &trace.fields {
+        let name = format!("{}{}", "  ".repeat(field.depth), field.name);
+        if field.group {
+            println!("{:>6} {:>5}  {name}", field.bit_offset, field.bit_width);
+            continue;
+        }
+        if field.name == "segmentation_upid_type"
+            && let Some(value) = field.value
+        {
+            upid_type = SegmentationUpidType::from(value as u8);
+        }
+        println!(
+            "{:>6} {:>5}  {name:<44} {:<42} {}",
+            field.bit_offset,
+            field.bit_width,
+            raw_bits(field, &buffer),
+            describe(field, &buffer, upid_type)
+        );
+    }
+
+    let consumed = trace
+        .fields
+        .first()
+        .map(|section| (section.bit_offset + section.bit_width).div_ceil(8))
+        .unwrap_or(0);
+    if consumed < buffer.len() {
+        println!(
+            "({} trailing bytes after the section)",
+            buffer.len() - consumed
+        );
+    }
+
+    trace
+        .result
+        .map(|_| ())
+        .map_err(|e| format!("Error parsing SpliceInfoSection: {e}"))
+}
+
+fn raw_bits(field: &TracedField, buffer: &[u8]) -> String {
+    if field.bit_width <= MAX_RAW_BITS {
+        return field.raw_bits(buffer);
+    }
+    let mut bits = field.raw_bits(buffer);
+    bits.truncate(MAX_RAW_BITS - 3);
+    bits + "..."
+}
+
+/// Formats a field value, adding an interpretation for fields where the number alone is
+/// not very telling.
+fn describe(field: &TracedField, buffer: &[u8], upid_type: SegmentationUpidType) -> String {
+    let Some(value) = field.value else {
+        let bytes = field.bytes(buffer).unwrap_or_default();
+        if field.name == "segmentation_upid"
+            && let Some(text) = format_upid(upid_type, bytes)
+        {
+            return text;
+        }
+        return format_private_data(bytes);
+    };
+
+    let note = match field.name {
+        "splice_command_type" => Some(command_name(value as u8).to_string()),
+        "pts_time" | "pts_adjustment" | "duration" | "segmentation_duration" | "pts_offset" => {
+            Some(format!("{:.6}s", value as f64 / 90_000.0))
+        }
+        "identifier" => Some(format_identifier_to_string(value as u32)),
+        "descriptor_tag" => Some(descriptor_name(value as u8).to_string()),
+        "segmentation_upid_type" => Some(SegmentationUpidType::from(value as u8).to_string()),
+        "segmentation_type_id" => Some(SegmentationType::from_id(value as u8).to_string()),
+        _ => None,
+    };
+
+    let hex_digits = field.bit_width.div_ceil(4);
+    match note {
+        Some(note) => format!("{value} (0x{value:0hex_digits$X}) {note}"),
+        None if field.bit_width == 1 => value.to_string(),
+        None => format!("{value} (0x{value:0hex_digits$X})"),
+    }
+}
+
+fn command_name(command_type: u8) -> &'static str {
+    match command_type {
+        0x00 => "SpliceNull",
+        0x04 => "SpliceSchedule",
+        0x05 => "SpliceInsert",
+        0x06 => "TimeSignal",
+        0x07 => "BandwidthReservation",
+        0xFF => "PrivateCommand",
+        _ => "Unknown",
+    }
+}
+
+fn descriptor_name(tag: u8) -> &'static str {
+    match tag {
+        0x00 => "avail_descriptor",
+        0x01 => "DTMF_descriptor",
+        0x02 => "segmentation_descriptor",
+        0x03 => "time_descriptor",
+        0x04 => "audio_descriptor",
+        _ => "private descriptor",
+    }
+}
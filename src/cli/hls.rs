@@ -0,0 +1,260 @@
+//! The `hls` subcommand: a timeline of the ad markers in a media playlist.
+
+use clap::{Args, ValueEnum};
+use scte35::fmt::format_utc_time;
+use scte35::hls::{CueKind, CueTag, HlsCue, extract_cues};
+use scte35::{SpliceCommand, SpliceDescriptor, SpliceInfoSection, parse_splice_info_section};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Declared and decoded durations further apart than this are reported as a mismatch.
+const DURATION_TOLERANCE: Duration = Duration::from_millis(1);
+
+/// Output format of `scte35 hls`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum HlsFormat {
+    /// One row per cue, with notes on breaks and mismatches
+    Text,
+    /// A JSON object with every cue and break
+    Json,
+}
+
+/// Arguments of `scte35 hls`.
+#[derive(Debug, Args)]
+pub struct HlsArgs {
+    /// Local media playlist (.m3u8)
+    #[arg(value_name = "PLAYLIST")]
+    playlist: PathBuf,
+
+    /// Output format
+    #[arg(short = 'o', long = "output", value_enum, default_value_t = HlsFormat::Text)]
+    output: HlsFormat,
+}
+
+/// A cue with its decoded section and the checks made against it.
+struct Entry {
+    cue: HlsCue,
+    section: Option<Result<SpliceInfoSection, String>>,
+    /// Duration carried by the section, and the field it came from
+    decoded_duration: Option<(&'static str, Duration)>,
+    /// Length of the break closed by this cue, and the duration declared when it opened
+    break_length: Option<(Duration, Option<Duration>)>,
+}
+
+impl Entry {
+    fn duration_mismatch(&self) -> bool {
+        match (self.cue.duration, self.decoded_duration) {
+            (Some(declared), Some((_, decoded))) => declared.abs_diff(decoded) > DURATION_TOLERANCE,
+            _ => false,
+        }
+    }
+}
+
+/// Runs the `hls` subcommand.
+pub fn run(args: &HlsArgs) -> Result<(), String> {
+    let playlist = std::fs::read_to_string(&args.playlist)
+        .map_err(|e| format!("Error reading {}: {e}", args.playlist.display()))?;
+    let cues = extract_cues(&playlist).map_err(|e| e.to_string())?;
+
+    let mut entries: Vec<Entry> = cues.into_iter().map(decode).collect();
+    match_breaks(&mut entries);
+    let mismatches = entries
+        .iter()
+        .filter(|entry| entry.duration_mismatch())
+        .count();
+
+    match args.output {
+        HlsFormat::Text => {
+            println!(
+                "{:>8} {:>12}  {:<24} {:<28} EVENT",
+                "SEGMENT", "TIME", "PROGRAM-DATE-TIME", "TAG"
+            );
+            for entry in &entries {
+                print_entry(entry);
+            }
+            println!("{} cues, {mismatches} duration mismatches", entries.len());
+        }
+        HlsFormat::Json => {
+            let output = serde_json::json!({
+                "playlist": args.playlist.display().to_string(),
+                "cues": entries.iter().map(json_record).collect::<Vec<_>>(),
+                "summary": {
+                    "cues": entries.len(),
+                    "duration_mismatches": mismatches,
+                },
+            });
+            let output = serde_json::to_string_pretty(&output)
+                .map_err(|e| format!("Error serializing to JSON: {e}"))?;
+            println!("{output}");
+        }
+    }
+
+    Ok(())
+}
+
+fn decode(cue: HlsCue) -> Entry {
+    let section = cue.decode().map(|bytes| {
+        let bytes = bytes.map_err(|e| e.to_string())?;
+        parse_splice_info_section(&bytes)
+            .map_err(|e| format!("Error parsing SpliceInfoSection: {e}"))
+    });
+    let decoded_duration = match &section {
+        Some(Ok(section)) => decoded_duration(section),
+        _ => None,
+    };
+    Entry {
+        cue,
+        section,
+        decoded_duration,
+        break_length: None,
+    }
+}
+
+/// Returns the break_duration of a splice_insert, else the first segmentation_duration.
+fn decoded_duration(section: &SpliceInfoSection) -> Option<(&'static str, Duration)> {
+    if let SpliceCommand::SpliceInsert(insert) = &section.splice_command
+        && let Some(duration) = &insert.break_duration
+    {
+        return Some(("break_duration", duration.to_duration()));
+    }
+    section
+        .splice_descriptors
+        .iter()
+        .find_map(|descriptor| match descriptor {
+            SpliceDescriptor::Segmentation(segmentation) => segmentation.duration(),
+            _ => None,
+        })
+        .map(|duration| ("segmentation_duration", duration))
+}
+
+/// Pairs each break start with the next break end and records the break length on the end.
+///
+/// Several markers for the same break (say a DATERANGE and a CUE-OUT) usually sit on the
+/// same segment, so only the first start and the first end of a break are paired.
+fn match_breaks(entries: &mut [Entry]) {
+    let mut open: Option<usize> = None;
+    for index in 0..entries.len() {
+        match entries[index].cue.kind {
+            CueKind::Out if open.is_none() => open = Some(index),
+            CueKind::In => {
+                let Some(start) = open.take() else {
+                    continue;
+                };
+                let (out, cue_in) = (&entries[start].cue, &entries[index].cue);
+                let length = match (out.start_date, cue_in.start_date) {
+                    (Some(out_date), Some(in_date)) => {
+                        in_date.duration_since(out_date).unwrap_or_default()
+                    }
+                    _ => cue_in.playlist_time.saturating_sub(out.playlist_time),
+                };
+                let declared = out.duration.or(entries[start]
+                    .decoded_duration
+                    .map(|(_, duration)| duration));
+                entries[index].break_length = Some((length, declared));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn print_entry(entry: &Entry) {
+    let cue = &entry.cue;
+    let mut tag = cue.tag.to_string();
+    if let Some(attribute) = daterange_attribute(cue) {
+        tag = format!("{tag} {attribute}");
+    }
+
+    let mut event = Vec::new();
+    if let Some(id) = &cue.id {
+        event.push(format!("id={id}"));
+    }
+    if let Some(duration) = cue.duration {
+        event.push(format!("duration={:.3}s", duration.as_secs_f64()));
+    }
+    match &entry.section {
+        Some(Ok(section)) => event.push(section.to_string()),
+        Some(Err(error)) => event.push(error.clone()),
+        None => {}
+    }
+
+    let row = format!(
+        "{:>8} {:>12}  {:<24} {:<28} {}",
+        cue.segment,
+        format!("{:.3}s", cue.playlist_time.as_secs_f64()),
+        cue.program_date_time
+            .map(format_utc_time)
+            .unwrap_or_else(|| "-".to_string()),
+        tag,
+        event.join(" ")
+    );
+    println!("{}", row.trim_end());
+
+    if entry.duration_mismatch()
+        && let (Some(declared), Some((field, decoded))) = (cue.duration, entry.decoded_duration)
+    {
+        println!(
+            "{:>8} ! tag duration {:.3}s does not match decoded {field} {:.3}s",
+            "",
+            declared.as_secs_f64(),
+            decoded.as_secs_f64()
+        );
+    }
+    if let Some((length, declared)) = entry.break_length {
+        let declared = declared
+            .map(|declared| format!(" (declared {:.3}s)", declared.as_secs_f64()))
+            .unwrap_or_default();
+        println!(
+            "{:>8}   break length {:.3}s{declared}",
+            "",
+            length.as_secs_f64()
+        );
+    }
+}
+
+fn daterange_attribute(cue: &HlsCue) -> Option<&'static str> {
+    (cue.tag == CueTag::DateRange).then(|| cue.kind.daterange_attribute())
+}
+
+fn json_record(entry: &Entry) -> serde_json::Value {
+    use serde_json::json;
+
+    let cue = &entry.cue;
+    let seconds = |duration: Option<Duration>| duration.map(|duration| duration.as_secs_f64());
+    let mut record = json!({
+        "line": cue.line,
+        "tag": cue.tag.to_string(),
+        "attribute": daterange_attribute(cue),
+        "kind": match cue.kind {
+            CueKind::Out => "out",
+            CueKind::In => "in",
+            CueKind::Command => "command",
+        },
+        "id": cue.id,
+        "segment": cue.segment,
+        "playlist_time": cue.playlist_time.as_secs_f64(),
+        "program_date_time": cue.program_date_time.map(format_utc_time),
+        "start_date": cue.start_date.map(format_utc_time),
+        "duration": seconds(cue.duration),
+        "payload": cue.payload,
+        "decoded_duration": seconds(entry.decoded_duration.map(|(_, duration)| duration)),
+        "duration_mismatch": entry.duration_mismatch(),
+    });
+    match &entry.section {
+        Some(Ok(section)) => {
+            record["status"] = json!("success");
+            record["data"] = json!(section);
+        }
+        Some(Err(error)) => {
+            record["status"] = json!("error");
+            record["error"] = json!(error);
+        }
+        None => {}
+    }
+    if let Some((length, declared)) = entry.break_length {
+        record["break"] = json!({
+            "length": length.as_secs_f64(),
+            "declared": seconds(declared),
+        });
+    }
+    record
+}
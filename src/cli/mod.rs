@@ -0,0 +1,148 @@
+//! Building blocks of the `scte35` command-line tool.
+
+pub mod batch;
+pub mod convert;
+pub mod diff;
+pub mod encode;
+pub mod explain;
+pub mod hls;
+pub mod summary;
+pub mod ts;
+pub mod validate;
+
+use clap::Args;
+use scte35::input::{InputFormat, decode_input};
+use scte35::validate_scte35_crc;
+use std::io::{self, IsTerminal, Read};
+use std::path::PathBuf;
+
+/// Where to read a payload from and how it is encoded.
+#[derive(Debug, Args)]
+pub struct InputArgs {
+    /// SCTE-35 payload (base64, URL-safe base64 or hex); read from stdin when omitted
+    #[arg(value_name = "PAYLOAD", conflicts_with = "file")]
+    pub payload: Option<String>,
+
+    /// Read the payload from a file instead of the command line
+    #[arg(short = 'f', long = "file", value_name = "PATH")]
+    pub file: Option<PathBuf>,
+
+    /// Encoding of the payload
+    #[arg(short = 'i', long = "input-format", value_enum, default_value_t = InputFormat::Auto)]
+    pub input_format: InputFormat,
+}
+
+impl InputArgs {
+    /// Reads the raw payload from the positional argument, `--file` or stdin.
+    pub fn read_raw(&self) -> io::Result<Vec<u8>> {
+        if let Some(payload) = &self.payload {
+            Ok(payload.as_bytes().to_vec())
+        } else if let Some(path) = &self.file {
+            std::fs::read(path)
+        } else {
+            let mut input = Vec::new();
+            io::stdin().read_to_end(&mut input)?;
+            Ok(input)
+        }
+    }
+
+    /// Reads and decodes the payload into section bytes.
+    pub fn read(&self) -> Result<Vec<u8>, String> {
+        let input = self
+            .read_raw()
+            .map_err(|e| format!("Error reading input: {e}"))?;
+        decode_input(&input, self.input_format)
+            .map_err(|e| format!("Error decoding {} string: {}", e.format, e.reason))
+    }
+}
+
+/// Builds the `crc_validation` object included in JSON output.
+pub fn crc_validation_json(buffer: &[u8]) -> serde_json::Value {
+    use serde_json::json;
+
+    match validate_scte35_crc(buffer) {
+        Ok(valid) => json!({
+            "valid": valid,
+            "error": null
+        }),
+        Err(e) => json!({
+            "valid": false,
+            "error": e.to_string()
+        }),
+    }
+}
+
+/// Text or binary encoding of an output payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PayloadEncoding {
+    /// Standard base64
+    Base64,
+    /// Uppercase hex with a `0x` prefix, as used in HLS `#EXT-X-DATERANGE` tags
+    Hex,
+    /// Raw section bytes
+    Binary,
+}
+
+impl PayloadEncoding {
+    /// Encodes section bytes for output.
+    pub fn encode(self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            PayloadEncoding::Base64 => data_encoding::BASE64.encode(bytes).into_bytes(),
+            PayloadEncoding::Hex => {
+                format!("0x{}", data_encoding::HEXUPPER.encode(bytes)).into_bytes()
+            }
+            PayloadEncoding::Binary => bytes.to_vec(),
+        }
+    }
+}
+
+/// When to color terminal output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    /// Always color
+    Always,
+    /// Never color
+    Never,
+}
+
+impl ColorChoice {
+    /// Resolves the choice against stdout and the `NO_COLOR` convention.
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                    && io::stdout().is_terminal()
+            }
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// ANSI styles used in colored output.
+#[derive(Debug, Clone, Copy)]
+pub enum Style {
+    Bold,
+    Dim,
+    Red,
+    Green,
+    Yellow,
+}
+
+/// Wraps `text` in the ANSI escape codes for `style` when `enabled`.
+pub fn paint(text: &str, style: Style, enabled: bool) -> String {
+    if !enabled {
+        return text.to_string();
+    }
+    let code = match style {
+        Style::Bold => "1",
+        Style::Dim => "2",
+        Style::Red => "31",
+        Style::Green => "32",
+        Style::Yellow => "33",
+    };
+    format!("\x1b[{code}m{text}\x1b[0m")
+}
@@ -0,0 +1,164 @@
+//! Summary mode: one human-readable line per cue, with optional detail lines.
+
+use super::batch;
+use super::{Style, paint};
+use scte35::input::{InputFormat, decode_input, detect_format};
+use scte35::{SpliceDescriptor, SpliceInfoSection, parse_splice_info_section, validate_scte35_crc};
+use std::io::{self, Write};
+
+/// How summary lines are written.
+pub struct SummaryOptions {
+    /// Whether to use ANSI colors
+    pub color: bool,
+    /// Number of `-v` flags: 1 adds header, CRC and descriptor lines, 2 adds the payload
+    pub verbosity: u8,
+}
+
+/// Writes a summary of every cue in `input`.
+///
+/// Text input is read one payload per non-blank line; binary input is a single section.
+/// Failures are reported in place of the summary and do not stop processing. Returns
+/// the number of payloads that failed.
+pub fn run(
+    input: &[u8],
+    input_format: InputFormat,
+    options: &SummaryOptions,
+    out: &mut impl Write,
+) -> io::Result<usize> {
+    let format = match input_format {
+        InputFormat::Auto => detect_format(input),
+        format => format,
+    };
+    let mut failures = 0;
+
+    if format == InputFormat::Binary {
+        let result = decode_input(input, format)
+            .map_err(|e| format!("Error decoding {} string: {}", e.format, e.reason))
+            .and_then(|buffer| {
+                parse_splice_info_section(&buffer)
+                    .map(|section| (section, buffer))
+                    .map_err(|e| format!("Error parsing SpliceInfoSection: {e}"))
+            });
+        if result.is_err() {
+            failures += 1;
+        }
+        write_entry(out, None, None, &result, options)?;
+    } else {
+        let text = String::from_utf8_lossy(input);
+        for (index, line) in text.lines().enumerate() {
+            let payload = line.trim();
+            if payload.is_empty() {
+                continue;
+            }
+            let result = batch::process(payload, input_format);
+            if result.is_err() {
+                failures += 1;
+            }
+            write_entry(out, Some(index + 1), Some(payload), &result, options)?;
+        }
+    }
+
+    out.flush()?;
+    Ok(failures)
+}
+
+fn write_entry(
+    out: &mut impl Write,
+    line: Option<usize>,
+    payload: Option<&str>,
+    result: &Result<(SpliceInfoSection, Vec<u8>), String>,
+    options: &SummaryOptions,
+) -> io::Result<()> {
+    let color = options.color;
+    let (section, buffer) = match result {
+        Ok(entry) => entry,
+        Err(error) => {
+            let location = line
+                .map(|line| format!("line {line}: "))
+                .unwrap_or_default();
+            return writeln!(
+                out,
+                "{}",
+                paint(&format!("{location}{error}"), Style::Red, color)
+            );
+        }
+    };
+
+    // The command and splice time stand out from the event details that follow.
+    let summary = section.to_string();
+    match summary.split_once(": ") {
+        Some((head, details)) => writeln!(out, "{}: {details}", paint(head, Style::Bold, color))?,
+        None => writeln!(out, "{}", paint(&summary, Style::Bold, color))?,
+    }
+    if options.verbosity == 0 {
+        return Ok(());
+    }
+
+    let crc = match validate_scte35_crc(buffer) {
+        Ok(true) => paint("valid", Style::Green, color),
+        Ok(false) => paint("invalid", Style::Red, color),
+        Err(e) => paint(&format!("not checked ({e})"), Style::Yellow, color),
+    };
+    writeln!(out, "  crc 0x{:08x} {crc}", section.crc_32)?;
+    let detail = format!(
+        "pts_adjustment {} ticks, tier 0x{:03x}, {} descriptor(s)",
+        section.pts_adjustment,
+        section.tier,
+        section.splice_descriptors.len()
+    );
+    writeln!(out, "  {}", paint(&detail, Style::Dim, color))?;
+    for (index, descriptor) in section.splice_descriptors.iter().enumerate() {
+        let detail = descriptor_detail(descriptor);
+        writeln!(
+            out,
+            "  {}",
+            paint(&format!("[{index}] {detail}"), Style::Dim, color)
+        )?;
+    }
+
+    if options.verbosity >= 2 {
+        if let Some(payload) = payload {
+            writeln!(
+                out,
+                "  {}",
+                paint(&format!("payload {payload}"), Style::Dim, color)
+            )?;
+        }
+        let hex: String = buffer.iter().map(|byte| format!("{byte:02x}")).collect();
+        writeln!(
+            out,
+            "  {}",
+            paint(&format!("bytes {hex}"), Style::Dim, color)
+        )?;
+    }
+    Ok(())
+}
+
+/// Describes a descriptor by name and the fields the summary line leaves out.
+fn descriptor_detail(descriptor: &SpliceDescriptor) -> String {
+    match descriptor {
+        SpliceDescriptor::Segmentation(segmentation) => format!(
+            "segmentation_descriptor: type 0x{:02x}, segment {}/{}, UPID type 0x{:02x} ({}), {}",
+            segmentation.segmentation_type_id,
+            segmentation.segment_num,
+            segmentation.segments_expected,
+            u8::from(segmentation.segmentation_upid_type),
+            segmentation.segmentation_upid_type.short_name(),
+            if segmentation.delivery_not_restricted_flag {
+                "delivery not restricted"
+            } else {
+                "delivery restricted"
+            }
+        ),
+        other => {
+            let name = match other.tag() {
+                0x00 => "avail_descriptor",
+                0x01 => "DTMF_descriptor",
+                0x03 => "time_descriptor",
+                0x04 => "audio_descriptor",
+                _ => "descriptor",
+            };
+            format!("{name}: tag 0x{:02x}", other.tag())
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! The `ts` subcommand: lists the SCTE-35 cues in a transport stream file.
+
+use clap::{Args, ValueEnum};
+use scte35::ts::{PACKET_SIZE, TsCue, TsEvent, TsScanner};
+use scte35::validate_scte35_crc;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Output format of `scte35 ts`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TsFormat {
+    /// One row per cue or stream error, followed by a summary
+    Text,
+    /// A JSON object with all cues and stream errors
+    Json,
+}
+
+/// Arguments of `scte35 ts`.
+#[derive(Debug, Args)]
+pub struct TsArgs {
+    /// Transport stream file
+    #[arg(value_name = "FILE")]
+    file: PathBuf,
+
+    /// Also scan this PID for SCTE-35 sections, e.g. when the stream has no PMT
+    #[arg(long = "pid", value_name = "PID", value_parser = parse_pid)]
+    pids: Vec<u16>,
+
+    /// Output format
+    #[arg(short = 'o', long = "output", value_enum, default_value_t = TsFormat::Text)]
+    output: TsFormat,
+}
+
+/// Runs the `ts` subcommand.
+pub fn run(args: &TsArgs) -> Result<(), String> {
+    let mut file = File::open(&args.file)
+        .map_err(|e| format!("Error opening {}: {e}", args.file.display()))?;
+
+    let mut scanner = TsScanner::new();
+    for &pid in &args.pids {
+        scanner.add_scte35_pid(pid);
+    }
+
+    let mut events = Vec::new();
+    let mut chunk = vec![0; PACKET_SIZE * 1024];
+    loop {
+        let read = file
+            .read(&mut chunk)
+            .map_err(|e| format!("Error reading {}: {e}", args.file.display()))?;
+        if read == 0 {
+            break;
+        }
+        events.extend(scanner.push(&chunk[..read]));
+    }
+
+    let cues = events
+        .iter()
+        .filter(|event| matches!(event, TsEvent::Cue(_)))
+        .count();
+    let crc_failures = events
+        .iter()
+        .filter(|event| matches!(event, TsEvent::Cue(cue) if !crc_valid(cue)))
+        .count();
+    let continuity_errors = events
+        .iter()
+        .filter(|event| matches!(event, TsEvent::ContinuityError { .. }))
+        .count();
+    let pids: Vec<u16> = scanner.scte35_pids().collect();
+
+    match args.output {
+        TsFormat::Text => {
+            println!(
+                "{:>8} {:>12} {:>6} {:>16} {:>16}  EVENT",
+                "PACKET", "OFFSET", "PID", "VIDEO_PTS", "PCR"
+            );
+            for event in &events {
+                println!("{}", text_row(event));
+            }
+            let pids: Vec<String> = pids.iter().map(|pid| format!("0x{pid:04X}")).collect();
+            println!(
+                "{} packets, {cues} cues, {crc_failures} CRC failures, {continuity_errors} continuity errors; SCTE-35 PIDs: {}",
+                scanner.packets(),
+                if pids.is_empty() {
+                    "none".to_string()
+                } else {
+                    pids.join(", ")
+                }
+            );
+        }
+        TsFormat::Json => {
+            use serde_json::json;
+
+            let (cue_records, error_records): (Vec<_>, Vec<_>) = events
+                .iter()
+                .partition(|event| matches!(event, TsEvent::Cue(_)));
+            let output = json!({
+                "file": args.file.display().to_string(),
+                "packets": scanner.packets(),
+                "scte35_pids": pids,
+                "summary": {
+                    "cues": cues,
+                    "crc_failures": crc_failures,
+                    "continuity_errors": continuity_errors,
+                },
+                "cues": cue_records.into_iter().map(json_record).collect::<Vec<_>>(),
+                "errors": error_records.into_iter().map(json_record).collect::<Vec<_>>(),
+            });
+            let output = serde_json::to_string_pretty(&output)
+                .map_err(|e| format!("Error serializing to JSON: {e}"))?;
+            println!("{output}");
+        }
+    }
+
+    Ok(())
+}
+
+fn crc_valid(cue: &TsCue) -> bool {
+    matches!(validate_scte35_crc(&cue.section), Ok(true))
+}
+
+fn seconds(ticks: Option<u64>, rate: f64) -> String {
+    ticks
+        .map(|ticks| format!("{:.6}s", ticks as f64 / rate))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn text_row(event: &TsEvent) -> String {
+    let row = |packet: u64, offset: u64, pid: Option<u16>, pts, pcr, text: String| {
+        format!(
+            "{packet:>8} {offset:>12} {:>6} {:>16} {:>16}  {text}",
+            pid.map(|pid| format!("0x{pid:04X}"))
+                .unwrap_or_else(|| "-".to_string()),
+            seconds(pts, 90_000.0),
+            seconds(pcr, 27_000_000.0),
+        )
+    };
+
+    match event {
+        TsEvent::Cue(cue) => {
+            let text = if !crc_valid(cue) {
+                "CRC mismatch".to_string()
+            } else {
+                match cue.parse() {
+                    Ok(section) => section.to_string(),
+                    Err(e) => format!("parse error: {e}"),
+                }
+            };
+            row(
+                cue.packet,
+                cue.offset,
+                Some(cue.pid),
+                cue.video_pts.map(|pts| pts.ticks()),
+                cue.pcr,
+                text,
+            )
+        }
+        TsEvent::ContinuityError {
+            packet,
+            offset,
+            pid,
+            expected,
+            found,
+        } => row(
+            *packet,
+            *offset,
+            Some(*pid),
+            None,
+            None,
+            format!("continuity error: expected {expected}, found {found}"),
+        ),
+        TsEvent::SyncLost { offset } => format!("{:>8} {offset:>12}  sync lost", "-"),
+    }
+}
+
+fn json_record(event: &TsEvent) -> serde_json::Value {
+    use serde_json::json;
+
+    match event {
+        TsEvent::Cue(cue) => {
+            let mut record = json!({
+                "packet": cue.packet,
+                "offset": cue.offset,
+                "pid": cue.pid,
+                "video_pts": cue.video_pts.map(|pts| pts.ticks()),
+                "pcr": cue.pcr,
+                "payload": data_encoding::BASE64.encode(&cue.section),
+                "crc_valid": crc_valid(cue),
+            });
+            match cue.parse() {
+                Ok(section) => {
+                    record["status"] = json!("success");
+                    record["data"] = json!(section);
+                }
+                Err(e) => {
+                    record["status"] = json!("error");
+                    record["error"] = json!(e.to_string());
+                }
+            }
+            record
+        }
+        TsEvent::ContinuityError {
+            packet,
+            offset,
+            pid,
+            expected,
+            found,
+        } => json!({
+            "type": "continuity",
+            "packet": packet,
+            "offset": offset,
+            "pid": pid,
+            "expected": expected,
+            "found": found,
+        }),
+        TsEvent::SyncLost { offset } => json!({
+            "type": "sync",
+            "offset": offset,
+        }),
+    }
+}
+
+fn parse_pid(text: &str) -> Result<u16, String> {
+    let pid = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u16::from_str_radix(hex, 16),
+        None => text.parse(),
+    }
+    .map_err(|_| format!("invalid PID '{text}'"))?;
+    if pid > 0x1FFF {
+        return Err(format!("PID {text} exceeds 0x1FFF"));
+    }
+    Ok(pid)
+}
@@ -0,0 +1,195 @@
+//! The `validate` subcommand: CRC and conformance checks for CI pipelines.
+
+use clap::{Args, ValueEnum};
+use scte35::input::{InputFormat, decode_input};
+use scte35::validation::{Severity, ValidationIssue, validate};
+use scte35::{parse_splice_info_section, validate_scte35_crc};
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Output format of `scte35 validate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ValidateFormat {
+    /// One line per finding, followed by a summary
+    Text,
+    /// A JSON object with the findings of every input and a summary
+    Json,
+}
+
+/// Arguments of `scte35 validate`.
+#[derive(Debug, Args)]
+pub struct ValidateArgs {
+    /// SCTE-35 payloads; read one per line from `--file` or stdin when omitted
+    #[arg(value_name = "PAYLOAD", conflicts_with = "file")]
+    payloads: Vec<String>,
+
+    /// Read payloads from a file, one per line
+    #[arg(short = 'f', long = "file", value_name = "PATH")]
+    file: Option<PathBuf>,
+
+    /// Encoding of the payloads
+    #[arg(short = 'i', long = "input-format", value_enum, default_value_t = InputFormat::Auto)]
+    input_format: InputFormat,
+
+    /// Output format
+    #[arg(short = 'o', long = "output", value_enum, default_value_t = ValidateFormat::Text)]
+    output: ValidateFormat,
+
+    /// Exit with status 1 if any finding is at least this severe
+    #[arg(long = "fail-on", value_enum, default_value_t = Severity::Error)]
+    fail_on: Severity,
+}
+
+/// The findings for one payload.
+struct Report {
+    /// `"payload N"` for arguments, `"line N"` for file and stdin input
+    source: String,
+    payload: String,
+    issues: Vec<ValidationIssue>,
+}
+
+/// Runs the `validate` subcommand, returning whether every input passed `--fail-on`.
+pub fn run(args: &ValidateArgs) -> Result<bool, String> {
+    let reports: Vec<Report> = read_payloads(args)?
+        .into_iter()
+        .map(|(source, payload)| Report {
+            issues: check(&payload, args.input_format),
+            source,
+            payload,
+        })
+        .collect();
+
+    let count = |severity| {
+        reports
+            .iter()
+            .flat_map(|report| &report.issues)
+            .filter(|issue| issue.severity == severity)
+            .count()
+    };
+    let (errors, warnings, info) = (
+        count(Severity::Error),
+        count(Severity::Warning),
+        count(Severity::Info),
+    );
+    let passed = reports
+        .iter()
+        .flat_map(|report| &report.issues)
+        .all(|issue| issue.severity < args.fail_on);
+
+    match args.output {
+        ValidateFormat::Text => {
+            for report in &reports {
+                if report.issues.is_empty() {
+                    println!("{}: ok", report.source);
+                }
+                for issue in &report.issues {
+                    println!("{}: {issue}", report.source);
+                }
+            }
+            println!(
+                "{} inputs checked: {errors} errors, {warnings} warnings, {info} info",
+                reports.len()
+            );
+        }
+        ValidateFormat::Json => {
+            use serde_json::json;
+
+            let inputs: Vec<_> = reports
+                .iter()
+                .map(|report| {
+                    json!({
+                        "source": report.source,
+                        "payload": report.payload,
+                        "issues": report.issues,
+                    })
+                })
+                .collect();
+            let output = json!({
+                "passed": passed,
+                "fail_on": args.fail_on,
+                "summary": {
+                    "inputs": reports.len(),
+                    "errors": errors,
+                    "warnings": warnings,
+                    "info": info,
+                },
+                "inputs": inputs,
+            });
+            let output = serde_json::to_string_pretty(&output)
+                .map_err(|e| format!("Error serializing to JSON: {e}"))?;
+            println!("{output}");
+        }
+    }
+
+    Ok(passed)
+}
+
+/// Collects the payloads to check, labelled by where they came from.
+///
+/// Fails if the file or stdin holds no payloads.
+fn read_payloads(args: &ValidateArgs) -> Result<Vec<(String, String)>, String> {
+    if !args.payloads.is_empty() {
+        return Ok(args
+            .payloads
+            .iter()
+            .enumerate()
+            .map(|(index, payload)| (format!("payload {}", index + 1), payload.clone()))
+            .collect());
+    }
+
+    let input = match &args.file {
+        Some(path) => std::fs::read(path),
+        None => {
+            let mut input = Vec::new();
+            io::stdin().read_to_end(&mut input).map(|_| input)
+        }
+    }
+    .map_err(|e| format!("Error reading input: {e}"))?;
+
+    let payloads: Vec<_> = String::from_utf8_lossy(&input)
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| (format!("line {}", index + 1), line.trim().to_string()))
+        .collect();
+    // An empty input would otherwise pass without anything having been checked
+    if payloads.is_empty() {
+        return Err("No payloads to validate".to_string());
+    }
+    Ok(payloads)
+}
+
+/// Decodes, CRC-checks, parses and validates one payload.
+fn check(payload: &str, input_format: InputFormat) -> Vec<ValidationIssue> {
+    let issue = |code, location: &str, message: String| ValidationIssue {
+        severity: Severity::Error,
+        code,
+        location: location.to_string(),
+        message,
+    };
+
+    let buffer = match decode_input(payload.as_bytes(), input_format) {
+        Ok(buffer) => buffer,
+        Err(e) => {
+            return vec![issue(
+                "decode-error",
+                "input",
+                format!("cannot decode {} string: {}", e.format, e.reason),
+            )];
+        }
+    };
+
+    // A CRC mismatch also fails the parse, so report it once, as the more specific issue
+    if let Ok(false) = validate_scte35_crc(&buffer) {
+        return vec![issue(
+            "crc-mismatch",
+            "CRC_32",
+            "CRC-32 does not match the section contents".to_string(),
+        )];
+    }
+
+    match parse_splice_info_section(&buffer) {
+        Ok(section) => validate(&section),
+        Err(e) => vec![issue("parse-error", "input", e.to_string())],
+    }
+}
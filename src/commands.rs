@@ -7,7 +7,7 @@ use crate::bit_reader::BitReader;
 use crate::time::{BreakDuration, SpliceTime};
 use crate::types::{
     BandwidthReservation, ComponentSplice, PrivateCommand, SpliceCommand, SpliceInsert,
-    SpliceInsertComponent, SpliceSchedule, TimeSignal,
+    SpliceInsertComponent, SpliceSchedule, SpliceScheduleEvent, TimeSignal,
 };
 use std::io;
 
@@ -20,7 +20,16 @@ pub(crate) fn parse_splice_command(
     splice_command_type: u8,
     splice_command_length: u16,
 ) -> Result<SpliceCommand, io::Error> {
-    match splice_command_type {
+    reader.begin(match splice_command_type {
+        0x00 => "splice_null()",
+        0x04 => "splice_schedule()",
+        0x05 => "splice_insert()",
+        0x06 => "time_signal()",
+        0x07 => "bandwidth_reservation()",
+        0xFF => "private_command()",
+        _ => "splice_command()",
+    });
+    let command = match splice_command_type {
         0x00 => Ok(SpliceCommand::SpliceNull),
         0x04 => Ok(SpliceCommand::SpliceSchedule(parse_splice_schedule(
             reader,
@@ -35,59 +44,112 @@ pub(crate) fn parse_splice_command(
         )?)),
         _ => {
             // Unknown command type - skip the data
-            reader.skip_bits((splice_command_length * 8) as usize)?;
+            reader.skip_bits("command_bytes", (splice_command_length * 8) as usize)?;
             Ok(SpliceCommand::Unknown)
         }
-    }
+    };
+    reader.end();
+    command
 }
 
 /// Parses a splice schedule command (0x04).
 pub(crate) fn parse_splice_schedule(reader: &mut BitReader) -> Result<SpliceSchedule, io::Error> {
-    let splice_event_id = reader.read_uimsbf(32)? as u32;
-    let splice_event_cancel_indicator = reader.read_bslbf(1)? as u8;
-    let reserved = reader.read_bslbf(7)? as u8;
-    let out_of_network_indicator = reader.read_bslbf(1)? as u8;
-    let duration_flag = reader.read_bslbf(1)? as u8;
+    let splice_count = reader.read_uimsbf("splice_count", 8)? as u8;
+    let mut events = Vec::new();
+    for _ in 0..splice_count {
+        reader.begin("event");
+        events.push(parse_splice_schedule_event(reader)?);
+        reader.end();
+    }
+
+    Ok(SpliceSchedule {
+        splice_count,
+        events,
+    })
+}
+
+/// Parses one event of a splice schedule command.
+fn parse_splice_schedule_event(reader: &mut BitReader) -> Result<SpliceScheduleEvent, io::Error> {
+    let splice_event_id = reader.read_uimsbf("splice_event_id", 32)? as u32;
+    let splice_event_cancel_indicator =
+        reader.read_bslbf("splice_event_cancel_indicator", 1)? as u8;
+    let reserved = reader.read_bslbf("reserved", 7)? as u8;
+
+    if splice_event_cancel_indicator == 1 {
+        // If cancel indicator is set, no other fields follow
+        return Ok(SpliceScheduleEvent {
+            splice_event_id,
+            splice_event_cancel_indicator,
+            reserved,
+            out_of_network_indicator: 0,
+            program_splice_flag: 0,
+            duration_flag: 0,
+            reserved2: 0,
+            utc_splice_time: None,
+            component_count: 0,
+            components: Vec::new(),
+            break_duration: None,
+            unique_program_id: 0,
+            avail_num: 0,
+            avails_expected: 0,
+        });
+    }
+
+    let out_of_network_indicator = reader.read_bslbf("out_of_network_indicator", 1)? as u8;
+    let program_splice_flag = reader.read_bslbf("program_splice_flag", 1)? as u8;
+    let duration_flag = reader.read_bslbf("duration_flag", 1)? as u8;
+    let reserved2 = reader.read_bslbf("reserved", 5)? as u8;
 
-    let splice_duration = if duration_flag == 1 {
-        Some(reader.read_uimsbf(32)? as u32)
+    let utc_splice_time = if program_splice_flag == 1 {
+        Some(reader.read_uimsbf("utc_splice_time", 32)? as u32)
     } else {
         None
     };
 
-    let utc_splice_time = if duration_flag == 0 {
-        // According to SCTE-35 spec: if (program_splice_flag == '1') utc_splice_time (32 bits)
-        Some(reader.read_uimsbf(32)? as u32)
+    let component_count = if program_splice_flag == 0 {
+        reader.read_uimsbf("component_count", 8)? as u8
+    } else {
+        0
+    };
+    let mut components = Vec::new();
+    for _ in 0..component_count {
+        components.push(parse_component_splice(reader)?);
+    }
+
+    let break_duration = if duration_flag == 1 {
+        Some(parse_break_duration(reader)?)
     } else {
         None
     };
 
-    let unique_program_id = reader.read_uimsbf(16)? as u16;
-    let num_splice = reader.read_uimsbf(8)? as u8;
-    let mut component_list = Vec::new();
-    for _ in 0..num_splice {
-        component_list.push(parse_component_splice(reader)?);
-    }
+    let unique_program_id = reader.read_uimsbf("unique_program_id", 16)? as u16;
+    let avail_num = reader.read_uimsbf("avail_num", 8)? as u8;
+    let avails_expected = reader.read_uimsbf("avails_expected", 8)? as u8;
 
-    Ok(SpliceSchedule {
+    Ok(SpliceScheduleEvent {
         splice_event_id,
         splice_event_cancel_indicator,
         reserved,
         out_of_network_indicator,
+        program_splice_flag,
         duration_flag,
-        splice_duration,
+        reserved2,
         utc_splice_time,
+        component_count,
+        components,
+        break_duration,
         unique_program_id,
-        num_splice,
-        component_list,
+        avail_num,
+        avails_expected,
     })
 }
 
 /// Parses a splice insert command (0x05).
 pub(crate) fn parse_splice_insert(reader: &mut BitReader) -> Result<SpliceInsert, io::Error> {
-    let splice_event_id = reader.read_uimsbf(32)? as u32;
-    let splice_event_cancel_indicator = reader.read_bslbf(1)? as u8;
-    let reserved = reader.read_bslbf(7)? as u8;
+    let splice_event_id = reader.read_uimsbf("splice_event_id", 32)? as u32;
+    let splice_event_cancel_indicator =
+        reader.read_bslbf("splice_event_cancel_indicator", 1)? as u8;
+    let reserved = reader.read_bslbf("reserved", 7)? as u8;
 
     if splice_event_cancel_indicator == 1 {
         // If cancel indicator is set, no other fields follow
@@ -110,11 +172,11 @@ pub(crate) fn parse_splice_insert(reader: &mut BitReader) -> Result<SpliceInsert
         });
     }
 
-    let out_of_network_indicator = reader.read_bslbf(1)? as u8;
-    let program_splice_flag = reader.read_bslbf(1)? as u8;
-    let duration_flag = reader.read_bslbf(1)? as u8;
-    let splice_immediate_flag = reader.read_bslbf(1)? as u8;
-    let reserved2 = reader.read_bslbf(4)? as u8;
+    let out_of_network_indicator = reader.read_bslbf("out_of_network_indicator", 1)? as u8;
+    let program_splice_flag = reader.read_bslbf("program_splice_flag", 1)? as u8;
+    let duration_flag = reader.read_bslbf("duration_flag", 1)? as u8;
+    let splice_immediate_flag = reader.read_bslbf("splice_immediate_flag", 1)? as u8;
+    let reserved2 = reader.read_bslbf("reserved", 4)? as u8;
 
     let splice_time = if program_splice_flag == 1 && splice_immediate_flag == 0 {
         Some(parse_splice_time(reader)?)
@@ -123,7 +185,7 @@ pub(crate) fn parse_splice_insert(reader: &mut BitReader) -> Result<SpliceInsert
     };
 
     let component_count = if program_splice_flag == 0 {
-        reader.read_uimsbf(8)? as u8
+        reader.read_uimsbf("component_count", 8)? as u8
     } else {
         0
     };
@@ -131,12 +193,14 @@ pub(crate) fn parse_splice_insert(reader: &mut BitReader) -> Result<SpliceInsert
     let mut components = Vec::new();
     if program_splice_flag == 0 {
         for _ in 0..component_count {
-            let component_tag = reader.read_uimsbf(8)? as u8;
+            reader.begin("component");
+            let component_tag = reader.read_uimsbf("component_tag", 8)? as u8;
             let splice_time = if splice_immediate_flag == 0 {
                 Some(parse_splice_time(reader)?)
             } else {
                 None
             };
+            reader.end();
             components.push(SpliceInsertComponent {
                 component_tag,
                 splice_time,
@@ -150,9 +214,9 @@ pub(crate) fn parse_splice_insert(reader: &mut BitReader) -> Result<SpliceInsert
         None
     };
 
-    let unique_program_id = reader.read_uimsbf(16)? as u16;
-    let avail_num = reader.read_uimsbf(8)? as u8;
-    let avails_expected = reader.read_uimsbf(8)? as u8;
+    let unique_program_id = reader.read_uimsbf("unique_program_id", 16)? as u16;
+    let avail_num = reader.read_uimsbf("avail_num", 8)? as u8;
+    let avails_expected = reader.read_uimsbf("avails_expected", 8)? as u8;
 
     Ok(SpliceInsert {
         splice_event_id,
@@ -183,8 +247,8 @@ pub(crate) fn parse_time_signal(reader: &mut BitReader) -> Result<TimeSignal, io
 pub(crate) fn parse_bandwidth_reservation(
     reader: &mut BitReader,
 ) -> Result<BandwidthReservation, io::Error> {
-    let reserved = reader.read_bslbf(8)? as u8;
-    let dwbw_reservation = reader.read_uimsbf(32)? as u32;
+    let reserved = reader.read_bslbf("reserved", 8)? as u8;
+    let dwbw_reservation = reader.read_uimsbf("dwbw_reservation", 32)? as u32;
     Ok(BandwidthReservation {
         reserved,
         dwbw_reservation,
@@ -193,12 +257,9 @@ pub(crate) fn parse_bandwidth_reservation(
 
 /// Parses a private command (0xFF).
 pub(crate) fn parse_private_command(reader: &mut BitReader) -> Result<PrivateCommand, io::Error> {
-    let private_command_id = reader.read_uimsbf(16)? as u16;
-    let private_command_length = reader.read_uimsbf(8)? as u8;
-    let mut private_bytes = Vec::new();
-    for _ in 0..private_command_length {
-        private_bytes.push(reader.read_uimsbf(8)? as u8);
-    }
+    let private_command_id = reader.read_uimsbf("private_command_id", 16)? as u16;
+    let private_command_length = reader.read_uimsbf("private_command_length", 8)? as u8;
+    let private_bytes = reader.read_bytes("private_byte", private_command_length as usize)?;
     Ok(PrivateCommand {
         private_command_id,
         private_command_length,
@@ -208,14 +269,16 @@ pub(crate) fn parse_private_command(reader: &mut BitReader) -> Result<PrivateCom
 
 /// Parses a splice time structure.
 pub(crate) fn parse_splice_time(reader: &mut BitReader) -> Result<SpliceTime, io::Error> {
-    let time_specified_flag = reader.read_bslbf(1)? as u8;
+    reader.begin("splice_time()");
+    let time_specified_flag = reader.read_bslbf("time_specified_flag", 1)? as u8;
     let pts_time = if time_specified_flag == 1 {
-        let _reserved = reader.read_bslbf(6)? as u8;
-        Some(reader.read_uimsbf(33)?)
+        let _reserved = reader.read_bslbf("reserved", 6)? as u8;
+        Some(reader.read_uimsbf("pts_time", 33)?)
     } else {
-        let _reserved = reader.read_bslbf(7)? as u8;
+        let _reserved = reader.read_bslbf("reserved", 7)? as u8;
         None
     };
+    reader.end();
     Ok(SpliceTime {
         time_specified_flag,
         pts_time,
@@ -224,9 +287,11 @@ pub(crate) fn parse_splice_time(reader: &mut BitReader) -> Result<SpliceTime, io
 
 /// Parses a break duration structure.
 pub(crate) fn parse_break_duration(reader: &mut BitReader) -> Result<BreakDuration, io::Error> {
-    let auto_return = reader.read_bslbf(1)? as u8;
-    let reserved = reader.read_bslbf(6)? as u8;
-    let duration = reader.read_uimsbf(33)?;
+    reader.begin("break_duration()");
+    let auto_return = reader.read_bslbf("auto_return", 1)? as u8;
+    let reserved = reader.read_bslbf("reserved", 6)? as u8;
+    let duration = reader.read_uimsbf("duration", 33)?;
+    reader.end();
     Ok(BreakDuration {
         auto_return,
         reserved,
@@ -236,30 +301,13 @@ pub(crate) fn parse_break_duration(reader: &mut BitReader) -> Result<BreakDurati
 
 /// Parses a component splice structure.
 pub(crate) fn parse_component_splice(reader: &mut BitReader) -> Result<ComponentSplice, io::Error> {
-    let component_tag = reader.read_uimsbf(8)? as u8;
-    let reserved = reader.read_bslbf(5)? as u8;
-    let splice_mode_indicator = reader.read_bslbf(1)? as u8;
-    let duration_flag = reader.read_bslbf(1)? as u8;
-
-    let splice_duration = if duration_flag == 1 {
-        Some(reader.read_uimsbf(32)? as u32)
-    } else {
-        None
-    };
-
-    let utc_splice_time = if duration_flag == 0 {
-        // According to SCTE-35 spec: utc_splice_time (32 bits)
-        Some(reader.read_uimsbf(32)? as u32)
-    } else {
-        None
-    };
+    reader.begin("component");
+    let component_tag = reader.read_uimsbf("component_tag", 8)? as u8;
+    let utc_splice_time = reader.read_uimsbf("utc_splice_time", 32)? as u32;
+    reader.end();
 
     Ok(ComponentSplice {
         component_tag,
-        reserved,
-        splice_mode_indicator,
-        duration_flag,
-        splice_duration,
         utc_splice_time,
     })
 }
@@ -0,0 +1,498 @@
+//! Conversion of cues between the representations they travel in.
+//!
+//! The same splice_info_section reaches tooling as base64, hex, JSON, SCTE-35 XML, an HLS
+//! `#EXT-X-DATERANGE` tag or a DASH `EventStream`. [`decode`] reads any of these into
+//! section bytes and [`encode`] writes section bytes as any of them, so converting a cue
+//! is one call to each:
+//!
+//! ```rust
+//! use scte35::convert::{EncodeOptions, Format, decode, encode};
+//! use scte35::parse_splice_info_section;
+//!
+//! let cues = decode("0xFC301600000000000000FFF00506FE423A35BD0000BB0C73F4", Format::Hex)?;
+//! let xml = encode(&cues[0], Format::Xml, &EncodeOptions::default())?;
+//!
+//! // The CRC is only recomputed with the `crc-validation` feature, so compare the commands
+//! let read_back = decode(&xml, Format::Xml)?;
+//! assert_eq!(
+//!     parse_splice_info_section(&read_back[0])?.splice_command,
+//!     parse_splice_info_section(&cues[0])?.splice_command,
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! JSON requires the `serde` feature; base64 and DASH (which embeds base64) require the
+//! `base64` feature.
+
+use crate::descriptors::SpliceDescriptor;
+use crate::encoding::EncodingResult;
+use crate::hls::{CueKind, extract_cues, format_daterange};
+use crate::input::{InputFormat, decode_input};
+use crate::parser::parse_splice_info_section;
+use crate::types::{SegmentationCategory, SpliceCommand, SpliceInfoSection};
+use crate::xml::{NAMESPACE, Signal, XmlWriter, find_signals, to_xml};
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+/// DASH event scheme for cues carried as base64 section bytes.
+pub const DASH_SCHEME: &str = "urn:scte:scte35:2014:xml+bin";
+
+/// A representation of a cue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Format {
+    /// Standard base64, one cue per line
+    Base64,
+    /// Hexadecimal with an optional `0x` prefix, one cue per line
+    Hex,
+    /// The JSON written by `serde`, one or more documents or arrays of them
+    Json,
+    /// SCTE-35 XML `SpliceInfoSection` elements
+    Xml,
+    /// HLS ad-marker tags, one per line, or a whole media playlist
+    Hls,
+    /// A DASH `EventStream`, or any document holding SCTE-35 signals such as an MPD
+    Dash,
+}
+
+impl Format {
+    /// Returns `true` if every line of input holds a complete cue.
+    ///
+    /// Input in these formats can be converted line by line as it arrives; the others
+    /// are documents that have to be read in full.
+    pub fn is_line_oriented(self) -> bool {
+        matches!(self, Format::Base64 | Format::Hex | Format::Hls)
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Base64 => write!(f, "base64"),
+            Format::Hex => write!(f, "hex"),
+            Format::Json => write!(f, "json"),
+            Format::Xml => write!(f, "xml"),
+            Format::Hls => write!(f, "hls"),
+            Format::Dash => write!(f, "dash"),
+        }
+    }
+}
+
+/// Error returned when a cue cannot be read from or written as a format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvertError {
+    /// The format being read or written
+    pub format: Format,
+    /// `true` if the cue could not be written, `false` if the input could not be read
+    pub output: bool,
+    /// A description of what went wrong
+    pub reason: String,
+}
+
+impl ConvertError {
+    fn input(format: Format, reason: impl fmt::Display) -> Self {
+        Self {
+            format,
+            output: false,
+            reason: reason.to_string(),
+        }
+    }
+
+    fn output(format: Format, reason: impl fmt::Display) -> Self {
+        Self {
+            format,
+            output: true,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.output {
+            write!(f, "Cannot write {}: {}", self.format, self.reason)
+        } else {
+            write!(f, "Invalid {} input: {}", self.format, self.reason)
+        }
+    }
+}
+
+impl Error for ConvertError {}
+
+/// Settings for the formats that carry more than the cue itself.
+#[derive(Debug, Clone, Default)]
+pub struct EncodeOptions {
+    /// `ID` of an HLS tag or `id` of a DASH event; defaults to the splice or
+    /// segmentation event id
+    pub id: Option<String>,
+    /// `START-DATE` of an HLS tag; defaults to the current time
+    pub start_date: Option<SystemTime>,
+}
+
+/// Reads every cue in `input` and returns the section bytes of each, in order.
+///
+/// Base64 and hex input holds one cue per non-empty line. HLS input is a media playlist
+/// or bare tag lines; tags without a payload, such as a plain `#EXT-X-CUE-IN`, are
+/// skipped. Cues read from JSON or XML are encoded with a freshly computed CRC when the
+/// `crc-validation` feature is enabled.
+pub fn decode(input: &str, format: Format) -> Result<Vec<Vec<u8>>, ConvertError> {
+    let error = |reason: &dyn fmt::Display| ConvertError::input(format, reason);
+
+    match format {
+        Format::Base64 | Format::Hex => {
+            let input_format = if format == Format::Hex {
+                InputFormat::Hex
+            } else {
+                InputFormat::Base64
+            };
+            input
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(|line| {
+                    decode_input(line.as_bytes(), input_format).map_err(|e| error(&e.reason))
+                })
+                .collect()
+        }
+        Format::Json => decode_json(input),
+        Format::Xml | Format::Dash => find_signals(input)
+            .map_err(|e| error(&e.reason))?
+            .into_iter()
+            .map(|signal| match signal {
+                Signal::Section(section) => encode_section(&section).map_err(|e| error(&e)),
+                Signal::Binary(text) => {
+                    decode_input(text.as_bytes(), InputFormat::Base64).map_err(|e| error(&e.reason))
+                }
+            })
+            .collect(),
+        Format::Hls => {
+            // Bare tags are read as a playlist of their own
+            let playlist = if input.trim_start().starts_with("#EXTM3U") {
+                input.to_string()
+            } else {
+                format!("#EXTM3U\n{input}")
+            };
+            extract_cues(&playlist)
+                .map_err(|e| error(&e.reason))?
+                .iter()
+                .filter_map(|cue| cue.decode())
+                .map(|bytes| bytes.map_err(|e| error(&e.reason)))
+                .collect()
+        }
+    }
+}
+
+/// Writes the cue in `bytes` in the given format.
+///
+/// Base64 and hex are plain re-encodings. The other formats parse the section first, so
+/// `bytes` has to hold a valid splice_info_section (with a valid CRC when the
+/// `crc-validation` feature is enabled). HLS cues are written as an `#EXT-X-DATERANGE`
+/// tag: breaks opened by a `splice_insert()` or an ad, break or placement-opportunity
+/// segmentation start become `SCTE35-OUT`, their ends `SCTE35-IN`, and everything else
+/// `SCTE35-CMD`. DASH cues are written as an `EventStream` holding one `Event`.
+pub fn encode(
+    bytes: &[u8],
+    format: Format,
+    options: &EncodeOptions,
+) -> Result<String, ConvertError> {
+    let error = |reason: &dyn fmt::Display| ConvertError::output(format, reason);
+
+    match format {
+        Format::Base64 => encode_base64(bytes)
+            .ok_or_else(|| error(&"base64 output requires the `base64` feature")),
+        Format::Hex => Ok(format!("0x{}", hex(bytes))),
+        _ => {
+            let section = parse_splice_info_section(bytes)
+                .map_err(|e| error(&format!("not a valid splice_info_section: {e}")))?;
+            match format {
+                Format::Json => encode_json(&section).map_err(|e| error(&e)),
+                Format::Xml => to_xml(&section).map_err(|e| error(&e.reason)),
+                Format::Hls => {
+                    let id = options
+                        .id
+                        .clone()
+                        .or_else(|| event_id(&section).map(|id| id.to_string()))
+                        .unwrap_or_else(|| format!("scte35-{:08X}", section.crc_32));
+                    let planned_duration = duration(&section).map(|(_, duration)| duration);
+                    Ok(format_daterange(
+                        cue_kind(&section),
+                        &id,
+                        options.start_date.unwrap_or_else(SystemTime::now),
+                        planned_duration.filter(|_| cue_kind(&section) == CueKind::Out),
+                        bytes,
+                    ))
+                }
+                _ => {
+                    let binary = encode_base64(bytes)
+                        .ok_or_else(|| error(&"DASH output requires the `base64` feature"))?;
+                    Ok(event_stream(&section, &binary, options))
+                }
+            }
+        }
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
+#[cfg(feature = "base64")]
+fn encode_base64(bytes: &[u8]) -> Option<String> {
+    Some(data_encoding::BASE64.encode(bytes))
+}
+
+#[cfg(not(feature = "base64"))]
+fn encode_base64(_bytes: &[u8]) -> Option<String> {
+    None
+}
+
+/// Encodes a section built from JSON or XML, whose lengths and CRC are not yet set.
+fn encode_section(section: &SpliceInfoSection) -> EncodingResult<Vec<u8>> {
+    #[cfg(feature = "crc-validation")]
+    {
+        use crate::encoding::CrcEncodable;
+        section.encode_with_crc()
+    }
+    #[cfg(not(feature = "crc-validation"))]
+    {
+        use crate::encoding::Encodable;
+        section.encode_to_vec()
+    }
+}
+
+#[cfg(feature = "serde")]
+fn decode_json(input: &str) -> Result<Vec<Vec<u8>>, ConvertError> {
+    let error = |reason: &dyn fmt::Display| ConvertError::input(Format::Json, reason);
+
+    let mut documents = Vec::new();
+    for value in serde_json::Deserializer::from_str(input).into_iter::<serde_json::Value>() {
+        match value.map_err(|e| error(&e))? {
+            serde_json::Value::Array(values) => documents.extend(values),
+            value => documents.push(value),
+        }
+    }
+
+    documents
+        .into_iter()
+        .map(|mut document| {
+            // The {"status": ..., "data": ...} envelope written by the CLI
+            if document.get("status").is_some()
+                && let Some(data) = document.get_mut("data")
+            {
+                document = data.take();
+            }
+            let section: SpliceInfoSection = serde_json::from_value(document)
+                .map_err(|e| error(&format!("not a splice_info_section: {e}")))?;
+            encode_section(&section).map_err(|e| error(&e))
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "serde"))]
+fn decode_json(_input: &str) -> Result<Vec<Vec<u8>>, ConvertError> {
+    Err(ConvertError::input(
+        Format::Json,
+        "JSON input requires the `serde` feature",
+    ))
+}
+
+#[cfg(feature = "serde")]
+fn encode_json(section: &SpliceInfoSection) -> Result<String, String> {
+    serde_json::to_string(section).map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "serde"))]
+fn encode_json(_section: &SpliceInfoSection) -> Result<String, String> {
+    Err("JSON output requires the `serde` feature".to_string())
+}
+
+/// Returns the splice_event_id, else the first segmentation_event_id.
+fn event_id(section: &SpliceInfoSection) -> Option<u32> {
+    if let SpliceCommand::SpliceInsert(insert) = &section.splice_command {
+        return Some(insert.splice_event_id);
+    }
+    section
+        .splice_descriptors
+        .iter()
+        .find_map(|descriptor| match descriptor {
+            SpliceDescriptor::Segmentation(segmentation) => {
+                Some(segmentation.segmentation_event_id)
+            }
+            _ => None,
+        })
+}
+
+/// Returns the break_duration of a splice_insert, else the first segmentation_duration,
+/// both in 90kHz ticks and as a duration.
+fn duration(section: &SpliceInfoSection) -> Option<(u64, Duration)> {
+    if let SpliceCommand::SpliceInsert(insert) = &section.splice_command
+        && let Some(duration) = &insert.break_duration
+    {
+        return Some((duration.duration, duration.to_duration()));
+    }
+    section
+        .splice_descriptors
+        .iter()
+        .find_map(|descriptor| match descriptor {
+            SpliceDescriptor::Segmentation(segmentation) => segmentation
+                .segmentation_duration
+                .zip(segmentation.duration()),
+            _ => None,
+        })
+}
+
+fn cue_kind(section: &SpliceInfoSection) -> CueKind {
+    if let SpliceCommand::SpliceInsert(insert) = &section.splice_command {
+        return match (
+            insert.splice_event_cancel_indicator,
+            insert.out_of_network_indicator,
+        ) {
+            (1, _) => CueKind::Command,
+            (_, 1) => CueKind::Out,
+            _ => CueKind::In,
+        };
+    }
+    for descriptor in &section.splice_descriptors {
+        if let SpliceDescriptor::Segmentation(segmentation) = descriptor
+            && !segmentation.segmentation_event_cancel_indicator
+            && matches!(
+                segmentation.segmentation_type.category(),
+                SegmentationCategory::Break
+                    | SegmentationCategory::Advertisement
+                    | SegmentationCategory::PlacementOpportunity
+            )
+        {
+            if segmentation.segmentation_type.is_start() {
+                return CueKind::Out;
+            }
+            if segmentation.segmentation_type.is_end() {
+                return CueKind::In;
+            }
+        }
+    }
+    CueKind::Command
+}
+
+/// Writes a DASH `EventStream` with one `Event` on the 90kHz timeline of the section.
+fn event_stream(section: &SpliceInfoSection, binary: &str, options: &EncodeOptions) -> String {
+    let mut event = Vec::new();
+    if let Some(pts) = section.effective_program_pts() {
+        event.push(("presentationTime", pts.ticks().to_string()));
+    }
+    if let Some((ticks, _)) = duration(section) {
+        event.push(("duration", ticks.to_string()));
+    }
+    if let Some(id) = options
+        .id
+        .clone()
+        .or_else(|| event_id(section).map(|id| id.to_string()))
+    {
+        event.push(("id", id));
+    }
+
+    let mut writer = XmlWriter::new();
+    writer.start(
+        "EventStream",
+        &[
+            ("schemeIdUri", DASH_SCHEME.to_string()),
+            ("timescale", "90000".to_string()),
+        ],
+    );
+    writer.start("Event", &event);
+    writer.start("Signal", &[("xmlns", NAMESPACE.to_string())]);
+    writer.text("Binary", &[], binary);
+    writer.end();
+    writer.end();
+    writer.end();
+    writer.finish()
+}
+
+// Every format is exercised, so the tests need the default features
+#[cfg(all(test, feature = "crc-validation", feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::fmt::parse_utc_time;
+
+    /// splice_insert, event 0x4800008F, out of network, 60.3s break at 21514.559089s,
+    /// with an avail_descriptor.
+    const SPLICE_INSERT: &str =
+        "/DAvAAAAAAAA///wFAVIAACPf+/+c2nALv4AUsz1AAAAAAAKAAhDVUVJAAABNWLbowo=";
+
+    /// [`SPLICE_INSERT`] with cw_index 0, which XML cannot carry.
+    fn cue() -> Vec<u8> {
+        use crate::encoding::CrcEncodable;
+
+        let bytes = decode(SPLICE_INSERT, Format::Base64).unwrap().remove(0);
+        let mut section = parse_splice_info_section(&bytes).unwrap();
+        section.cw_index = 0;
+        section.encode_with_crc().unwrap()
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let bytes = cue();
+        let options = EncodeOptions {
+            id: None,
+            start_date: parse_utc_time("2024-01-01T00:00:00Z"),
+        };
+        for format in [
+            Format::Base64,
+            Format::Hex,
+            Format::Json,
+            Format::Xml,
+            Format::Hls,
+            Format::Dash,
+        ] {
+            let text = encode(&bytes, format, &options).unwrap();
+            assert_eq!(
+                decode(&text, format).unwrap(),
+                vec![bytes.clone()],
+                "{format}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_carriage_formats() {
+        let bytes = cue();
+        let options = EncodeOptions {
+            id: None,
+            start_date: parse_utc_time("2024-01-01T00:00:00Z"),
+        };
+
+        let hls = encode(&bytes, Format::Hls, &options).unwrap();
+        assert!(hls.starts_with(
+            r#"#EXT-X-DATERANGE:ID="1207959695",START-DATE="2024-01-01T00:00:00Z",PLANNED-DURATION=60.294,SCTE35-OUT=0xFC302F"#
+        ));
+
+        let dash = encode(&bytes, Format::Dash, &options).unwrap();
+        assert!(dash.contains(
+            r#"<Event presentationTime="1936310318" duration="5426421" id="1207959695">"#
+        ));
+        assert!(dash.contains(&format!(
+            "<Binary>{}</Binary>",
+            encode_base64(&bytes).unwrap()
+        )));
+    }
+
+    #[test]
+    fn test_decode_documents() {
+        let bytes = cue();
+        let json = encode(&bytes, Format::Json, &EncodeOptions::default()).unwrap();
+        let envelope = format!(r#"{{"status": "success", "data": {json}}}"#);
+        let stream = format!("{json}\n[{envelope}, {json}]");
+        assert_eq!(decode(&stream, Format::Json).unwrap().len(), 3);
+
+        let playlist = "#EXT-X-CUE-OUT:30\n\
+            #EXT-X-SCTE35:CUE=\"/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==\"\n\
+            #EXT-X-CUE-IN\n";
+        assert_eq!(decode(playlist, Format::Hls).unwrap().len(), 1);
+
+        let error = decode("0xFC3", Format::Hex).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid hex input: odd number of hex digits"
+        );
+    }
+}
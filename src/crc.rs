@@ -58,8 +58,10 @@ pub fn calculate_crc(_data: &[u8]) -> Option<u32> {
 
 /// Validates the CRC-32 checksum of a complete SCTE-35 message.
 ///
-/// This function extracts the CRC from the last 4 bytes of the buffer
-/// and validates it against the calculated CRC of the preceding data.
+/// The message ends `section_length + 3` bytes into the buffer; this function extracts
+/// the CRC from the last 4 bytes of the message and validates it against the calculated
+/// CRC of the preceding data. Any bytes after the message are ignored. If the buffer is
+/// shorter than `section_length` indicates, the whole buffer is treated as the message.
 ///
 /// # Arguments
 ///
@@ -94,6 +96,13 @@ pub fn validate_message_crc(buffer: &[u8]) -> Result<bool, io::Error> {
         ));
     }
 
+    let section_end = 3 + ((((buffer[1] & 0x0F) as usize) << 8) | buffer[2] as usize);
+    let buffer = if section_end <= buffer.len() {
+        &buffer[..section_end]
+    } else {
+        buffer
+    };
+
     // Extract CRC from the last 4 bytes (big-endian)
     let crc_bytes = &buffer[buffer.len() - 4..];
     let stored_crc = u32::from_be_bytes([crc_bytes[0], crc_bytes[1], crc_bytes[2], crc_bytes[3]]);
@@ -3,16 +3,20 @@
 //! This module contains structures and functions for handling SCTE-35 descriptors,
 //! which provide additional metadata about splice operations.
 
+use crate::registry::{MpuPayload, PrivateDescriptor};
 use crate::types::SegmentationType;
-use crate::upid::{SegmentationUpidType, format_base64, format_isan, format_uuid};
-use std::time::Duration;
+use crate::upid::{
+    AtscContentIdentifier, MidUpid, SegmentationUpidType, Umid, decode_mpu_upid, format_upid,
+    parse_mid,
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Represents different types of splice descriptors with parsed content.
 ///
 /// This enum provides structured access to descriptor data, with full parsing
 /// for supported descriptor types and raw bytes for unsupported types.
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(tag = "descriptor_type"))]
 pub enum SpliceDescriptor {
     /// Segmentation descriptor (tag 0x02) - fully parsed
@@ -25,6 +29,11 @@ pub enum SpliceDescriptor {
     Time(TimeDescriptor),
     /// Audio descriptor (tag 0x04) - for audio component information
     Audio(AudioDescriptor),
+    /// Proprietary descriptor decoded by a parser registered with
+    /// [`register_descriptor`](crate::registry::register_descriptor)
+    ///
+    /// Deserializes as [`Unknown`](SpliceDescriptor::Unknown) with the same tag and bytes.
+    Private(Box<dyn PrivateDescriptor>),
     /// Unknown or unsupported descriptor type with raw bytes
     Unknown {
         /// Descriptor tag
@@ -34,10 +43,7 @@ pub enum SpliceDescriptor {
         /// Raw descriptor bytes
         #[cfg_attr(
             feature = "serde",
-            serde(
-                serialize_with = "crate::serde::serialize_bytes",
-                deserialize_with = "crate::serde::deserialize_bytes"
-            )
+            serde(serialize_with = "crate::serde::serialize_bytes")
         )]
         data: Vec<u8>,
     },
@@ -52,23 +58,30 @@ impl SpliceDescriptor {
             SpliceDescriptor::Dtmf(_) => 0x01,
             SpliceDescriptor::Time(_) => 0x03,
             SpliceDescriptor::Audio(_) => 0x04,
+            SpliceDescriptor::Private(desc) => desc.tag(),
             SpliceDescriptor::Unknown { tag, .. } => *tag,
         }
     }
 
     /// Returns the descriptor length.
-    pub fn length(&self) -> u8 {
+    ///
+    /// Returns `None` for a private descriptor whose identifier and private bytes do not
+    /// fit the 8-bit `descriptor_length` field; encoding such a descriptor fails.
+    pub fn length(&self) -> Option<u8> {
         match self {
             SpliceDescriptor::Segmentation(_) => {
                 // For segmentation descriptors, we calculate based on the actual content
                 // This is a simplified calculation - real implementation would serialize back
-                33 // Minimum segmentation descriptor length
+                Some(33) // Minimum segmentation descriptor length
             }
-            SpliceDescriptor::Avail(desc) => 4 + desc.provider_avail_id.len() as u8,
-            SpliceDescriptor::Dtmf(desc) => 4 + desc.dtmf_chars.len() as u8,
-            SpliceDescriptor::Time(_) => 4 + 6 + 4 + 2, // identifier + tai_seconds + tai_ns + utc_offset
-            SpliceDescriptor::Audio(desc) => 4 + desc.audio_components.len() as u8,
-            SpliceDescriptor::Unknown { length, .. } => *length,
+            SpliceDescriptor::Avail(desc) => Some(4 + desc.provider_avail_id.len() as u8),
+            SpliceDescriptor::Dtmf(desc) => Some(4 + desc.dtmf_chars.len() as u8),
+            SpliceDescriptor::Time(_) => Some(4 + 6 + 4 + 2), // identifier + tai_seconds + tai_ns + utc_offset
+            SpliceDescriptor::Audio(desc) => Some(4 + desc.audio_components.len() as u8),
+            SpliceDescriptor::Private(desc) => {
+                private_descriptor_length(&desc.private_bytes()).ok()
+            }
+            SpliceDescriptor::Unknown { length, .. } => Some(*length),
         }
     }
 
@@ -80,6 +93,7 @@ impl SpliceDescriptor {
             SpliceDescriptor::Dtmf(_) => None,
             SpliceDescriptor::Time(_) => None,
             SpliceDescriptor::Audio(_) => None,
+            SpliceDescriptor::Private(_) => None,
             SpliceDescriptor::Unknown { data, .. } => Some(data),
         }
     }
@@ -121,6 +135,7 @@ impl SpliceDescriptor {
                 .map(|s| s.to_string()),
             SpliceDescriptor::Time(_) => None, // Time data not interpretable as string
             SpliceDescriptor::Audio(_) => None, // Audio data not interpretable as string
+            SpliceDescriptor::Private(desc) => Some(desc.to_string()),
             SpliceDescriptor::Unknown { data, .. } => {
                 std::str::from_utf8(data).ok().map(|s| s.to_string())
             }
@@ -128,6 +143,13 @@ impl SpliceDescriptor {
     }
 }
 
+/// Returns the `descriptor_length` of a private descriptor: the identifier plus its
+/// private bytes, or the oversized length if that does not fit 8 bits.
+pub(crate) fn private_descriptor_length(private_bytes: &[u8]) -> Result<u8, usize> {
+    let length = 4 + private_bytes.len();
+    u8::try_from(length).map_err(|_| length)
+}
+
 /// Represents a parsed segmentation descriptor (tag 0x02).
 ///
 /// Segmentation descriptors provide detailed information about content segments,
@@ -194,6 +216,9 @@ impl SegmentationDescriptor {
     /// - `Some(String)` if the UPID can be converted to a readable string
     /// - `None` if the UPID type doesn't support string conversion or the data is malformed
     ///
+    /// MPU UPIDs are rendered with the process-wide decoder registered for their format
+    /// identifier, if any; see [`registry`](crate::registry#global-state).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -224,54 +249,49 @@ impl SegmentationDescriptor {
     /// assert_eq!(descriptor.upid_as_string(), Some("ABCD01234567".to_string()));
     /// ```
     pub fn upid_as_string(&self) -> Option<String> {
-        match self.segmentation_upid_type {
-            SegmentationUpidType::URI | SegmentationUpidType::AdID | SegmentationUpidType::TID => {
-                std::str::from_utf8(&self.segmentation_upid)
-                    .ok()
-                    .map(|s| s.to_string())
-            }
-            SegmentationUpidType::MPU => {
-                // MPU has format identifier (4 bytes) + private data
-                if self.segmentation_upid.len() >= 4 {
-                    let format_identifier = u32::from_be_bytes([
-                        self.segmentation_upid[0],
-                        self.segmentation_upid[1],
-                        self.segmentation_upid[2],
-                        self.segmentation_upid[3],
-                    ]);
-                    let private_data = &self.segmentation_upid[4..];
-
-                    // Format using our fmt utilities
-                    let format_str = crate::fmt::format_identifier_to_string(format_identifier);
-                    let data_str = crate::fmt::format_private_data(private_data);
-                    Some(format!("MPU(format: {format_str}, data: {data_str})"))
-                } else {
-                    None
-                }
-            }
-            SegmentationUpidType::UUID => {
-                if self.segmentation_upid.len() == 16 {
-                    Some(format_uuid(&self.segmentation_upid))
-                } else {
-                    None
-                }
-            }
-            SegmentationUpidType::ISAN => {
-                if self.segmentation_upid.len() >= 12 {
-                    Some(format_isan(&self.segmentation_upid))
-                } else {
-                    None
-                }
-            }
-            // For other types, return base64 representation for now
-            _ => {
-                if !self.segmentation_upid.is_empty() {
-                    Some(format_base64(&self.segmentation_upid))
-                } else {
-                    None
-                }
-            }
+        format_upid(self.segmentation_upid_type, &self.segmentation_upid)
+    }
+
+    /// Decodes the member UPIDs of a MID (0x0D) segmentation UPID.
+    ///
+    /// Returns `None` if the UPID is not a MID or its members are malformed.
+    pub fn mid_upids(&self) -> Option<Vec<MidUpid>> {
+        if self.segmentation_upid_type != SegmentationUpidType::MID {
+            return None;
         }
+        parse_mid(&self.segmentation_upid)
+    }
+
+    /// Decodes the private data of an MPU (0x0C) segmentation UPID.
+    ///
+    /// Uses the decoder registered for the UPID's `format_identifier` with
+    /// [`register_mpu_decoder`](crate::registry::register_mpu_decoder). Returns `None`
+    /// if the UPID is not an MPU, no decoder is registered or the decoder rejects the data.
+    pub fn mpu_payload(&self) -> Option<Box<dyn MpuPayload>> {
+        if self.segmentation_upid_type != SegmentationUpidType::MPU {
+            return None;
+        }
+        decode_mpu_upid(&self.segmentation_upid)
+    }
+
+    /// Decodes a UMID (0x04) segmentation UPID into its SMPTE 330M parts.
+    ///
+    /// Returns `None` if the UPID is not a 32-byte UMID.
+    pub fn umid(&self) -> Option<Umid> {
+        if self.segmentation_upid_type != SegmentationUpidType::UMID {
+            return None;
+        }
+        Umid::from_bytes(&self.segmentation_upid)
+    }
+
+    /// Decodes an ATSC Content Identifier (0x0B) segmentation UPID.
+    ///
+    /// Returns `None` if the UPID is not an ATSC Content Identifier or is too short.
+    pub fn atsc_content_identifier(&self) -> Option<AtscContentIdentifier> {
+        if self.segmentation_upid_type != SegmentationUpidType::ATSCContentIdentifier {
+            return None;
+        }
+        AtscContentIdentifier::from_bytes(&self.segmentation_upid)
     }
 
     /// Returns a description of the UPID type.
@@ -576,6 +596,49 @@ pub struct TimeDescriptor {
     pub utc_offset: Vec<u8>,
 }
 
+impl TimeDescriptor {
+    /// Returns the TAI seconds field as an integer, if it holds the expected 6 bytes.
+    pub fn tai_seconds_value(&self) -> Option<u64> {
+        if self.tai_seconds.len() != 6 {
+            return None;
+        }
+        Some(
+            self.tai_seconds
+                .iter()
+                .fold(0u64, |acc, &byte| (acc << 8) | byte as u64),
+        )
+    }
+
+    /// Returns the TAI nanoseconds field as an integer, if it holds the expected 4 bytes.
+    pub fn tai_ns_value(&self) -> Option<u32> {
+        let bytes: [u8; 4] = self.tai_ns.as_slice().try_into().ok()?;
+        Some(u32::from_be_bytes(bytes))
+    }
+
+    /// Returns the UTC offset (TAI - UTC) in seconds, if it holds the expected 2 bytes.
+    pub fn utc_offset_value(&self) -> Option<u16> {
+        let bytes: [u8; 2] = self.utc_offset.as_slice().try_into().ok()?;
+        Some(u16::from_be_bytes(bytes))
+    }
+
+    /// Converts the descriptor's TAI time to a UTC [`SystemTime`].
+    ///
+    /// TAI seconds are counted from the PTP epoch (1970-01-01 00:00:00 TAI), so the
+    /// UTC time is obtained by subtracting `utc_offset` from the TAI seconds.
+    ///
+    /// Returns `None` if any field has an unexpected size or the nanoseconds are out of range.
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let tai_seconds = self.tai_seconds_value()?;
+        let tai_ns = self.tai_ns_value()?;
+        let utc_offset = self.utc_offset_value()?;
+        if tai_ns >= 1_000_000_000 {
+            return None;
+        }
+        let utc_seconds = tai_seconds.checked_sub(utc_offset as u64)?;
+        UNIX_EPOCH.checked_add(Duration::new(utc_seconds, tai_ns))
+    }
+}
+
 /// Audio descriptor for audio component information.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -0,0 +1,758 @@
+//! Field-level comparison of two splice information sections.
+//!
+//! [`diff`] walks both messages and reports every field whose value differs, identified
+//! by a path such as `splice_command.break_duration.duration` or
+//! `splice_descriptors[1].segmentation_upid`. Descriptors are matched by tag (and by
+//! `segmentation_event_id` for segmentation descriptors) rather than by position, so an
+//! inserted or reordered descriptor shows up as one added or removed entry instead of a
+//! cascade of modifications.
+
+use crate::descriptors::{SegmentationDescriptor, SpliceDescriptor};
+use crate::encoding::Encodable;
+use crate::fmt::format_private_data;
+use crate::time::{BreakDuration, SpliceTime};
+use crate::types::{SegmentationType, SpliceCommand, SpliceInfoSection, SpliceScheduleEvent};
+use crate::upid::format_upid;
+use std::fmt;
+
+/// How a field differs between the two messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ChangeKind {
+    /// Present only in the second message
+    Added,
+    /// Present only in the first message
+    Removed,
+    /// Present in both with different values
+    Modified,
+}
+
+/// A single difference between two messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Change {
+    /// Path of the field, e.g. `"splice_descriptors[0].segment_num"`
+    pub path: String,
+    /// Whether the field was added, removed or modified
+    pub kind: ChangeKind,
+    /// Value in the first message
+    pub old: Option<String>,
+    /// Value in the second message
+    pub new: Option<String>,
+}
+
+/// Fields that are derived from the rest of the message when it is encoded.
+const DERIVED_FIELDS: &[&str] = &[
+    "section_length",
+    "splice_command_length",
+    "descriptor_loop_length",
+    "segmentation_upid_length",
+    "private_command_length",
+    "component_count",
+    "num_splice",
+    "crc_32",
+    "e_crc_32",
+];
+
+impl Change {
+    /// Returns `true` for CRC and length fields, which change whenever the content does.
+    ///
+    /// Filtering these out leaves only the differences in content.
+    pub fn is_derived(&self) -> bool {
+        let field = self.path.rsplit('.').next().unwrap_or(&self.path);
+        DERIVED_FIELDS.contains(&field)
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let none = "-";
+        match self.kind {
+            ChangeKind::Added => write!(
+                f,
+                "+ {}: {}",
+                self.path,
+                self.new.as_deref().unwrap_or(none)
+            ),
+            ChangeKind::Removed => write!(
+                f,
+                "- {}: {}",
+                self.path,
+                self.old.as_deref().unwrap_or(none)
+            ),
+            ChangeKind::Modified => write!(
+                f,
+                "~ {}: {} -> {}",
+                self.path,
+                self.old.as_deref().unwrap_or(none),
+                self.new.as_deref().unwrap_or(none)
+            ),
+        }
+    }
+}
+
+/// Compares two splice information sections field by field.
+///
+/// Returns the differences in message order; an empty list means the messages are
+/// equivalent. Descriptor paths use the index in `old` for matched and removed
+/// descriptors, and the index in `new` for added ones.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::builders::{SpliceInfoSectionBuilder, SpliceInsertBuilder};
+/// use scte35::diff::{ChangeKind, diff};
+/// use std::time::Duration;
+///
+/// let insert = |seconds| {
+///     SpliceInsertBuilder::new(0x1234)
+///         .duration(Duration::from_secs(seconds))
+///         .build()
+///         .unwrap()
+/// };
+/// let old = SpliceInfoSectionBuilder::new().splice_insert(insert(30)).build()?;
+/// let new = SpliceInfoSectionBuilder::new().splice_insert(insert(60)).build()?;
+///
+/// let changes = diff(&old, &new);
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].path, "splice_command.break_duration.duration");
+/// assert_eq!(changes[0].kind, ChangeKind::Modified);
+/// assert_eq!(changes[0].old.as_deref(), Some("2700000 (30.000000s)"));
+/// # Ok::<(), scte35::builders::BuilderError>(())
+/// ```
+pub fn diff(old: &SpliceInfoSection, new: &SpliceInfoSection) -> Vec<Change> {
+    let mut differ = Differ::default();
+
+    differ.field("table_id", hex(old.table_id), hex(new.table_id));
+    differ.field(
+        "section_syntax_indicator",
+        old.section_syntax_indicator,
+        new.section_syntax_indicator,
+    );
+    differ.field(
+        "private_indicator",
+        old.private_indicator,
+        new.private_indicator,
+    );
+    differ.field("sap_type", old.sap_type, new.sap_type);
+    differ.field("section_length", old.section_length, new.section_length);
+    differ.field(
+        "protocol_version",
+        old.protocol_version,
+        new.protocol_version,
+    );
+    differ.field(
+        "encrypted_packet",
+        old.encrypted_packet,
+        new.encrypted_packet,
+    );
+    differ.field(
+        "encryption_algorithm",
+        old.encryption_algorithm,
+        new.encryption_algorithm,
+    );
+    differ.field(
+        "pts_adjustment",
+        ticks(old.pts_adjustment),
+        ticks(new.pts_adjustment),
+    );
+    differ.field("cw_index", old.cw_index, new.cw_index);
+    differ.field("tier", hex(old.tier), hex(new.tier));
+    differ.field(
+        "splice_command_length",
+        old.splice_command_length,
+        new.splice_command_length,
+    );
+    differ.field(
+        "splice_command_type",
+        hex(old.splice_command_type),
+        hex(new.splice_command_type),
+    );
+
+    diff_commands(&mut differ, &old.splice_command, &new.splice_command);
+
+    differ.field(
+        "descriptor_loop_length",
+        old.descriptor_loop_length,
+        new.descriptor_loop_length,
+    );
+    diff_descriptors(
+        &mut differ,
+        &old.splice_descriptors,
+        &new.splice_descriptors,
+    );
+
+    differ.field(
+        "alignment_stuffing_bits",
+        old.alignment_stuffing_bits.len(),
+        new.alignment_stuffing_bits.len(),
+    );
+    differ.optional("e_crc_32", old.e_crc_32.map(hex), new.e_crc_32.map(hex));
+    differ.field("crc_32", hex(old.crc_32), hex(new.crc_32));
+
+    differ.changes
+}
+
+/// Collects changes under a path prefix.
+#[derive(Default)]
+struct Differ {
+    prefix: String,
+    changes: Vec<Change>,
+}
+
+impl Differ {
+    fn path(&self, name: &str) -> String {
+        if self.prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{name}", self.prefix)
+        }
+    }
+
+    fn push(&mut self, name: &str, kind: ChangeKind, old: Option<String>, new: Option<String>) {
+        self.changes.push(Change {
+            path: self.path(name),
+            kind,
+            old,
+            new,
+        });
+    }
+
+    /// Records a modification when the two values differ.
+    fn field<T: PartialEq + fmt::Display>(&mut self, name: &str, old: T, new: T) {
+        if old != new {
+            self.push(
+                name,
+                ChangeKind::Modified,
+                Some(old.to_string()),
+                Some(new.to_string()),
+            );
+        }
+    }
+
+    /// Records an addition, removal or modification of an optional field.
+    fn optional<T: PartialEq + fmt::Display>(
+        &mut self,
+        name: &str,
+        old: Option<T>,
+        new: Option<T>,
+    ) {
+        let kind = match (&old, &new) {
+            (None, None) => return,
+            (Some(_), None) => ChangeKind::Removed,
+            (None, Some(_)) => ChangeKind::Added,
+            (Some(old), Some(new)) if old == new => return,
+            (Some(_), Some(_)) => ChangeKind::Modified,
+        };
+        self.push(
+            name,
+            kind,
+            old.map(|value| value.to_string()),
+            new.map(|value| value.to_string()),
+        );
+    }
+
+    /// Runs `compare` with `name` appended to the path prefix.
+    fn nested(&mut self, name: &str, compare: impl FnOnce(&mut Self)) {
+        let saved = std::mem::take(&mut self.prefix);
+        self.prefix = if saved.is_empty() {
+            name.to_string()
+        } else {
+            format!("{saved}.{name}")
+        };
+        compare(self);
+        self.prefix = saved;
+    }
+}
+
+fn hex<T: fmt::UpperHex>(value: T) -> String {
+    format!("0x{value:X}")
+}
+
+fn ticks(value: u64) -> String {
+    format!("{value} ({:.6}s)", value as f64 / 90_000.0)
+}
+
+fn diff_splice_time(differ: &mut Differ, old: Option<&SpliceTime>, new: Option<&SpliceTime>) {
+    match (old, new) {
+        (Some(old), Some(new)) => differ.nested("splice_time", |differ| {
+            differ.field(
+                "time_specified_flag",
+                old.time_specified_flag,
+                new.time_specified_flag,
+            );
+            differ.optional("pts_time", old.pts_time.map(ticks), new.pts_time.map(ticks));
+        }),
+        (old, new) => differ.optional(
+            "splice_time",
+            old.map(splice_time_summary),
+            new.map(splice_time_summary),
+        ),
+    }
+}
+
+fn splice_time_summary(splice_time: &SpliceTime) -> String {
+    splice_time
+        .pts_time
+        .map(ticks)
+        .unwrap_or_else(|| "no time specified".to_string())
+}
+
+fn diff_break_duration(
+    differ: &mut Differ,
+    old: Option<&BreakDuration>,
+    new: Option<&BreakDuration>,
+) {
+    match (old, new) {
+        (Some(old), Some(new)) => differ.nested("break_duration", |differ| {
+            differ.field("auto_return", old.auto_return, new.auto_return);
+            differ.field("duration", ticks(old.duration), ticks(new.duration));
+        }),
+        (old, new) => differ.optional(
+            "break_duration",
+            old.map(|duration| ticks(duration.duration)),
+            new.map(|duration| ticks(duration.duration)),
+        ),
+    }
+}
+
+fn diff_schedule_event(differ: &mut Differ, old: &SpliceScheduleEvent, new: &SpliceScheduleEvent) {
+    differ.field(
+        "splice_event_id",
+        hex(old.splice_event_id),
+        hex(new.splice_event_id),
+    );
+    differ.field(
+        "splice_event_cancel_indicator",
+        old.splice_event_cancel_indicator,
+        new.splice_event_cancel_indicator,
+    );
+    differ.field(
+        "out_of_network_indicator",
+        old.out_of_network_indicator,
+        new.out_of_network_indicator,
+    );
+    differ.field(
+        "program_splice_flag",
+        old.program_splice_flag,
+        new.program_splice_flag,
+    );
+    differ.field("duration_flag", old.duration_flag, new.duration_flag);
+    differ.optional("utc_splice_time", old.utc_splice_time, new.utc_splice_time);
+    differ.field("component_count", old.component_count, new.component_count);
+    for index in 0..old.components.len().max(new.components.len()) {
+        let (old, new) = (old.components.get(index), new.components.get(index));
+        differ.nested(&format!("components[{index}]"), |differ| {
+            differ.optional(
+                "component_tag",
+                old.map(|component| component.component_tag),
+                new.map(|component| component.component_tag),
+            );
+            differ.optional(
+                "utc_splice_time",
+                old.map(|component| component.utc_splice_time),
+                new.map(|component| component.utc_splice_time),
+            );
+        });
+    }
+    diff_break_duration(
+        differ,
+        old.break_duration.as_ref(),
+        new.break_duration.as_ref(),
+    );
+    differ.field(
+        "unique_program_id",
+        old.unique_program_id,
+        new.unique_program_id,
+    );
+    differ.field("avail_num", old.avail_num, new.avail_num);
+    differ.field("avails_expected", old.avails_expected, new.avails_expected);
+}
+
+fn diff_commands(differ: &mut Differ, old: &SpliceCommand, new: &SpliceCommand) {
+    differ.nested("splice_command", |differ| match (old, new) {
+        (SpliceCommand::SpliceInsert(old), SpliceCommand::SpliceInsert(new)) => {
+            differ.field(
+                "splice_event_id",
+                hex(old.splice_event_id),
+                hex(new.splice_event_id),
+            );
+            differ.field(
+                "splice_event_cancel_indicator",
+                old.splice_event_cancel_indicator,
+                new.splice_event_cancel_indicator,
+            );
+            differ.field(
+                "out_of_network_indicator",
+                old.out_of_network_indicator,
+                new.out_of_network_indicator,
+            );
+            differ.field(
+                "program_splice_flag",
+                old.program_splice_flag,
+                new.program_splice_flag,
+            );
+            differ.field("duration_flag", old.duration_flag, new.duration_flag);
+            differ.field(
+                "splice_immediate_flag",
+                old.splice_immediate_flag,
+                new.splice_immediate_flag,
+            );
+            diff_splice_time(differ, old.splice_time.as_ref(), new.splice_time.as_ref());
+            differ.field("component_count", old.component_count, new.component_count);
+            for index in 0..old.components.len().max(new.components.len()) {
+                let (old, new) = (old.components.get(index), new.components.get(index));
+                differ.nested(&format!("components[{index}]"), |differ| {
+                    differ.optional(
+                        "component_tag",
+                        old.map(|component| component.component_tag),
+                        new.map(|component| component.component_tag),
+                    );
+                    diff_splice_time(
+                        differ,
+                        old.and_then(|component| component.splice_time.as_ref()),
+                        new.and_then(|component| component.splice_time.as_ref()),
+                    );
+                });
+            }
+            diff_break_duration(
+                differ,
+                old.break_duration.as_ref(),
+                new.break_duration.as_ref(),
+            );
+            differ.field(
+                "unique_program_id",
+                old.unique_program_id,
+                new.unique_program_id,
+            );
+            differ.field("avail_num", old.avail_num, new.avail_num);
+            differ.field("avails_expected", old.avails_expected, new.avails_expected);
+        }
+        (SpliceCommand::TimeSignal(old), SpliceCommand::TimeSignal(new)) => {
+            diff_splice_time(differ, Some(&old.splice_time), Some(&new.splice_time));
+        }
+        (SpliceCommand::SpliceSchedule(old), SpliceCommand::SpliceSchedule(new)) => {
+            differ.field("splice_count", old.splice_count, new.splice_count);
+            for index in 0..old.events.len().max(new.events.len()) {
+                let name = format!("events[{index}]");
+                match (old.events.get(index), new.events.get(index)) {
+                    (Some(old), Some(new)) => {
+                        differ.nested(&name, |differ| diff_schedule_event(differ, old, new))
+                    }
+                    (old, new) => differ.optional(
+                        &name,
+                        old.map(|event| hex(event.splice_event_id)),
+                        new.map(|event| hex(event.splice_event_id)),
+                    ),
+                }
+            }
+        }
+        (SpliceCommand::BandwidthReservation(old), SpliceCommand::BandwidthReservation(new)) => {
+            differ.field(
+                "dwbw_reservation",
+                old.dwbw_reservation,
+                new.dwbw_reservation,
+            );
+        }
+        (SpliceCommand::PrivateCommand(old), SpliceCommand::PrivateCommand(new)) => {
+            differ.field(
+                "private_command_id",
+                hex(old.private_command_id),
+                hex(new.private_command_id),
+            );
+            differ.field(
+                "private_command_length",
+                old.private_command_length,
+                new.private_command_length,
+            );
+            differ.field(
+                "private_bytes",
+                format_private_data(&old.private_bytes),
+                format_private_data(&new.private_bytes),
+            );
+        }
+        (SpliceCommand::SpliceNull, SpliceCommand::SpliceNull)
+        | (SpliceCommand::Unknown, SpliceCommand::Unknown) => {}
+        (old, new) => differ.push(
+            "type",
+            ChangeKind::Modified,
+            Some(old.name().into()),
+            Some(new.name().into()),
+        ),
+    });
+}
+
+/// Key used to match descriptors between the two messages.
+fn descriptor_key(descriptor: &SpliceDescriptor) -> (u8, Option<u32>) {
+    match descriptor {
+        SpliceDescriptor::Segmentation(segmentation) => {
+            (0x02, Some(segmentation.segmentation_event_id))
+        }
+        other => (other.tag(), None),
+    }
+}
+
+fn descriptor_summary(descriptor: &SpliceDescriptor) -> String {
+    match descriptor {
+        SpliceDescriptor::Segmentation(segmentation) => format!(
+            "segmentation_descriptor(event_id: 0x{:08X}, type: {})",
+            segmentation.segmentation_event_id,
+            SegmentationType::from_id(segmentation.segmentation_type_id)
+        ),
+        other => format!(
+            "descriptor(tag: 0x{:02X}, {})",
+            other.tag(),
+            format_private_data(&descriptor_bytes(other))
+        ),
+    }
+}
+
+fn descriptor_bytes(descriptor: &SpliceDescriptor) -> Vec<u8> {
+    descriptor.encode_to_vec().unwrap_or_default()
+}
+
+fn diff_descriptors(differ: &mut Differ, old: &[SpliceDescriptor], new: &[SpliceDescriptor]) {
+    let mut matched = vec![false; new.len()];
+
+    for (old_index, old_descriptor) in old.iter().enumerate() {
+        let key = descriptor_key(old_descriptor);
+        let partner =
+            (0..new.len()).find(|&index| !matched[index] && descriptor_key(&new[index]) == key);
+        let path = format!("splice_descriptors[{old_index}]");
+
+        match partner {
+            Some(new_index) => {
+                matched[new_index] = true;
+                differ.nested(&path, |differ| match (old_descriptor, &new[new_index]) {
+                    (SpliceDescriptor::Segmentation(old), SpliceDescriptor::Segmentation(new)) => {
+                        diff_segmentation(differ, old, new)
+                    }
+                    (old, new) => differ.field(
+                        "data",
+                        format_private_data(&descriptor_bytes(old)),
+                        format_private_data(&descriptor_bytes(new)),
+                    ),
+                });
+            }
+            None => differ.push(
+                &path,
+                ChangeKind::Removed,
+                Some(descriptor_summary(old_descriptor)),
+                None,
+            ),
+        }
+    }
+
+    for (new_index, new_descriptor) in new.iter().enumerate() {
+        if !matched[new_index] {
+            differ.push(
+                &format!("splice_descriptors[{new_index}]"),
+                ChangeKind::Added,
+                None,
+                Some(descriptor_summary(new_descriptor)),
+            );
+        }
+    }
+}
+
+fn diff_segmentation(
+    differ: &mut Differ,
+    old: &SegmentationDescriptor,
+    new: &SegmentationDescriptor,
+) {
+    differ.field(
+        "segmentation_event_cancel_indicator",
+        old.segmentation_event_cancel_indicator,
+        new.segmentation_event_cancel_indicator,
+    );
+    differ.field(
+        "program_segmentation_flag",
+        old.program_segmentation_flag,
+        new.program_segmentation_flag,
+    );
+    differ.field(
+        "segmentation_duration_flag",
+        old.segmentation_duration_flag,
+        new.segmentation_duration_flag,
+    );
+    differ.field(
+        "delivery_not_restricted_flag",
+        old.delivery_not_restricted_flag,
+        new.delivery_not_restricted_flag,
+    );
+    differ.optional(
+        "web_delivery_allowed_flag",
+        old.web_delivery_allowed_flag,
+        new.web_delivery_allowed_flag,
+    );
+    differ.optional(
+        "no_regional_blackout_flag",
+        old.no_regional_blackout_flag,
+        new.no_regional_blackout_flag,
+    );
+    differ.optional(
+        "archive_allowed_flag",
+        old.archive_allowed_flag,
+        new.archive_allowed_flag,
+    );
+    differ.optional(
+        "device_restrictions",
+        old.device_restrictions,
+        new.device_restrictions,
+    );
+    differ.optional(
+        "segmentation_duration",
+        old.segmentation_duration.map(ticks),
+        new.segmentation_duration.map(ticks),
+    );
+    differ.field(
+        "segmentation_upid_type",
+        old.segmentation_upid_type,
+        new.segmentation_upid_type,
+    );
+    differ.field(
+        "segmentation_upid_length",
+        old.segmentation_upid_length,
+        new.segmentation_upid_length,
+    );
+    let upid = |descriptor: &SegmentationDescriptor| {
+        format_upid(
+            descriptor.segmentation_upid_type,
+            &descriptor.segmentation_upid,
+        )
+        .unwrap_or_else(|| format_private_data(&descriptor.segmentation_upid))
+    };
+    if old.segmentation_upid != new.segmentation_upid {
+        differ.push(
+            "segmentation_upid",
+            ChangeKind::Modified,
+            Some(upid(old)),
+            Some(upid(new)),
+        );
+    }
+    let segmentation_type = |id: u8| format!("0x{id:02X} ({})", SegmentationType::from_id(id));
+    differ.field(
+        "segmentation_type_id",
+        segmentation_type(old.segmentation_type_id),
+        segmentation_type(new.segmentation_type_id),
+    );
+    differ.field("segment_num", old.segment_num, new.segment_num);
+    differ.field(
+        "segments_expected",
+        old.segments_expected,
+        new.segments_expected,
+    );
+    differ.optional("sub_segment_num", old.sub_segment_num, new.sub_segment_num);
+    differ.optional(
+        "sub_segments_expected",
+        old.sub_segments_expected,
+        new.sub_segments_expected,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builders::{
+        SegmentationDescriptorBuilder, SpliceInfoSectionBuilder, TimeSignalBuilder, Upid,
+    };
+    use std::time::Duration;
+
+    fn time_signal(descriptors: Vec<SegmentationDescriptor>) -> SpliceInfoSection {
+        let mut builder = SpliceInfoSectionBuilder::new().time_signal(
+            TimeSignalBuilder::new()
+                .at_pts(Duration::from_secs(10))
+                .unwrap()
+                .build()
+                .unwrap(),
+        );
+        for descriptor in descriptors {
+            builder = builder.add_segmentation_descriptor(descriptor);
+        }
+        builder.build().unwrap()
+    }
+
+    fn segmentation(event_id: u32, segmentation_type: SegmentationType) -> SegmentationDescriptor {
+        SegmentationDescriptorBuilder::new(event_id, segmentation_type)
+            .upid(Upid::AdId("ABCD0001000H".to_string()))
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_identical_messages() {
+        let section = time_signal(vec![segmentation(1, SegmentationType::ProgramEnd)]);
+        assert!(diff(&section, &section).is_empty());
+    }
+
+    #[test]
+    fn test_descriptor_alignment() {
+        let old = time_signal(vec![
+            segmentation(1, SegmentationType::ProgramEnd),
+            segmentation(2, SegmentationType::ProviderAdvertisementEnd),
+        ]);
+        let mut changed = segmentation(2, SegmentationType::ProviderAdvertisementEnd);
+        changed.segment_num = 2;
+        changed.segments_expected = 2;
+        // Event 1 dropped, event 3 inserted ahead of event 2
+        let new = time_signal(vec![
+            segmentation(3, SegmentationType::ProgramStart),
+            changed,
+        ]);
+
+        let changes: Vec<_> = diff(&old, &new)
+            .into_iter()
+            .filter(|change| !change.is_derived())
+            .collect();
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|change| (change.path.as_str(), change.kind))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("splice_descriptors[0]", ChangeKind::Removed),
+                ("splice_descriptors[1].segment_num", ChangeKind::Modified),
+                (
+                    "splice_descriptors[1].segments_expected",
+                    ChangeKind::Modified
+                ),
+                ("splice_descriptors[0]", ChangeKind::Added),
+            ]
+        );
+        assert_eq!(
+            changes[3].new.as_deref(),
+            Some("segmentation_descriptor(event_id: 0x00000003, type: Program Start)")
+        );
+    }
+
+    #[test]
+    fn test_command_and_derived_changes() {
+        let old = time_signal(vec![]);
+        let new = SpliceInfoSectionBuilder::new()
+            .splice_null()
+            .build()
+            .unwrap();
+
+        let changes = diff(&old, &new);
+        let type_change = changes
+            .iter()
+            .find(|change| change.path == "splice_command.type")
+            .unwrap();
+        assert_eq!(type_change.old.as_deref(), Some("TimeSignal"));
+        assert_eq!(type_change.new.as_deref(), Some("SpliceNull"));
+        assert_eq!(
+            type_change.to_string(),
+            "~ splice_command.type: TimeSignal -> SpliceNull"
+        );
+
+        let derived: Vec<_> = changes
+            .iter()
+            .filter(|change| change.is_derived())
+            .map(|change| change.path.as_str())
+            .collect();
+        assert_eq!(derived, vec!["section_length", "splice_command_length"]);
+    }
+}
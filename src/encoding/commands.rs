@@ -156,69 +156,99 @@ impl Encodable for TimeSignal {
 
 impl Encodable for SpliceSchedule {
     fn encode(&self, writer: &mut BitWriter) -> EncodingResult<()> {
-        // splice_count (8 bits) - number of splice events
-        writer.write_bits(1u64, 8)?; // For simplicity, encoding as single event
+        // splice_count (8 bits)
+        writer.write_bits(self.splice_count as u64, 8)?;
 
+        for event in &self.events {
+            event.encode(writer)?;
+        }
+
+        Ok(())
+    }
+
+    fn encoded_size(&self) -> usize {
+        1 + self
+            .events
+            .iter()
+            .map(|event| event.encoded_size())
+            .sum::<usize>()
+    }
+}
+
+impl Encodable for SpliceScheduleEvent {
+    fn encode(&self, writer: &mut BitWriter) -> EncodingResult<()> {
         // splice_event_id (32 bits)
         writer.write_bits(self.splice_event_id as u64, 32)?;
 
         // splice_event_cancel_indicator (1 bit)
         writer.write_bits(self.splice_event_cancel_indicator as u64, 1)?;
 
-        // event_id_compliance_flag (1 bit) - part of reserved
-        writer.write_bits((self.reserved >> 6) as u64 & 1, 1)?;
-
-        // reserved (6 bits)
-        writer.write_bits(self.reserved as u64 & 0x3F, 6)?;
+        // event_id_compliance_flag (1 bit) + reserved (6 bits)
+        writer.write_bits(self.reserved as u64, 7)?;
 
         if self.splice_event_cancel_indicator == 0 {
             // out_of_network_indicator (1 bit)
             writer.write_bits(self.out_of_network_indicator as u64, 1)?;
 
-            // program_splice_flag (1 bit) - assuming always 1 for program-level
-            writer.write_bits(1u64, 1)?;
+            // program_splice_flag (1 bit)
+            writer.write_bits(self.program_splice_flag as u64, 1)?;
 
             // duration_flag (1 bit)
             writer.write_bits(self.duration_flag as u64, 1)?;
 
-            // reserved (5 bits) - should be all 1s
-            writer.write_bits(0x1F, 5)?; // 0x1F = 11111 in binary
+            // reserved (5 bits)
+            writer.write_bits(self.reserved2 as u64, 5)?;
 
-            // utc_splice_time (32 bits) if program_splice_flag == 1
-            if let Some(utc_time) = self.utc_splice_time {
-                writer.write_bits(utc_time as u64, 32)?;
-            }
+            if self.program_splice_flag == 1 {
+                // utc_splice_time (32 bits)
+                writer.write_bits(self.utc_splice_time.unwrap_or(0) as u64, 32)?;
+            } else {
+                // component_count (8 bits)
+                writer.write_bits(self.component_count as u64, 8)?;
 
-            // break_duration if duration_flag == 1
-            #[allow(clippy::collapsible_if)]
-            if self.duration_flag == 1 {
-                if let Some(splice_duration) = self.splice_duration {
-                    writer.write_bits(splice_duration as u64, 40)?; // break_duration is 40 bits
+                for component in &self.components {
+                    // component_tag (8 bits) + utc_splice_time (32 bits)
+                    writer.write_bits(component.component_tag as u64, 8)?;
+                    writer.write_bits(component.utc_splice_time as u64, 32)?;
                 }
             }
 
+            // Encode break_duration if duration_flag == 1
+            if self.duration_flag == 1
+                && let Some(ref break_duration) = self.break_duration
+            {
+                break_duration.encode(writer)?;
+            }
+
             // unique_program_id (16 bits)
             writer.write_bits(self.unique_program_id as u64, 16)?;
 
             // avail_num (8 bits)
-            writer.write_bits(self.num_splice as u64, 8)?;
+            writer.write_bits(self.avail_num as u64, 8)?;
 
-            // avails_expected (8 bits) - using num_splice as placeholder
-            writer.write_bits(self.num_splice as u64, 8)?;
+            // avails_expected (8 bits)
+            writer.write_bits(self.avails_expected as u64, 8)?;
         }
 
         Ok(())
     }
 
     fn encoded_size(&self) -> usize {
-        let mut size = 1 + 4 + 1; // splice_count + splice_event_id + flags
+        let mut size = 4 + 1; // splice_event_id + cancel indicator and reserved
 
         if self.splice_event_cancel_indicator == 0 {
-            size += 1; // flags
-            size += 4; // utc_splice_time
+            size += 1; // flags byte
 
-            if self.duration_flag == 1 {
-                size += 5; // break_duration (40 bits = 5 bytes)
+            if self.program_splice_flag == 1 {
+                size += 4; // utc_splice_time
+            } else {
+                size += 1 + 5 * self.components.len(); // component_count + components
+            }
+
+            if self.duration_flag == 1
+                && let Some(ref break_duration) = self.break_duration
+            {
+                size += break_duration.encoded_size();
             }
 
             size += 4; // unique_program_id + avail_num + avails_expected
@@ -1,7 +1,8 @@
 //! Encoding implementations for SCTE-35 descriptors.
 
 use crate::descriptors::*;
-use crate::encoding::{BitWriter, Encodable, EncodingResult};
+use crate::encoding::{BitWriter, Encodable, EncodingError, EncodingResult};
+use crate::types::SegmentationType;
 
 impl Encodable for SpliceDescriptor {
     fn encode(&self, writer: &mut BitWriter) -> EncodingResult<()> {
@@ -11,6 +12,31 @@ impl Encodable for SpliceDescriptor {
             SpliceDescriptor::Dtmf(desc) => desc.encode(writer),
             SpliceDescriptor::Time(desc) => desc.encode(writer),
             SpliceDescriptor::Audio(desc) => desc.encode(writer),
+            SpliceDescriptor::Private(desc) => {
+                let private_bytes = desc.private_bytes();
+                // identifier + private bytes must fit the 8-bit descriptor_length
+                let descriptor_length =
+                    private_descriptor_length(&private_bytes).map_err(|length| {
+                        EncodingError::ValueTooLarge {
+                            field: "descriptor_length",
+                            max_value: u8::MAX as u64,
+                            actual_value: length as u64,
+                        }
+                    })?;
+
+                // splice_descriptor_tag (8 bits)
+                writer.write_bits(desc.tag() as u64, 8)?;
+
+                // descriptor_length (8 bits) - identifier + private bytes
+                writer.write_bits(descriptor_length as u64, 8)?;
+
+                // identifier (32 bits)
+                writer.write_bits(desc.identifier() as u64, 32)?;
+
+                writer.write_bytes(&private_bytes)?;
+
+                Ok(())
+            }
             SpliceDescriptor::Unknown { tag, length, data } => {
                 // splice_descriptor_tag (8 bits)
                 writer.write_bits(*tag as u64, 8)?;
@@ -33,6 +59,7 @@ impl Encodable for SpliceDescriptor {
             SpliceDescriptor::Dtmf(desc) => desc.encoded_size(),
             SpliceDescriptor::Time(desc) => desc.encoded_size(),
             SpliceDescriptor::Audio(desc) => desc.encoded_size(),
+            SpliceDescriptor::Private(desc) => 6 + desc.private_bytes().len(), // tag + length + identifier + bytes
             SpliceDescriptor::Unknown { data, .. } => 2 + data.len(), // tag + length + data
         }
     }
@@ -128,18 +155,10 @@ impl Encodable for SegmentationDescriptor {
         // segments_expected (8 bits)
         writer.write_bits(self.segments_expected as u64, 8)?;
 
-        // Sub-segment fields for specific segmentation types that support sub-segments
-        // 0x34 (Provider Placement Opportunity Start) does NOT have sub-segment fields
-        if matches!(
-            self.segmentation_type_id,
-            0x30 | 0x32 | 0x36 | 0x38 | 0x3A | 0x44 | 0x46
-        ) {
-            if let Some(sub_segment_num) = self.sub_segment_num {
-                writer.write_bits(sub_segment_num as u64, 8)?;
-            }
-            if let Some(sub_segments_expected) = self.sub_segments_expected {
-                writer.write_bits(sub_segments_expected as u64, 8)?;
-            }
+        // Sub-segment fields for segmentation types that support sub-segments
+        if let Some((sub_segment_num, sub_segments_expected)) = self.encoded_sub_segments() {
+            writer.write_bits(sub_segment_num as u64, 8)?;
+            writer.write_bits(sub_segments_expected as u64, 8)?;
         }
 
         Ok(())
@@ -166,11 +185,8 @@ impl Encodable for SegmentationDescriptor {
         size += self.segmentation_upid.len(); // upid data
         size += 3; // type_id + segment_num + segments_expected
 
-        // Sub-segment fields - 0x34 (Provider Placement Opportunity Start) does NOT have sub-segment fields
-        if matches!(
-            self.segmentation_type_id,
-            0x30 | 0x32 | 0x36 | 0x38 | 0x3A | 0x44 | 0x46
-        ) {
+        // Sub-segment fields
+        if self.encoded_sub_segments().is_some() {
             size += 2; // sub_segment_num + sub_segments_expected
         }
 
@@ -179,6 +195,17 @@ impl Encodable for SegmentationDescriptor {
 }
 
 impl SegmentationDescriptor {
+    /// Returns the sub-segment fields to encode, if the segmentation type carries them.
+    ///
+    /// The fields are optional on the wire (older encoders omit them), so they are only
+    /// written when both values are present.
+    fn encoded_sub_segments(&self) -> Option<(u8, u8)> {
+        if !SegmentationType::from_id(self.segmentation_type_id).has_sub_segments() {
+            return None;
+        }
+        Some((self.sub_segment_num?, self.sub_segments_expected?))
+    }
+
     fn calculate_descriptor_length(&self) -> usize {
         // Calculate length excluding tag and length field itself
         self.encoded_size() - 2
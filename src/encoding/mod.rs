@@ -26,6 +26,7 @@ mod round_trip_tests;
 
 // Re-export commonly used types
 pub use error::{EncodingError, EncodingResult};
+pub use splice_info_section::encode_sections;
 pub use traits::Encodable;
 pub use writer::BitWriter;
 
@@ -293,7 +293,7 @@ mod tests {
 
     #[test]
     fn test_private_command() {
-        // PrivateCommand has no builder, so create manually
+        // Build the section manually to exercise the raw PrivateCommand fields
         use crate::types::{PrivateCommand, SpliceCommand, SpliceInfoSection};
 
         let private_command = PrivateCommand {
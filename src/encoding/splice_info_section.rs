@@ -165,3 +165,33 @@ use crate::encoding::Base64Encodable;
 
 #[cfg(feature = "base64")]
 impl Base64Encodable for SpliceInfoSection {}
+
+/// Encodes several splice information sections back to back into one buffer.
+///
+/// With the `crc-validation` feature each section gets a freshly calculated CRC-32;
+/// otherwise the stored `crc_32` values are written. The result can be split again with
+/// [`parse_sections`](crate::parse_sections).
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::builders::SpliceInfoSectionBuilder;
+/// use scte35::encoding::encode_sections;
+///
+/// let heartbeat = SpliceInfoSectionBuilder::new().splice_null().build().unwrap();
+/// let buffer = encode_sections([&heartbeat, &heartbeat]).unwrap();
+///
+/// assert_eq!(scte35::parse_sections(&buffer).count(), 2);
+/// ```
+pub fn encode_sections<'a>(
+    sections: impl IntoIterator<Item = &'a SpliceInfoSection>,
+) -> EncodingResult<Vec<u8>> {
+    let mut buffer = Vec::new();
+    for section in sections {
+        #[cfg(feature = "crc-validation")]
+        buffer.extend_from_slice(&CrcEncodable::encode_with_crc(section)?);
+        #[cfg(not(feature = "crc-validation"))]
+        buffer.extend_from_slice(&section.encode_to_vec()?);
+    }
+    Ok(buffer)
+}
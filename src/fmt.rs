@@ -4,6 +4,8 @@
 //! SCTE-35 data in human-readable formats, with intelligent handling
 //! of binary vs text data.
 
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 /// Converts a 32-bit format identifier to a human-readable string.
 ///
 /// Returns ASCII representation if all bytes are printable ASCII letters/numbers,
@@ -97,10 +99,222 @@ pub fn format_as_hex(data: &[u8]) -> String {
     }
 }
 
+/// Formats a wall-clock time as an ISO 8601 UTC timestamp.
+///
+/// Sub-second precision is included only when non-zero. Times before the Unix epoch
+/// are clamped to it.
+///
+/// # Examples
+/// ```rust
+/// use scte35::fmt::format_utc_time;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let time = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+/// assert_eq!(format_utc_time(time), "2024-01-01T00:00:00Z");
+/// assert_eq!(
+///     format_utc_time(time + Duration::from_millis(250)),
+///     "2024-01-01T00:00:00.250Z"
+/// );
+/// ```
+pub fn format_utc_time(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (year, month, day) = civil_from_days(seconds / 86_400);
+    let second_of_day = seconds % 86_400;
+    let millis = since_epoch.subsec_millis();
+
+    let mut formatted = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        second_of_day / 3600,
+        second_of_day / 60 % 60,
+        second_of_day % 60
+    );
+    if millis != 0 {
+        formatted.push_str(&format!(".{millis:03}"));
+    }
+    formatted.push('Z');
+    formatted
+}
+
+/// Parses an ISO 8601 timestamp such as `2024-01-01T00:00:00.250Z`.
+///
+/// This is the `date-time` form used by HLS `#EXT-X-PROGRAM-DATE-TIME` and
+/// `#EXT-X-DATERANGE` tags: a date and time with optional fractional seconds, followed
+/// by `Z` or a `+HH:MM`/`-HH:MM` offset. Returns `None` for anything else, including
+/// times before the Unix epoch and years too large for [`SystemTime`].
+///
+/// # Examples
+/// ```rust
+/// use scte35::fmt::{format_utc_time, parse_utc_time};
+///
+/// let time = parse_utc_time("2024-01-01T01:00:00.250+01:00").unwrap();
+/// assert_eq!(format_utc_time(time), "2024-01-01T00:00:00.250Z");
+/// assert_eq!(parse_utc_time("2024-01-01"), None);
+/// ```
+pub fn parse_utc_time(text: &str) -> Option<SystemTime> {
+    let number = |text: &str| -> Option<u64> {
+        text.bytes()
+            .all(|byte| byte.is_ascii_digit())
+            .then(|| text.parse().ok())
+            .flatten()
+    };
+    let (date, time) = text.split_once(['T', 't'])?;
+    let mut date = date.splitn(3, '-');
+    let (year, month, day) = (
+        number(date.next()?)?,
+        number(date.next()?)?,
+        number(date.next()?)?,
+    );
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Split off the zone designator, then any fraction of a second
+    let (time, offset_seconds) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else {
+        let sign_index = time.rfind(['+', '-'])?;
+        let (time, zone) = time.split_at(sign_index);
+        let digits = zone[1..].replace(':', "");
+        if digits.len() != 4 {
+            return None;
+        }
+        let offset = number(&digits[..2])? * 3600 + number(&digits[2..])? * 60;
+        (
+            time,
+            if zone.starts_with('-') {
+                -(offset as i64)
+            } else {
+                offset as i64
+            },
+        )
+    };
+    let (time, nanos) = match time.split_once('.') {
+        Some((time, fraction)) if !fraction.is_empty() && fraction.len() <= 9 => (
+            time,
+            number(fraction)? * 10u64.pow(9 - fraction.len() as u32),
+        ),
+        Some(_) => return None,
+        None => (time, 0),
+    };
+    let mut time = time.splitn(3, ':');
+    let (hour, minute, second) = (
+        number(time.next()?)?,
+        number(time.next()?)?,
+        number(time.next()?)?,
+    );
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    // Years far beyond any real timestamp overflow these sums; such dates are rejected
+    let seconds = days_from_civil(year, month, day)?
+        .checked_mul(86_400)?
+        .checked_add(hour * 3600 + minute * 60 + second)?;
+    let seconds = i64::try_from(seconds).ok()?.checked_sub(offset_seconds)?;
+    let seconds = u64::try_from(seconds).ok()?;
+    UNIX_EPOCH.checked_add(Duration::new(seconds, nanos as u32))
+}
+
+/// Converts a (year, month, day) civil date to days since 1970-01-01.
+///
+/// Returns `None` for dates before the epoch or too far ahead to count in a `u64`.
+fn days_from_civil(year: u64, month: u64, day: u64) -> Option<u64> {
+    let year = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era.checked_mul(146_097)?
+        .checked_add(day_of_era)?
+        .checked_sub(719_468)
+}
+
+/// Converts days since 1970-01-01 to a (year, month, day) civil date.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // Shift the epoch to 0000-03-01 so leap days fall at the end of each 400-year era
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = era * 400 + year_of_era + u64::from(month <= 2);
+    (year, month, day)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_utc_time() {
+        assert_eq!(format_utc_time(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        // GPS epoch
+        assert_eq!(
+            format_utc_time(UNIX_EPOCH + Duration::from_secs(315_964_800)),
+            "1980-01-06T00:00:00Z"
+        );
+        // Leap day
+        assert_eq!(
+            format_utc_time(UNIX_EPOCH + Duration::from_secs(951_782_400 + 3_723)),
+            "2000-02-29T01:02:03Z"
+        );
+        assert_eq!(
+            format_utc_time(UNIX_EPOCH + Duration::from_millis(1_735_689_599_999)),
+            "2024-12-31T23:59:59.999Z"
+        );
+    }
+
+    #[test]
+    fn test_parse_utc_time() {
+        for text in [
+            "1970-01-01T00:00:00Z",
+            "1980-01-06T00:00:00Z",
+            "2000-02-29T01:02:03Z",
+            "2024-12-31T23:59:59.999Z",
+        ] {
+            assert_eq!(
+                parse_utc_time(text).map(format_utc_time).as_deref(),
+                Some(text)
+            );
+        }
+        assert_eq!(
+            parse_utc_time("2024-06-01T12:00:00-0530"),
+            parse_utc_time("2024-06-01T17:30:00Z")
+        );
+        assert_eq!(
+            parse_utc_time("2024-06-01T12:00:00.5Z"),
+            parse_utc_time("2024-06-01T12:00:00.500000Z")
+        );
+
+        for text in [
+            "2024-06-01 12:00:00Z",
+            "2024-06-01T12:00:00",
+            "2024-13-01T12:00:00Z",
+            "2024-06-01T12:00:00.Z",
+            "1969-12-31T23:59:59Z",
+            "2024-06-01T+1:00:00Z",
+            // Out-of-range years
+            "999999999999999-01-01T00:00:00Z",
+            "18446744073709551615-12-31T23:59:59Z",
+        ] {
+            assert_eq!(parse_utc_time(text), None, "{text}");
+        }
+    }
+
     #[test]
     fn test_format_identifier_to_string() {
         // ASCII alphanumeric
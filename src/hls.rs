@@ -0,0 +1,562 @@
+//! Extraction of SCTE-35 cues from HLS media playlists.
+//!
+//! [`extract_cues`] finds the ad-marker tags in common use and places each on the
+//! playlist timeline:
+//!
+//! - `#EXT-X-DATERANGE` with `SCTE35-OUT`, `SCTE35-IN` or `SCTE35-CMD` attributes
+//!   (RFC 8216 section 4.3.2.7.1), one cue per attribute
+//! - `#EXT-X-CUE-OUT` and `#EXT-X-CUE-IN`
+//! - `#EXT-X-SCTE35` (`CUE`, `CUE-OUT` and `CUE-IN` attributes)
+//!
+//! Continuation tags (`#EXT-X-CUE-OUT-CONT`, `CUE-OUT=CONT`) repeat an earlier cue and are
+//! skipped.
+//!
+//! [`format_daterange`] goes the other way and writes a cue as an `#EXT-X-DATERANGE` tag.
+
+use crate::fmt::{format_utc_time, parse_utc_time};
+use crate::input::{InputError, InputFormat, decode_input};
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+/// The playlist tag a cue was found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CueTag {
+    /// `#EXT-X-DATERANGE`
+    DateRange,
+    /// `#EXT-X-CUE-OUT`
+    CueOut,
+    /// `#EXT-X-CUE-IN`
+    CueIn,
+    /// `#EXT-X-SCTE35`
+    Scte35,
+}
+
+impl fmt::Display for CueTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CueTag::DateRange => write!(f, "EXT-X-DATERANGE"),
+            CueTag::CueOut => write!(f, "EXT-X-CUE-OUT"),
+            CueTag::CueIn => write!(f, "EXT-X-CUE-IN"),
+            CueTag::Scte35 => write!(f, "EXT-X-SCTE35"),
+        }
+    }
+}
+
+/// Whether a cue starts a break, ends one, or is neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CueKind {
+    /// Leaving the network for a break (`SCTE35-OUT`, `CUE-OUT`)
+    Out,
+    /// Returning to the network (`SCTE35-IN`, `CUE-IN`)
+    In,
+    /// Any other signal (`SCTE35-CMD`, an `#EXT-X-SCTE35` without direction)
+    Command,
+}
+
+impl CueKind {
+    /// Returns the `#EXT-X-DATERANGE` attribute carrying cues of this kind, e.g. `"SCTE35-OUT"`.
+    pub fn daterange_attribute(self) -> &'static str {
+        match self {
+            CueKind::Out => "SCTE35-OUT",
+            CueKind::In => "SCTE35-IN",
+            CueKind::Command => "SCTE35-CMD",
+        }
+    }
+}
+
+/// An ad-marker tag found in a playlist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HlsCue {
+    /// 1-based line number of the tag
+    pub line: usize,
+    /// The tag the cue was found in
+    pub tag: CueTag,
+    /// Whether the cue starts or ends a break
+    pub kind: CueKind,
+    /// `ID` attribute of `#EXT-X-DATERANGE` and `#EXT-X-SCTE35` tags
+    pub id: Option<String>,
+    /// Media sequence number of the segment the tag precedes
+    pub segment: u64,
+    /// Start of that segment, measured from the first segment of the playlist
+    pub playlist_time: Duration,
+    /// `#EXT-X-PROGRAM-DATE-TIME` of that segment, extrapolated from an earlier segment
+    /// when it has none of its own
+    pub program_date_time: Option<SystemTime>,
+    /// `START-DATE` of an `#EXT-X-DATERANGE`
+    pub start_date: Option<SystemTime>,
+    /// Duration declared by the tag: `DURATION`, else `PLANNED-DURATION`, or the
+    /// `#EXT-X-CUE-OUT` value
+    pub duration: Option<Duration>,
+    /// Encoded splice_info_section carried by the tag, if any
+    pub payload: Option<String>,
+}
+
+impl HlsCue {
+    /// Decodes the payload into section bytes.
+    ///
+    /// Returns `None` for tags without a payload, such as a plain `#EXT-X-CUE-IN`.
+    pub fn decode(&self) -> Option<Result<Vec<u8>, InputError>> {
+        self.payload
+            .as_ref()
+            .map(|payload| decode_input(payload.as_bytes(), InputFormat::Auto))
+    }
+}
+
+/// Error returned when a playlist cannot be scanned for cues.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaylistError {
+    /// A description of why the playlist was rejected
+    pub reason: String,
+}
+
+impl PlaylistError {
+    fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for PlaylistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid playlist: {}", self.reason)
+    }
+}
+
+impl Error for PlaylistError {}
+
+/// Finds the SCTE-35 ad markers in an HLS media playlist.
+///
+/// Cues are returned in playlist order. Master playlists are rejected, since they list
+/// variant streams rather than segments.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::hls::{CueKind, CueTag, extract_cues};
+/// use std::time::Duration;
+///
+/// let playlist = "#EXTM3U
+/// #EXT-X-MEDIA-SEQUENCE:100
+/// #EXTINF:6.0,
+/// segment100.ts
+/// #EXT-X-CUE-OUT:30
+/// #EXTINF:6.0,
+/// segment101.ts
+/// ";
+///
+/// let cues = extract_cues(playlist)?;
+/// assert_eq!(cues[0].tag, CueTag::CueOut);
+/// assert_eq!(cues[0].kind, CueKind::Out);
+/// assert_eq!(cues[0].segment, 101);
+/// assert_eq!(cues[0].playlist_time, Duration::from_secs(6));
+/// assert_eq!(cues[0].duration, Some(Duration::from_secs(30)));
+/// # Ok::<(), scte35::hls::PlaylistError>(())
+/// ```
+pub fn extract_cues(playlist: &str) -> Result<Vec<HlsCue>, PlaylistError> {
+    let header = playlist.lines().next().map(str::trim);
+    if header.map(|line| line.trim_start_matches('\u{feff}')) != Some("#EXTM3U") {
+        return Err(PlaylistError::new("missing #EXTM3U header"));
+    }
+
+    let mut cues = Vec::new();
+    // Cues seen since the last segment; they take the timing of the next one
+    let mut pending: Vec<HlsCue> = Vec::new();
+    let mut segment = 0;
+    let mut playlist_time = Duration::ZERO;
+    let mut segment_duration = Duration::ZERO;
+    let mut segment_date: Option<SystemTime> = None;
+    // The most recent PROGRAM-DATE-TIME and the playlist time it applied to
+    let mut last_date: Option<(SystemTime, Duration)> = None;
+
+    for (index, line) in playlist.lines().map(str::trim).enumerate().skip(1) {
+        let line_number = index + 1;
+        if line.is_empty() {
+            continue;
+        }
+        if !line.starts_with('#') {
+            // A segment URI: settle the timing of the cues that precede it
+            let date = segment_date
+                .take()
+                .or_else(|| extrapolate(last_date, playlist_time));
+            if let Some(date) = date {
+                last_date = Some((date, playlist_time));
+            }
+            for mut cue in pending.drain(..) {
+                cue.program_date_time = date;
+                cues.push(cue);
+            }
+            segment += 1;
+            playlist_time = playlist_time.saturating_add(segment_duration);
+            segment_duration = Duration::ZERO;
+            continue;
+        }
+
+        let (name, value) = line[1..].split_once(':').unwrap_or((&line[1..], ""));
+        let cue = |tag, kind, attributes: &Attributes, duration, payload: Option<&str>| HlsCue {
+            line: line_number,
+            tag,
+            kind,
+            id: attributes.get("ID").map(str::to_string),
+            segment,
+            playlist_time,
+            program_date_time: None,
+            start_date: None,
+            duration,
+            payload: payload.map(str::to_string),
+        };
+
+        match name {
+            "EXT-X-STREAM-INF" | "EXT-X-I-FRAME-STREAM-INF" => {
+                return Err(PlaylistError::new(
+                    "this is a master playlist; pass one of its media playlists",
+                ));
+            }
+            "EXT-X-MEDIA-SEQUENCE" => {
+                segment = value.parse().map_err(|_| {
+                    PlaylistError::new(format!(
+                        "invalid media sequence '{value}' on line {line_number}"
+                    ))
+                })?;
+            }
+            "EXTINF" => {
+                let seconds = value.split(',').next().unwrap_or_default();
+                segment_duration = parse_seconds(seconds).ok_or_else(|| {
+                    PlaylistError::new(format!(
+                        "invalid EXTINF duration '{seconds}' on line {line_number}"
+                    ))
+                })?;
+            }
+            "EXT-X-PROGRAM-DATE-TIME" => segment_date = parse_utc_time(value),
+            "EXT-X-DATERANGE" => {
+                let attributes = Attributes::parse(value);
+                let duration = attributes
+                    .get("DURATION")
+                    .or_else(|| attributes.get("PLANNED-DURATION"))
+                    .and_then(parse_seconds);
+                for kind in [CueKind::Out, CueKind::In, CueKind::Command] {
+                    if let Some(payload) = attributes.get(kind.daterange_attribute()) {
+                        pending.push(HlsCue {
+                            start_date: attributes.get("START-DATE").and_then(parse_utc_time),
+                            ..cue(
+                                CueTag::DateRange,
+                                kind,
+                                &attributes,
+                                duration,
+                                Some(payload),
+                            )
+                        });
+                    }
+                }
+            }
+            "EXT-X-CUE-OUT" => {
+                // Either a bare duration or an attribute list with DURATION (and SCTE35)
+                let attributes = Attributes::parse(value);
+                let duration = parse_seconds(value)
+                    .or_else(|| attributes.get("DURATION").and_then(parse_seconds));
+                pending.push(cue(
+                    CueTag::CueOut,
+                    CueKind::Out,
+                    &attributes,
+                    duration,
+                    attributes.get("SCTE35"),
+                ));
+            }
+            "EXT-X-CUE-IN" => {
+                let attributes = Attributes::parse(value);
+                pending.push(cue(
+                    CueTag::CueIn,
+                    CueKind::In,
+                    &attributes,
+                    None,
+                    attributes.get("SCTE35"),
+                ));
+            }
+            "EXT-X-SCTE35" => {
+                let attributes = Attributes::parse(value);
+                let kind = match (attributes.get("CUE-OUT"), attributes.get("CUE-IN")) {
+                    (Some("CONT"), _) => continue,
+                    (Some("YES"), _) => CueKind::Out,
+                    (_, Some("YES")) => CueKind::In,
+                    _ => CueKind::Command,
+                };
+                let duration = attributes.get("DURATION").and_then(parse_seconds);
+                pending.push(cue(
+                    CueTag::Scte35,
+                    kind,
+                    &attributes,
+                    duration,
+                    attributes.get("CUE"),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    // Cues after the last segment apply to the next, not yet published, segment
+    let date = segment_date.or_else(|| extrapolate(last_date, playlist_time));
+    for mut cue in pending {
+        cue.program_date_time = date;
+        cues.push(cue);
+    }
+    Ok(cues)
+}
+
+/// Writes an `#EXT-X-DATERANGE` tag carrying a cue as a `SCTE35-OUT`, `SCTE35-IN` or
+/// `SCTE35-CMD` attribute, chosen by `kind`.
+///
+/// `planned_duration` is written as `PLANNED-DURATION` in seconds with millisecond
+/// precision, for breaks whose length is known in advance. The payload is written as uppercase hex with a `0x` prefix.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::hls::{CueKind, format_daterange};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let start = UNIX_EPOCH + Duration::from_secs(1_704_067_206);
+/// let tag = format_daterange(CueKind::Out, "splice-1", start, Some(Duration::from_secs(30)), &[0xFC, 0x30]);
+/// assert_eq!(
+///     tag,
+///     r#"#EXT-X-DATERANGE:ID="splice-1",START-DATE="2024-01-01T00:00:06Z",PLANNED-DURATION=30.000,SCTE35-OUT=0xFC30"#
+/// );
+/// ```
+pub fn format_daterange(
+    kind: CueKind,
+    id: &str,
+    start_date: SystemTime,
+    planned_duration: Option<Duration>,
+    payload: &[u8],
+) -> String {
+    let mut tag = format!(
+        "#EXT-X-DATERANGE:ID=\"{id}\",START-DATE=\"{}\"",
+        format_utc_time(start_date)
+    );
+    if let Some(duration) = planned_duration {
+        tag.push_str(&format!(",PLANNED-DURATION={:.3}", duration.as_secs_f64()));
+    }
+    let hex: String = payload.iter().map(|byte| format!("{byte:02X}")).collect();
+    tag.push_str(&format!(",{}=0x{hex}", kind.daterange_attribute()));
+    tag
+}
+
+/// Advances the most recent PROGRAM-DATE-TIME to `playlist_time`, or returns `None` if
+/// the result does not fit in a [`SystemTime`].
+fn extrapolate(
+    last_date: Option<(SystemTime, Duration)>,
+    playlist_time: Duration,
+) -> Option<SystemTime> {
+    let (date, at) = last_date?;
+    date.checked_add(playlist_time.saturating_sub(at))
+}
+
+fn parse_seconds(text: &str) -> Option<Duration> {
+    text.trim()
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+}
+
+/// An attribute list such as `ID="ad-1",DURATION=30.0,SCTE35-OUT=0xFC30...`.
+struct Attributes<'a>(Vec<(&'a str, &'a str)>);
+
+impl<'a> Attributes<'a> {
+    fn parse(text: &'a str) -> Self {
+        let mut attributes = Vec::new();
+        let mut rest = text;
+        while let Some((name, after)) = rest.split_once('=') {
+            let name = name.trim();
+            let (value, after) = if let Some(quoted) = after.strip_prefix('"') {
+                // Quoted strings may contain commas
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                let after = quoted.get(end + 1..).unwrap_or_default();
+                (
+                    &quoted[..end],
+                    after.split_once(',').map_or("", |(_, after)| after),
+                )
+            } else {
+                after.split_once(',').unwrap_or((after, ""))
+            };
+            attributes.push((name, value.trim()));
+            rest = after;
+        }
+        Attributes(attributes)
+    }
+
+    fn get(&self, name: &str) -> Option<&'a str> {
+        self.0
+            .iter()
+            .find(|(attribute, _)| attribute.eq_ignore_ascii_case(name))
+            .map(|(_, value)| *value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYLIST: &str = r#"#EXTM3U
+#EXT-X-VERSION:3
+#EXT-X-TARGETDURATION:6
+#EXT-X-MEDIA-SEQUENCE:10
+#EXT-X-PROGRAM-DATE-TIME:2024-01-01T00:00:00Z
+#EXTINF:6.0,
+seg10.ts
+#EXT-X-DATERANGE:ID="splice-1",START-DATE="2024-01-01T00:00:06Z",PLANNED-DURATION=30.0,SCTE35-OUT=0xFC301600000000000000FFF00506FE423A35BD0000BB0C73F4
+#EXT-X-CUE-OUT:DURATION=30
+#EXTINF:6.0,
+seg11.ts
+#EXT-X-CUE-OUT-CONT:ElapsedTime=6,Duration=30
+#EXTINF:6.0,
+seg12.ts
+#EXT-X-SCTE35:CUE="/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==",CUE-OUT=CONT
+#EXTINF:6.0,
+seg13.ts
+#EXT-X-CUE-IN
+#EXT-X-SCTE35:ID="in, 1",CUE="/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==",CUE-IN=YES
+#EXTINF:6.0,
+seg14.ts
+"#;
+
+    #[test]
+    fn test_extract_cues() {
+        let cues = extract_cues(PLAYLIST).unwrap();
+        let summary: Vec<_> = cues
+            .iter()
+            .map(|cue| (cue.line, cue.tag, cue.kind, cue.segment))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (8, CueTag::DateRange, CueKind::Out, 11),
+                (9, CueTag::CueOut, CueKind::Out, 11),
+                (18, CueTag::CueIn, CueKind::In, 14),
+                (19, CueTag::Scte35, CueKind::In, 14),
+            ]
+        );
+
+        let daterange = &cues[0];
+        assert_eq!(daterange.id.as_deref(), Some("splice-1"));
+        assert_eq!(daterange.duration, Some(Duration::from_secs(30)));
+        assert_eq!(
+            daterange.start_date.map(format_utc_time).as_deref(),
+            Some("2024-01-01T00:00:06Z")
+        );
+        assert_eq!(daterange.decode().unwrap().unwrap()[0], 0xFC);
+
+        // Extrapolated from the date of segment 10
+        let cue_in = &cues[2];
+        assert_eq!(cue_in.playlist_time, Duration::from_secs(24));
+        assert_eq!(
+            cue_in.program_date_time.map(format_utc_time).as_deref(),
+            Some("2024-01-01T00:00:24Z")
+        );
+        assert_eq!(cue_in.payload, None);
+        assert_eq!(cues[3].id.as_deref(), Some("in, 1"));
+    }
+
+    #[test]
+    fn test_rejects_non_media_playlists() {
+        assert_eq!(
+            extract_cues("seg1.ts\n").unwrap_err().reason,
+            "missing #EXTM3U header"
+        );
+        let master = "#EXTM3U\n#EXT-X-STREAM-INF:BANDWIDTH=1280000\nlow.m3u8\n";
+        assert!(
+            extract_cues(master)
+                .unwrap_err()
+                .reason
+                .contains("master playlist")
+        );
+    }
+
+    #[test]
+    fn test_program_date_time_extrapolation() {
+        let playlist = "#EXTM3U
+#EXT-X-CUE-OUT:30
+#EXTINF:4.0,
+seg0.ts
+#EXT-X-PROGRAM-DATE-TIME:2024-01-01T00:00:00Z
+#EXTINF:4.0,
+seg1.ts
+#EXTINF:4.0,
+seg2.ts
+#EXT-X-CUE-IN
+#EXT-X-DISCONTINUITY
+#EXT-X-PROGRAM-DATE-TIME:2024-06-01T12:00:00Z
+#EXTINF:4.0,
+seg3.ts
+#EXTINF:4.0,
+seg4.ts
+#EXT-X-CUE-OUT:30
+";
+        let dates: Vec<_> = extract_cues(playlist)
+            .unwrap()
+            .iter()
+            .map(|cue| cue.program_date_time.map(format_utc_time))
+            .collect();
+        assert_eq!(
+            dates,
+            vec![
+                // No date has been seen yet
+                None,
+                // The segment's own date wins over the extrapolated one
+                Some("2024-06-01T12:00:00Z".to_string()),
+                // After the last segment: extrapolated from the most recent date
+                Some("2024-06-01T12:00:08Z".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_dates() {
+        // An unparseable year leaves the cue undated rather than panicking
+        let playlist = "#EXTM3U
+#EXT-X-PROGRAM-DATE-TIME:999999999999999-01-01T00:00:00Z
+#EXT-X-CUE-OUT:30
+#EXTINF:6.0,
+seg0.ts
+";
+        let cues = extract_cues(playlist).unwrap();
+        assert_eq!(cues[0].program_date_time, None);
+
+        // Extrapolating past the end of SystemTime does the same
+        let playlist = "#EXTM3U
+#EXT-X-PROGRAM-DATE-TIME:2024-01-01T00:00:00Z
+#EXTINF:1e19,
+seg0.ts
+#EXT-X-CUE-IN
+";
+        let cues = extract_cues(playlist).unwrap();
+        assert_eq!(cues[0].program_date_time, None);
+    }
+
+    #[test]
+    fn test_rejects_master_playlist_after_other_tags() {
+        let master = "#EXTM3U
+#EXT-X-VERSION:6
+#EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID=\"aac\",NAME=\"en\",URI=\"en.m3u8\"
+#EXT-X-STREAM-INF:BANDWIDTH=1280000,AUDIO=\"aac\"
+low.m3u8
+";
+        let error = extract_cues(master).unwrap_err();
+        assert_eq!(
+            error.reason,
+            "this is a master playlist; pass one of its media playlists"
+        );
+
+        let iframes = "#EXTM3U\n#EXT-X-I-FRAME-STREAM-INF:BANDWIDTH=86000,URI=\"iframe.m3u8\"\n";
+        assert!(extract_cues(iframes).is_err());
+    }
+
+    #[test]
+    fn test_attributes() {
+        let attributes = Attributes::parse(r#"ID="a,b",DURATION=30.5, X-FOO="",CUE-OUT=YES"#);
+        assert_eq!(attributes.get("id"), Some("a,b"));
+        assert_eq!(attributes.get("DURATION"), Some("30.5"));
+        assert_eq!(attributes.get("X-FOO"), Some(""));
+        assert_eq!(attributes.get("CUE-OUT"), Some("YES"));
+        assert_eq!(attributes.get("MISSING"), None);
+    }
+}
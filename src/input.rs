@@ -0,0 +1,302 @@
+//! Decoding of SCTE-35 payloads from their common transport encodings.
+//!
+//! Cues reach tooling in many shapes: base64 from manifests and ad-server logs, hex from
+//! HLS `#EXT-X-DATERANGE` tags (`0xFC30...`), URL-safe base64 from query strings and raw
+//! binary section dumps. [`decode_input`] turns any of these into section bytes ready for
+//! [`parse_splice_info_section`](crate::parse_splice_info_section).
+
+use std::error::Error;
+use std::fmt;
+
+/// The encoding of an SCTE-35 payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum InputFormat {
+    /// Detect the encoding from the content
+    #[default]
+    Auto,
+    /// Hexadecimal digits, optionally prefixed with `0x`
+    Hex,
+    /// Standard base64 (RFC 4648 §4), padding optional
+    Base64,
+    /// URL-safe base64 (RFC 4648 §5), padding optional
+    #[cfg_attr(feature = "cli", value(name = "base64url"))]
+    Base64Url,
+    /// Raw section bytes
+    Binary,
+}
+
+impl fmt::Display for InputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputFormat::Auto => write!(f, "auto"),
+            InputFormat::Hex => write!(f, "hex"),
+            InputFormat::Base64 => write!(f, "base64"),
+            InputFormat::Base64Url => write!(f, "base64url"),
+            InputFormat::Binary => write!(f, "binary"),
+        }
+    }
+}
+
+/// Error returned when a payload cannot be decoded in the requested format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    /// The format the payload was decoded as
+    pub format: InputFormat,
+    /// A description of why decoding failed
+    pub reason: String,
+}
+
+impl InputError {
+    fn new(format: InputFormat, reason: impl Into<String>) -> Self {
+        Self {
+            format,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid {} input: {}", self.format, self.reason)
+    }
+}
+
+impl Error for InputError {}
+
+/// Guesses the encoding of a payload.
+///
+/// - Anything that is not printable ASCII text is [`InputFormat::Binary`]; a binary
+///   section starts with table_id `0xFC`, which is never valid text.
+/// - Text starting with `0x`, or made only of hex digits, is [`InputFormat::Hex`].
+/// - Any other text is base64. It is reported as [`InputFormat::Base64Url`] when it
+///   contains `-` or `_`, and [`InputFormat::Base64`] otherwise.
+///
+/// Whitespace is ignored for text payloads.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::input::{InputFormat, detect_format};
+///
+/// assert_eq!(detect_format(b"0xFC301100"), InputFormat::Hex);
+/// assert_eq!(detect_format(b"/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A=="), InputFormat::Base64);
+/// assert_eq!(detect_format(b"_DAWAAAAAAAAAP_wBQb-Qjo1vQAAuwxz9A"), InputFormat::Base64Url);
+/// assert_eq!(detect_format(&[0xFC, 0x30, 0x11]), InputFormat::Binary);
+/// ```
+pub fn detect_format(data: &[u8]) -> InputFormat {
+    if !is_text(data) {
+        return InputFormat::Binary;
+    }
+
+    let text = strip_whitespace(data);
+    if has_hex_prefix(&text) || (!text.is_empty() && text.iter().all(u8::is_ascii_hexdigit)) {
+        InputFormat::Hex
+    } else if text.iter().any(|&b| b == b'-' || b == b'_') {
+        InputFormat::Base64Url
+    } else {
+        InputFormat::Base64
+    }
+}
+
+/// Decodes a payload into raw section bytes.
+///
+/// With [`InputFormat::Auto`] the encoding is chosen by [`detect_format`]; auto-detected
+/// base64 accepts both the standard and URL-safe alphabets. Whitespace (including line
+/// breaks) is ignored in every text format. Binary input is returned unchanged.
+///
+/// Base64 decoding requires the `base64` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::input::{InputFormat, decode_input};
+///
+/// let from_hex = decode_input(b"0xFC301600000000000000FFF00506FE423A35BD0000BB0C73F4", InputFormat::Auto).unwrap();
+/// let wrapped = decode_input(b"FC301600000000000000FFF0\n0506FE423A35BD0000BB0C73F4\n", InputFormat::Hex).unwrap();
+/// assert_eq!(from_hex, wrapped);
+///
+/// let section = scte35::parse_splice_info_section(&from_hex).unwrap();
+/// assert_eq!(section.splice_command_type, 0x06);
+/// ```
+pub fn decode_input(data: &[u8], format: InputFormat) -> Result<Vec<u8>, InputError> {
+    match format {
+        InputFormat::Auto => match detect_format(data) {
+            InputFormat::Binary => Ok(data.to_vec()),
+            InputFormat::Hex => decode_hex(data),
+            _ => decode_base64(data, InputFormat::Base64, true),
+        },
+        InputFormat::Hex => decode_hex(data),
+        InputFormat::Base64 | InputFormat::Base64Url => decode_base64(data, format, false),
+        InputFormat::Binary => Ok(data.to_vec()),
+    }
+}
+
+fn is_text(data: &[u8]) -> bool {
+    data.iter()
+        .all(|&b| b.is_ascii_graphic() || b.is_ascii_whitespace())
+}
+
+fn strip_whitespace(data: &[u8]) -> Vec<u8> {
+    data.iter()
+        .copied()
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect()
+}
+
+fn has_hex_prefix(text: &[u8]) -> bool {
+    text.starts_with(b"0x") || text.starts_with(b"0X")
+}
+
+fn decode_hex(data: &[u8]) -> Result<Vec<u8>, InputError> {
+    let error = |reason: &str| InputError::new(InputFormat::Hex, reason);
+
+    let text = strip_whitespace(data);
+    let digits = if has_hex_prefix(&text) {
+        &text[2..]
+    } else {
+        &text[..]
+    };
+    if digits.is_empty() {
+        return Err(error("payload is empty"));
+    }
+    if digits.len() % 2 != 0 {
+        return Err(error("odd number of hex digits"));
+    }
+
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).map_err(|_| error("non-ASCII character"))?;
+            u8::from_str_radix(pair, 16).map_err(|_| error(&format!("invalid hex digits '{pair}'")))
+        })
+        .collect()
+}
+
+#[cfg(feature = "base64")]
+fn decode_base64(
+    data: &[u8],
+    format: InputFormat,
+    any_alphabet: bool,
+) -> Result<Vec<u8>, InputError> {
+    use data_encoding::{BASE64_NOPAD, BASE64URL_NOPAD};
+
+    let mut text = strip_whitespace(data);
+    while text.last() == Some(&b'=') {
+        text.pop();
+    }
+    if text.is_empty() {
+        return Err(InputError::new(format, "payload is empty"));
+    }
+
+    let encoding = if any_alphabet || format == InputFormat::Base64 {
+        if any_alphabet {
+            for byte in &mut text {
+                match byte {
+                    b'-' => *byte = b'+',
+                    b'_' => *byte = b'/',
+                    _ => {}
+                }
+            }
+        }
+        &BASE64_NOPAD
+    } else {
+        &BASE64URL_NOPAD
+    };
+
+    encoding
+        .decode(&text)
+        .map_err(|e| InputError::new(format, e.to_string()))
+}
+
+#[cfg(not(feature = "base64"))]
+fn decode_base64(
+    _data: &[u8],
+    format: InputFormat,
+    _any_alphabet: bool,
+) -> Result<Vec<u8>, InputError> {
+    Err(InputError::new(
+        format,
+        "base64 decoding requires the `base64` feature",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "FC301600000000000000FFF00506FE423A35BD0000BB0C73F4";
+    const BASE64: &str = "/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==";
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(detect_format(HEX.as_bytes()), InputFormat::Hex);
+        assert_eq!(detect_format(b"0xfc30"), InputFormat::Hex);
+        assert_eq!(detect_format(b" FC 30\n11 "), InputFormat::Hex);
+        assert_eq!(detect_format(BASE64.as_bytes()), InputFormat::Base64);
+        assert_eq!(detect_format(b"_DAW-AAA"), InputFormat::Base64Url);
+        assert_eq!(
+            detect_format(&[0xFC, 0x30, 0x16, 0x00]),
+            InputFormat::Binary
+        );
+        assert_eq!(detect_format(b"FC30\x00"), InputFormat::Binary);
+    }
+
+    #[test]
+    fn test_decode_hex() {
+        let expected = decode_input(HEX.as_bytes(), InputFormat::Hex).unwrap();
+        assert_eq!(expected[0], 0xFC);
+        assert_eq!(expected.len(), HEX.len() / 2);
+
+        let spaced = format!("0X{}\n{}\n", &HEX[..10], HEX[10..].to_lowercase());
+        assert_eq!(
+            decode_input(spaced.as_bytes(), InputFormat::Auto).unwrap(),
+            expected
+        );
+
+        let error = decode_input(b"FC3", InputFormat::Hex).unwrap_err();
+        assert_eq!(error.format, InputFormat::Hex);
+        assert!(decode_input(b"FC3G", InputFormat::Hex).is_err());
+        assert!(decode_input(b"0x", InputFormat::Hex).is_err());
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn test_decode_base64_variants() {
+        let expected = decode_input(HEX.as_bytes(), InputFormat::Hex).unwrap();
+
+        assert_eq!(
+            decode_input(BASE64.as_bytes(), InputFormat::Base64).unwrap(),
+            expected
+        );
+        let wrapped = format!("{}\n{}\n", &BASE64[..20], &BASE64[20..]);
+        assert_eq!(
+            decode_input(wrapped.as_bytes(), InputFormat::Auto).unwrap(),
+            expected
+        );
+
+        let url_safe = BASE64.replace('/', "_").replace('+', "-").replace('=', "");
+        assert_eq!(
+            decode_input(url_safe.as_bytes(), InputFormat::Base64Url).unwrap(),
+            expected
+        );
+        assert_eq!(
+            decode_input(url_safe.as_bytes(), InputFormat::Auto).unwrap(),
+            expected
+        );
+
+        // Explicit formats do not accept the other alphabet
+        assert!(decode_input(url_safe.as_bytes(), InputFormat::Base64).is_err());
+        assert!(decode_input(BASE64.as_bytes(), InputFormat::Base64Url).is_err());
+
+        let error = decode_input(b"invalid_base64!", InputFormat::Auto).unwrap_err();
+        assert_eq!(error.format, InputFormat::Base64);
+    }
+
+    #[test]
+    fn test_decode_binary() {
+        let binary = decode_input(HEX.as_bytes(), InputFormat::Hex).unwrap();
+        assert_eq!(decode_input(&binary, InputFormat::Auto).unwrap(), binary);
+        assert_eq!(decode_input(&binary, InputFormat::Binary).unwrap(), binary);
+    }
+}
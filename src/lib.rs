@@ -11,10 +11,34 @@ pub mod builders;
 pub mod descriptors;
 pub mod encoding;
 pub mod fmt;
+pub mod input;
 pub mod parser;
+pub mod registry;
 pub mod time;
+pub mod timeline;
 pub mod types;
 pub mod upid;
+pub mod validation;
+
+// Field-level comparison of messages - only included when feature is enabled
+#[cfg(feature = "diff")]
+pub mod diff;
+
+// HLS playlist cue extraction - only included when feature is enabled
+#[cfg(feature = "hls")]
+pub mod hls;
+
+// SCTE-35 XML reading and writing - only included when feature is enabled
+#[cfg(feature = "xml")]
+pub mod xml;
+
+// Conversion between cue carriage formats - only included when feature is enabled
+#[cfg(feature = "convert")]
+pub mod convert;
+
+// Transport stream scanning - only included when feature is enabled
+#[cfg(feature = "ts")]
+pub mod ts;
 
 // CRC validation module - only included when feature is enabled
 #[cfg(feature = "crc-validation")]
@@ -29,7 +53,7 @@ pub mod serde;
 pub use crc::{CrcValidatable, validate_message_crc};
 
 // Re-export main types and functions for ease of use
-pub use parser::parse_splice_info_section;
+pub use parser::{Sections, parse_sections, parse_splice_info_section, trace_splice_info_section};
 
 /// Convenient alias for [`parse_splice_info_section`].
 ///
@@ -44,6 +68,14 @@ pub use parser::parse_splice_info_section;
 /// * `Ok(SpliceInfoSection)` - Successfully parsed SCTE-35 message
 /// * `Err(io::Error)` - Parse error (malformed data, buffer underflow, etc.)
 ///
+/// # Global decoders
+///
+/// Private descriptors whose tag and identifier have a parser installed with
+/// [`registry::register_descriptor`] are returned
+/// as `SpliceDescriptor::Private` instead of `SpliceDescriptor::Unknown`. The registry
+/// is process-wide, so a registration anywhere in the process changes what this
+/// function returns; see [`registry`](crate::registry#global-state).
+///
 /// # Example
 ///
 /// ```rust
@@ -65,11 +97,15 @@ pub fn parse(buffer: &[u8]) -> Result<types::SpliceInfoSection, io::Error> {
 // Re-export main types
 pub use types::{
     BandwidthReservation, ComponentSplice, PrivateCommand, SegmentationType, SpliceCommand,
-    SpliceInfoSection, SpliceInsert, SpliceInsertComponent, SpliceNull, SpliceSchedule, TimeSignal,
+    SpliceInfoSection, SpliceInsert, SpliceInsertComponent, SpliceNull, SpliceSchedule,
+    SpliceScheduleEvent, TimeSignal,
 };
 
 // Re-export time types
-pub use time::{BreakDuration, SpliceTime};
+pub use time::{BreakDuration, EffectiveSplicePts, Pts, SpliceTime};
+
+// Re-export wall-clock mapping
+pub use timeline::Timeline;
 
 // Re-export UPID types
 pub use upid::SegmentationUpidType;
@@ -1,30 +1,87 @@
-use clap::{Parser, ValueEnum};
-use data_encoding::BASE64;
+use clap::{Parser, Subcommand, ValueEnum};
+use scte35::fmt::{format_identifier_to_string, format_private_data, format_utc_time};
+use scte35::input::decode_input;
+use scte35::time::{LeapSeconds, Pts};
 use scte35::{
     SpliceCommand, SpliceDescriptor, SpliceInfoSection, parse_splice_info_section,
     validate_scte35_crc,
 };
+use std::io;
 use std::process;
 
+mod cli;
+
+use cli::batch::{self, BatchFormat, Column};
+use cli::convert::{self, ConvertArgs};
+use cli::diff::{self, DiffArgs};
+use cli::encode::{self, EncodeArgs};
+use cli::explain::{self, ExplainArgs};
+use cli::hls::{self, HlsArgs};
+use cli::summary::{self, SummaryOptions};
+use cli::ts::{self, TsArgs};
+use cli::validate::{self, ValidateArgs};
+use cli::{ColorChoice, InputArgs};
+
 #[derive(Debug, Clone, ValueEnum, Default)]
 enum OutputFormat {
     #[default]
     Text,
     Json,
+    /// One JSON object per input line
+    Ndjson,
+    /// One CSV row per input line
+    Csv,
 }
 
 #[derive(Parser)]
 #[command(name = "scte35")]
-#[command(about = "Parse SCTE-35 messages from base64-encoded payloads")]
+#[command(about = "Parse SCTE-35 messages from base64, hex or binary payloads")]
 #[command(version)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Arguments {
-    /// Base64-encoded SCTE-35 payload
-    #[arg(value_name = "PAYLOAD")]
-    payload: String,
+    #[command(subcommand)]
+    command: Option<Command>,
 
-    /// Output format
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Output format; ndjson and csv process one payload per input line
     #[arg(short = 'o', long = "output", value_enum, default_value_t = OutputFormat::Text)]
     output: OutputFormat,
+
+    /// Columns to include in CSV output
+    #[arg(long = "columns", value_enum, value_delimiter = ',', default_values_t = batch::DEFAULT_COLUMNS.to_vec())]
+    columns: Vec<Column>,
+
+    /// Print one human-readable line per cue instead of every field
+    #[arg(short = 's', long = "summary", conflicts_with = "output")]
+    summary: bool,
+
+    /// When to color summary output
+    #[arg(long = "color", value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Add detail lines to summary output; repeat for more
+    #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count, requires = "summary")]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert cues between base64, hex, JSON, SCTE-35 XML, HLS tags and DASH events
+    Convert(ConvertArgs),
+    /// Compare two messages field by field; exits with 1 if they differ
+    Diff(DiffArgs),
+    /// Build a message from JSON (as printed by `-o json`) or from flags
+    Encode(EncodeArgs),
+    /// Print every field with its bit offset, width, raw bits and value
+    Explain(ExplainArgs),
+    /// Show a timeline of the ad markers in an HLS media playlist
+    Hls(HlsArgs),
+    /// List the SCTE-35 cues in a transport stream file, with stream timing and errors
+    Ts(TsArgs),
+    /// Check CRCs and conformance rules; exits with 1 on findings at the --fail-on level
+    Validate(ValidateArgs),
 }
 
 fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
@@ -32,7 +89,11 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
     println!("  Table ID: {}", section.table_id);
     println!("  Section Length: {}", section.section_length);
     println!("  Protocol Version: {}", section.protocol_version);
-    println!("  Splice Command Type: {}", section.splice_command_type);
+    println!(
+        "  Splice Command Type: 0x{:02x} ({})",
+        section.splice_command_type,
+        section.splice_command.name()
+    );
     println!("  Splice Command Length: {}", section.splice_command_length);
 
     match &section.splice_command {
@@ -41,13 +102,49 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
         }
         SpliceCommand::SpliceSchedule(cmd) => {
             println!("  Splice Command: SpliceSchedule");
-            println!("    Splice Event ID: {}", cmd.splice_event_id);
-            println!("    Duration Flag: {}", cmd.duration_flag);
-            if let Some(duration) = cmd.splice_duration {
-                println!("    Splice Duration: {duration}");
-            }
-            if let Some(time) = cmd.utc_splice_time {
-                println!("    UTC Splice Time: {time} (seconds since epoch)");
+            println!("    Splice Count: {}", cmd.splice_count);
+            let leap_seconds = LeapSeconds::default();
+            for event in &cmd.events {
+                println!("    Splice Event ID: 0x{:08x}", event.splice_event_id);
+                println!(
+                    "      Splice Event Cancel: {}",
+                    event.splice_event_cancel_indicator
+                );
+                if event.splice_event_cancel_indicator == 1 {
+                    continue;
+                }
+                println!("      Out of Network: {}", event.out_of_network_indicator);
+                println!("      Program Splice Flag: {}", event.program_splice_flag);
+                if let (Some(time), Some(utc)) = (
+                    event.utc_splice_time,
+                    event.splice_system_time(&leap_seconds),
+                ) {
+                    println!(
+                        "      UTC Splice Time: {} (GPS seconds: {time})",
+                        format_utc_time(utc)
+                    );
+                }
+                for component in &event.components {
+                    println!("      Component {}:", component.component_tag);
+                    println!(
+                        "        UTC Splice Time: {} (GPS seconds: {})",
+                        format_utc_time(component.splice_system_time(&leap_seconds)),
+                        component.utc_splice_time
+                    );
+                }
+                if let Some(break_duration) = &event.break_duration {
+                    println!("      Break Duration:");
+                    println!("        Auto Return: {}", break_duration.auto_return);
+                    let duration = break_duration.to_duration();
+                    println!(
+                        "        Duration: 0x{:09x} ({:.6} seconds)",
+                        break_duration.duration,
+                        duration.as_secs_f64()
+                    );
+                }
+                println!("      Unique Program ID: {}", event.unique_program_id);
+                println!("      Avail Num: {}", event.avail_num);
+                println!("      Avails Expected: {}", event.avails_expected);
             }
         }
         SpliceCommand::SpliceInsert(cmd) => {
@@ -62,12 +159,12 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
             println!("    Duration Flag: {}", cmd.duration_flag);
             println!("    Splice Immediate Flag: {}", cmd.splice_immediate_flag);
 
-            if let Some(splice_time) = &cmd.splice_time {
-                if let Some(pts) = splice_time.pts_time {
-                    println!("    Splice Time PTS: 0x{pts:09x}");
-                    if let Some(duration) = splice_time.to_duration() {
-                        println!("    Splice Time: {:.6} seconds", duration.as_secs_f64());
-                    }
+            if let Some(splice_time) = &cmd.splice_time
+                && let Some(pts) = splice_time.pts_time
+            {
+                println!("    Splice Time PTS: 0x{pts:09x}");
+                if let Some(duration) = splice_time.to_duration() {
+                    println!("    Splice Time: {:.6} seconds", duration.as_secs_f64());
                 }
             }
 
@@ -89,7 +186,7 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
         SpliceCommand::TimeSignal(cmd) => {
             println!("  Splice Command: TimeSignal");
             if let Some(pts) = cmd.splice_time.pts_time {
-                println!("    PTS Time: {pts}");
+                println!("    PTS Time: {}", Pts::new(pts));
             }
         }
         SpliceCommand::BandwidthReservation(cmd) => {
@@ -100,6 +197,13 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
             println!("  Splice Command: PrivateCommand");
             println!("    Private Command ID: {}", cmd.private_command_id);
             println!("    Private Command Length: {}", cmd.private_command_length);
+            match cmd.payload() {
+                Some(payload) => println!("    Payload: {payload}"),
+                None => println!(
+                    "    Private Data: {}",
+                    format_private_data(&cmd.private_bytes)
+                ),
+            }
         }
         SpliceCommand::Unknown => {
             println!("  Splice Command: Unknown");
@@ -146,7 +250,19 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
                     seg_desc.segmentation_upid_length
                 );
 
-                if let Some(upid_str) = seg_desc.upid_as_string() {
+                if let Some(mid_upids) = seg_desc.mid_upids() {
+                    println!("      UPID: MID ({} members)", mid_upids.len());
+                    for (index, member) in mid_upids.iter().enumerate() {
+                        println!(
+                            "        [{index}] {} (0x{:02x}): {}",
+                            member.upid_type,
+                            u8::from(member.upid_type),
+                            member
+                                .upid_as_string()
+                                .unwrap_or_else(|| "(empty)".to_string())
+                        );
+                    }
+                } else if let Some(upid_str) = seg_desc.upid_as_string() {
                     println!("      UPID: {upid_str}");
                 } else if !seg_desc.segmentation_upid.is_empty() {
                     // Show base64 for binary data when base64 is available
@@ -154,7 +270,7 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
                     {
                         println!(
                             "      UPID (base64): {}",
-                            BASE64.encode(&seg_desc.segmentation_upid)
+                            data_encoding::BASE64.encode(&seg_desc.segmentation_upid)
                         );
                     }
                     #[cfg(not(feature = "base64"))]
@@ -172,8 +288,8 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
                 }
 
                 println!(
-                    "      Segmentation Type ID: 0x{:02x}",
-                    seg_desc.segmentation_type_id
+                    "      Segmentation Type ID: 0x{:02x} ({})",
+                    seg_desc.segmentation_type_id, seg_desc.segmentation_type
                 );
                 println!("      Segment Number: {}", seg_desc.segment_num);
                 println!("      Segments Expected: {}", seg_desc.segments_expected);
@@ -220,6 +336,16 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
                     audio_desc.audio_components.len()
                 );
             }
+            SpliceDescriptor::Private(private_desc) => {
+                println!("    Private Descriptor:");
+                println!("      Tag: 0x{:02x}", private_desc.tag());
+                println!(
+                    "      Identifier: 0x{:08x} ({})",
+                    private_desc.identifier(),
+                    format_identifier_to_string(private_desc.identifier())
+                );
+                println!("      Value: {private_desc}");
+            }
             SpliceDescriptor::Unknown { tag, length, data } => {
                 println!("    Unknown Descriptor:");
                 println!("      Tag: 0x{tag:02x}");
@@ -249,21 +375,10 @@ fn print_text_output(section: &SpliceInfoSection, buffer: &[u8]) {
 fn print_json_output(section: &SpliceInfoSection, buffer: &[u8]) {
     use serde_json::json;
 
-    let crc_validation = match validate_scte35_crc(buffer) {
-        Ok(valid) => json!({
-            "valid": valid,
-            "error": null
-        }),
-        Err(e) => json!({
-            "valid": false,
-            "error": e.to_string()
-        }),
-    };
-
     let output = json!({
         "status": "success",
         "data": section,
-        "crc_validation": crc_validation
+        "crc_validation": cli::crc_validation_json(buffer)
     });
 
     match serde_json::to_string_pretty(&output) {
@@ -275,61 +390,118 @@ fn print_json_output(section: &SpliceInfoSection, buffer: &[u8]) {
     }
 }
 
+fn exit_with_error(output: &OutputFormat, message: String) -> ! {
+    match output {
+        OutputFormat::Text | OutputFormat::Csv => eprintln!("{message}"),
+        OutputFormat::Ndjson => println!(
+            "{}",
+            serde_json::json!({ "status": "error", "error": message })
+        ),
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "status": "error",
+                "error": message
+            });
+            match serde_json::to_string_pretty(&output) {
+                Ok(json_str) => println!("{json_str}"),
+                Err(json_err) => eprintln!("Error serializing error to JSON: {json_err}"),
+            }
+        }
+    }
+    process::exit(1);
+}
+
 fn main() {
     let args = Arguments::parse();
 
-    let base64_payload = &args.payload;
+    if let Some(command) = &args.command {
+        let result = match command {
+            Command::Convert(convert_args) => convert::run(convert_args),
+            Command::Diff(diff_args) => match diff::run(diff_args) {
+                Ok(identical) => process::exit(if identical { 0 } else { 1 }),
+                Err(message) => {
+                    eprintln!("{message}");
+                    process::exit(2);
+                }
+            },
+            Command::Encode(encode_args) => encode::run(encode_args),
+            Command::Explain(explain_args) => explain::run(explain_args),
+            Command::Hls(hls_args) => hls::run(hls_args),
+            Command::Ts(ts_args) => ts::run(ts_args),
+            Command::Validate(validate_args) => match validate::run(validate_args) {
+                Ok(passed) => process::exit(if passed { 0 } else { 1 }),
+                Err(message) => {
+                    eprintln!("{message}");
+                    process::exit(2);
+                }
+            },
+        };
+        if let Err(message) = result {
+            eprintln!("{message}");
+            process::exit(1);
+        }
+        return;
+    }
+
+    let input = match args.input.read_raw() {
+        Ok(input) => input,
+        Err(e) => exit_with_error(&args.output, format!("Error reading input: {e}")),
+    };
+
+    if args.summary {
+        let options = SummaryOptions {
+            color: args.color.enabled(),
+            verbosity: args.verbose,
+        };
+        match summary::run(
+            &input,
+            args.input.input_format,
+            &options,
+            &mut io::stdout().lock(),
+        ) {
+            Ok(0) => return,
+            Ok(_) => process::exit(1),
+            Err(e) => exit_with_error(&args.output, format!("Error writing output: {e}")),
+        }
+    }
 
-    let buffer = match BASE64.decode(base64_payload.as_bytes()) {
+    let batch_format = match args.output {
+        OutputFormat::Ndjson => Some(BatchFormat::Ndjson),
+        OutputFormat::Csv => Some(BatchFormat::Csv(&args.columns)),
+        OutputFormat::Text | OutputFormat::Json => None,
+    };
+    if let Some(format) = batch_format {
+        match batch::run(
+            &input,
+            args.input.input_format,
+            format,
+            &mut io::stdout().lock(),
+        ) {
+            Ok(0) => return,
+            Ok(_) => process::exit(1),
+            Err(e) => exit_with_error(&args.output, format!("Error writing output: {e}")),
+        }
+    }
+
+    let buffer = match decode_input(&input, args.input.input_format) {
         Ok(data) => data,
-        Err(e) => match args.output {
-            OutputFormat::Text => {
-                eprintln!("Error decoding base64 string: {e}");
-                process::exit(1);
-            }
-            OutputFormat::Json => {
-                use serde_json::json;
-                let output = json!({
-                    "status": "error",
-                    "error": format!("Error decoding base64 string: {e}")
-                });
-                match serde_json::to_string_pretty(&output) {
-                    Ok(json_str) => println!("{json_str}"),
-                    Err(json_err) => {
-                        eprintln!("Error serializing error to JSON: {json_err}");
-                        process::exit(1);
-                    }
-                }
-                process::exit(1);
-            }
-        },
+        Err(e) => exit_with_error(
+            &args.output,
+            format!("Error decoding {} string: {}", e.format, e.reason),
+        ),
     };
 
     match parse_splice_info_section(&buffer) {
         Ok(section) => match args.output {
-            OutputFormat::Text => print_text_output(&section, &buffer),
             OutputFormat::Json => print_json_output(&section, &buffer),
+            _ => print_text_output(&section, &buffer),
         },
         Err(e) => match args.output {
-            OutputFormat::Text => {
-                eprintln!("Error parsing SpliceInfoSection: {e}");
-                process::exit(1);
-            }
-            OutputFormat::Json => {
-                use serde_json::json;
-                let output = json!({
-                    "status": "error",
-                    "error": e.to_string()
-                });
-                match serde_json::to_string_pretty(&output) {
-                    Ok(json_str) => println!("{json_str}"),
-                    Err(json_err) => {
-                        eprintln!("Error serializing error to JSON: {json_err}");
-                        process::exit(1);
-                    }
-                }
-                process::exit(1);
-            }
+            OutputFormat::Json => exit_with_error(&args.output, e.to_string()),
+            _ => exit_with_error(
+                &args.output,
+                format!("Error parsing SpliceInfoSection: {e}"),
+            ),
         },
     }
 }
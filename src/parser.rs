@@ -5,7 +5,7 @@
 
 use crate::bit_reader::BitReader;
 use crate::commands::parse_splice_command;
-use crate::descriptors::{SegmentationDescriptor, SpliceDescriptor};
+use crate::descriptors::{SegmentationDescriptor, SpliceDescriptor, TimeDescriptor};
 use crate::types::{SegmentationType, SpliceInfoSection};
 use crate::upid::SegmentationUpidType;
 use std::io::{self, ErrorKind};
@@ -34,6 +34,14 @@ use std::io::{self, ErrorKind};
 /// - `0x07` - Bandwidth Reservation
 /// - `0xFF` - Private Command
 ///
+/// # Global decoders
+///
+/// Private descriptors whose tag and identifier have a parser installed with
+/// [`registry::register_descriptor`](crate::registry::register_descriptor) are returned
+/// as `SpliceDescriptor::Private` instead of `SpliceDescriptor::Unknown`. The registry
+/// is process-wide, so a registration anywhere in the process changes what this
+/// function returns; see [`registry`](crate::registry#global-state).
+///
 /// # Example
 ///
 /// ```rust
@@ -52,25 +60,111 @@ use std::io::{self, ErrorKind};
 /// }
 /// ```
 pub fn parse_splice_info_section(buffer: &[u8]) -> Result<SpliceInfoSection, io::Error> {
-    let mut reader = BitReader::new(buffer);
-
-    let table_id = reader.read_uimsbf(8)? as u8;
-    let section_syntax_indicator = reader.read_bslbf(1)? as u8;
-    let private_indicator = reader.read_bslbf(1)? as u8;
-    let sap_type = reader.read_bslbf(2)? as u8;
-    let section_length = reader.read_uimsbf(12)? as u16;
-    let protocol_version = reader.read_uimsbf(8)? as u8;
-    let encrypted_packet = reader.read_bslbf(1)? as u8;
-    let encryption_algorithm = reader.read_bslbf(6)? as u8;
-    let pts_adjustment = reader.read_uimsbf(33)?;
-    let cw_index = reader.read_uimsbf(8)? as u8;
-    let tier = reader.read_bslbf(12)? as u16;
-    let splice_command_length = reader.read_uimsbf(12)? as u16;
-    let splice_command_type = reader.read_uimsbf(8)? as u8;
+    parse_section(&mut BitReader::new(buffer), buffer)
+}
+
+/// A field read by the parser, as recorded by [`trace_splice_info_section`].
+///
+/// Fields are named after the syntax tables of the SCTE-35 specification (for example
+/// `splice_event_id` or `reserved`). Structures such as `splice_insert()` or
+/// `segmentation_descriptor()` appear as groups spanning the fields they contain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedField {
+    /// Field or group name from the specification
+    pub name: &'static str,
+    /// Nesting level; fields inside a group are one level deeper than the group
+    pub depth: usize,
+    /// Offset of the first bit from the start of the buffer
+    pub bit_offset: usize,
+    /// Width of the field in bits
+    pub bit_width: usize,
+    /// Value of fields up to 64 bits wide; `None` for groups and byte strings
+    pub value: Option<u64>,
+    /// Whether this entry is a group rather than a single field
+    pub group: bool,
+}
+
+impl TracedField {
+    /// Returns the field's bits from `buffer` as a string of `0`s and `1`s.
+    ///
+    /// `buffer` must be the buffer that was traced.
+    pub fn raw_bits(&self, buffer: &[u8]) -> String {
+        (self.bit_offset..self.bit_offset + self.bit_width)
+            .map(|bit| match buffer.get(bit / 8) {
+                Some(byte) if byte & (0x80 >> (bit % 8)) != 0 => '1',
+                _ => '0',
+            })
+            .collect()
+    }
+
+    /// Returns the bytes covered by the field, for byte-aligned fields.
+    pub fn bytes<'a>(&self, buffer: &'a [u8]) -> Option<&'a [u8]> {
+        if !self.bit_offset.is_multiple_of(8) || !self.bit_width.is_multiple_of(8) {
+            return None;
+        }
+        buffer.get(self.bit_offset / 8..(self.bit_offset + self.bit_width) / 8)
+    }
+}
+
+/// The result of [`trace_splice_info_section`].
+#[derive(Debug)]
+pub struct SectionTrace {
+    /// Every field read, in bit order
+    pub fields: Vec<TracedField>,
+    /// The parse result; the trace is kept even when parsing fails
+    pub result: Result<SpliceInfoSection, io::Error>,
+}
+
+/// Parses a splice information section while recording every field read.
+///
+/// The trace covers the fields consumed up to the point of failure, so it is useful for
+/// locating problems in malformed messages as well as for annotated dumps.
+///
+/// # Example
+///
+/// ```rust
+/// use data_encoding::BASE64;
+/// use scte35::parser::trace_splice_info_section;
+///
+/// let buffer = BASE64.decode(b"/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==").unwrap();
+/// let trace = trace_splice_info_section(&buffer);
+/// assert!(trace.result.is_ok());
+///
+/// let pts = trace.fields.iter().find(|field| field.name == "pts_time").unwrap();
+/// assert_eq!(pts.bit_offset, 14 * 8 + 7);
+/// assert_eq!(pts.bit_width, 33);
+/// assert_eq!(pts.value, Some(0x0_423A_35BD));
+/// ```
+pub fn trace_splice_info_section(buffer: &[u8]) -> SectionTrace {
+    let mut reader = BitReader::with_trace(buffer);
+    let result = parse_section(&mut reader, buffer);
+    SectionTrace {
+        fields: reader.into_trace(),
+        result,
+    }
+}
+
+fn parse_section(
+    reader: &mut BitReader,
+    #[cfg_attr(not(feature = "crc-validation"), allow(unused_variables))] buffer: &[u8],
+) -> Result<SpliceInfoSection, io::Error> {
+    reader.begin("splice_info_section()");
+    let table_id = reader.read_uimsbf("table_id", 8)? as u8;
+    let section_syntax_indicator = reader.read_bslbf("section_syntax_indicator", 1)? as u8;
+    let private_indicator = reader.read_bslbf("private_indicator", 1)? as u8;
+    let sap_type = reader.read_bslbf("sap_type", 2)? as u8;
+    let section_length = reader.read_uimsbf("section_length", 12)? as u16;
+    let protocol_version = reader.read_uimsbf("protocol_version", 8)? as u8;
+    let encrypted_packet = reader.read_bslbf("encrypted_packet", 1)? as u8;
+    let encryption_algorithm = reader.read_bslbf("encryption_algorithm", 6)? as u8;
+    let pts_adjustment = reader.read_uimsbf("pts_adjustment", 33)?;
+    let cw_index = reader.read_uimsbf("cw_index", 8)? as u8;
+    let tier = reader.read_bslbf("tier", 12)? as u16;
+    let splice_command_length = reader.read_uimsbf("splice_command_length", 12)? as u16;
+    let splice_command_type = reader.read_uimsbf("splice_command_type", 8)? as u8;
 
     let command_start_offset = reader.get_offset();
-    let splice_command =
-        parse_splice_command(&mut reader, splice_command_type, splice_command_length)?;
+    let splice_command = parse_splice_command(reader, splice_command_type, splice_command_length)?;
     let command_end_offset = reader.get_offset();
     let command_bits_read = command_end_offset - command_start_offset;
     let command_expected_bits = splice_command_length as usize * 8;
@@ -78,15 +172,15 @@ pub fn parse_splice_info_section(buffer: &[u8]) -> Result<SpliceInfoSection, io:
         eprintln!(
             "Warning: Splice command length mismatch. Expected {command_expected_bits} bits, read {command_bits_read} bits."
         );
-        reader.skip_bits(command_expected_bits - command_bits_read)?;
+        reader.skip_bits("command_bytes", command_expected_bits - command_bits_read)?;
     }
 
-    let descriptor_loop_length = reader.read_uimsbf(16)? as u16;
+    let descriptor_loop_length = reader.read_uimsbf("descriptor_loop_length", 16)? as u16;
     let mut splice_descriptors = Vec::new();
     let descriptor_start_offset = reader.get_offset();
     let mut descriptor_bits_read = 0;
     while descriptor_bits_read < descriptor_loop_length as usize * 8 {
-        splice_descriptors.push(parse_splice_descriptor(&mut reader)?);
+        splice_descriptors.push(parse_splice_descriptor(reader)?);
         descriptor_bits_read = reader.get_offset() - descriptor_start_offset;
     }
     if descriptor_bits_read > descriptor_loop_length as usize * 8 {
@@ -95,7 +189,10 @@ pub fn parse_splice_info_section(buffer: &[u8]) -> Result<SpliceInfoSection, io:
             descriptor_loop_length as usize * 8,
             descriptor_bits_read
         );
-        reader.skip_bits(descriptor_loop_length as usize * 8 - descriptor_bits_read)?;
+        reader.skip_bits(
+            "descriptor_bytes",
+            descriptor_loop_length as usize * 8 - descriptor_bits_read,
+        )?;
     }
 
     // Calculate remaining bits for stuffing
@@ -111,7 +208,7 @@ pub fn parse_splice_info_section(buffer: &[u8]) -> Result<SpliceInfoSection, io:
         let remaining_bits = expected_content_end - current_offset;
         let mut stuffing = Vec::new();
         for _ in 0..remaining_bits {
-            stuffing.push(reader.read_bslbf(1)? as u8);
+            stuffing.push(reader.read_bslbf("alignment_stuffing", 1)? as u8);
         }
         stuffing
     } else {
@@ -119,16 +216,19 @@ pub fn parse_splice_info_section(buffer: &[u8]) -> Result<SpliceInfoSection, io:
     };
 
     let e_crc_32 = if encrypted_packet == 1 {
-        Some(reader.read_rpchof(32)? as u32)
+        Some(reader.read_rpchof("E_CRC_32", 32)? as u32)
     } else {
         None
     };
-    let crc_32 = reader.read_rpchof(32)? as u32;
+    let crc_32 = reader.read_rpchof("CRC_32", 32)? as u32;
+    reader.end();
 
-    // Validate CRC if feature is enabled - much cleaner!
+    // Validate CRC if feature is enabled. The CRC covers the section only (section_length + 3
+    // bytes), so trailing data after the section does not affect it.
     #[cfg(feature = "crc-validation")]
     {
-        if !crate::crc::validate_crc(&buffer[0..buffer.len() - 4], crc_32) {
+        let section_end = reader.get_offset() / 8;
+        if !crate::crc::validate_crc(&buffer[0..section_end - 4], crc_32) {
             return Err(io::Error::new(
                 ErrorKind::InvalidData,
                 format!("CRC validation failed. Expected: 0x{crc_32:08X}"),
@@ -159,12 +259,102 @@ pub fn parse_splice_info_section(buffer: &[u8]) -> Result<SpliceInfoSection, io:
     })
 }
 
+/// Parses every splice information section in a buffer of concatenated sections.
+///
+/// Sections are split using their `section_length` field, and `0xFF` stuffing bytes
+/// between (or after) sections are skipped. Each item is the result of parsing one
+/// section; a section that fails to parse does not stop the iteration, but a section
+/// that runs past the end of the buffer ends it.
+///
+/// # Example
+///
+/// ```rust
+/// use data_encoding::BASE64;
+/// use scte35::parse_sections;
+///
+/// let section = BASE64.decode(b"/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==").unwrap();
+/// let mut buffer = section.clone();
+/// buffer.extend_from_slice(&[0xFF, 0xFF]); // stuffing
+/// buffer.extend_from_slice(&section);
+///
+/// let sections: Vec<_> = parse_sections(&buffer).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(sections.len(), 2);
+/// ```
+pub fn parse_sections(buffer: &[u8]) -> Sections<'_> {
+    Sections {
+        remaining: buffer,
+        offset: 0,
+    }
+}
+
+/// Iterator over the sections of a multi-section buffer, created by [`parse_sections`].
+#[derive(Debug, Clone)]
+pub struct Sections<'a> {
+    remaining: &'a [u8],
+    offset: usize,
+}
+
+impl Sections<'_> {
+    /// Returns the byte offset in the original buffer of the next section to parse.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Iterator for Sections<'_> {
+    type Item = Result<SpliceInfoSection, io::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip stuffing between sections
+        let stuffing = self
+            .remaining
+            .iter()
+            .take_while(|&&byte| byte == 0xFF)
+            .count();
+        self.remaining = &self.remaining[stuffing..];
+        self.offset += stuffing;
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let section_end = match self.remaining {
+            [_, high, low, ..] => 3 + ((((*high & 0x0F) as usize) << 8) | *low as usize),
+            _ => 3,
+        };
+        if self.remaining.len() < section_end {
+            let error = io::Error::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "Section at offset {} needs {} bytes, only {} available",
+                    self.offset,
+                    section_end,
+                    self.remaining.len()
+                ),
+            );
+            self.remaining = &[];
+            return Some(Err(error));
+        }
+
+        let (section, rest) = self.remaining.split_at(section_end);
+        self.remaining = rest;
+        self.offset += section_end;
+        Some(parse_splice_info_section(section))
+    }
+}
+
 /// Parses a splice descriptor from the bit stream.
 pub(crate) fn parse_splice_descriptor(
     reader: &mut BitReader,
 ) -> Result<SpliceDescriptor, io::Error> {
-    let descriptor_tag = reader.read_uimsbf(8)? as u8;
-    let descriptor_length = reader.read_uimsbf(8)? as u8;
+    reader.begin("splice_descriptor()");
+    let descriptor = parse_splice_descriptor_fields(reader);
+    reader.end();
+    descriptor
+}
+
+fn parse_splice_descriptor_fields(reader: &mut BitReader) -> Result<SpliceDescriptor, io::Error> {
+    let descriptor_tag = reader.read_uimsbf("descriptor_tag", 8)? as u8;
+    let descriptor_length = reader.read_uimsbf("descriptor_length", 8)? as u8;
 
     match descriptor_tag {
         0x02 => {
@@ -175,8 +365,23 @@ pub(crate) fn parse_splice_descriptor(
         _ => {
             // Unknown descriptor - store raw bytes
             let mut descriptor_bytes = Vec::new();
-            for _ in 0..descriptor_length {
-                descriptor_bytes.push(reader.read_uimsbf(8)? as u8);
+            if descriptor_length >= 4 {
+                let identifier = reader.read_uimsbf("identifier", 32)? as u32;
+                descriptor_bytes.extend_from_slice(&identifier.to_be_bytes());
+            }
+            let remaining = descriptor_length as usize - descriptor_bytes.len();
+            descriptor_bytes.extend(reader.read_bytes("private_byte", remaining)?);
+            // time_descriptor() is decoded when well-formed; otherwise kept as raw bytes
+            if descriptor_tag == 0x03
+                && let Some(time_descriptor) = parse_time_descriptor(&descriptor_bytes)
+            {
+                return Ok(SpliceDescriptor::Time(time_descriptor));
+            }
+            // Proprietary descriptors registered by the application
+            if let Some(private) =
+                crate::registry::parse_private_descriptor(descriptor_tag, &descriptor_bytes)
+            {
+                return Ok(SpliceDescriptor::Private(private));
             }
             Ok(SpliceDescriptor::Unknown {
                 tag: descriptor_tag,
@@ -187,6 +392,26 @@ pub(crate) fn parse_splice_descriptor(
     }
 }
 
+/// Parses the body of a time descriptor (tag 0x03).
+///
+/// Returns `None` if the body is not the 16-byte CUEI layout defined by SCTE-35,
+/// in which case the caller keeps the descriptor as raw bytes.
+fn parse_time_descriptor(data: &[u8]) -> Option<TimeDescriptor> {
+    if data.len() != 16 {
+        return None;
+    }
+    let identifier = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+    if identifier != 0x43554549 {
+        return None;
+    }
+    Some(TimeDescriptor {
+        identifier,
+        tai_seconds: data[4..10].to_vec(),
+        tai_ns: data[10..14].to_vec(),
+        utc_offset: data[14..16].to_vec(),
+    })
+}
+
 /// Parses a segmentation descriptor from the bit stream.
 ///
 /// This function implements the complete SCTE-35 segmentation descriptor parsing
@@ -207,7 +432,7 @@ pub(crate) fn parse_segmentation_descriptor(
         ));
     }
 
-    let identifier = reader.read_uimsbf(32)? as u32;
+    let identifier = reader.read_uimsbf("identifier", 32)? as u32;
     if identifier != 0x43554549 {
         // "CUEI" in big-endian
         return Err(io::Error::new(
@@ -226,9 +451,10 @@ pub(crate) fn parse_segmentation_descriptor(
         ));
     }
 
-    let segmentation_event_id = reader.read_uimsbf(32)? as u32;
-    let segmentation_event_cancel_indicator = reader.read_bslbf(1)? != 0;
-    let _reserved = reader.read_bslbf(7)?; // reserved bits
+    let segmentation_event_id = reader.read_uimsbf("segmentation_event_id", 32)? as u32;
+    let segmentation_event_cancel_indicator =
+        reader.read_bslbf("segmentation_event_cancel_indicator", 1)? != 0;
+    let _reserved = reader.read_bslbf("reserved", 7)?; // reserved bits
 
     if segmentation_event_cancel_indicator {
         // If cancel indicator is set, only the event ID and cancel flag are present
@@ -263,9 +489,9 @@ pub(crate) fn parse_segmentation_descriptor(
         ));
     }
 
-    let program_segmentation_flag = reader.read_bslbf(1)? != 0;
-    let segmentation_duration_flag = reader.read_bslbf(1)? != 0;
-    let delivery_not_restricted_flag = reader.read_bslbf(1)? != 0;
+    let program_segmentation_flag = reader.read_bslbf("program_segmentation_flag", 1)? != 0;
+    let segmentation_duration_flag = reader.read_bslbf("segmentation_duration_flag", 1)? != 0;
+    let delivery_not_restricted_flag = reader.read_bslbf("delivery_not_restricted_flag", 1)? != 0;
 
     let (
         web_delivery_allowed_flag,
@@ -273,10 +499,10 @@ pub(crate) fn parse_segmentation_descriptor(
         archive_allowed_flag,
         device_restrictions,
     ) = if !delivery_not_restricted_flag {
-        let web_delivery_allowed = reader.read_bslbf(1)? != 0;
-        let no_regional_blackout = reader.read_bslbf(1)? != 0;
-        let archive_allowed = reader.read_bslbf(1)? != 0;
-        let device_restrictions = reader.read_bslbf(2)? as u8;
+        let web_delivery_allowed = reader.read_bslbf("web_delivery_allowed", 1)? != 0;
+        let no_regional_blackout = reader.read_bslbf("no_regional_blackout", 1)? != 0;
+        let archive_allowed = reader.read_bslbf("archive_allowed", 1)? != 0;
+        let device_restrictions = reader.read_bslbf("device_restrictions", 2)? as u8;
         (
             Some(web_delivery_allowed),
             Some(no_regional_blackout),
@@ -284,7 +510,7 @@ pub(crate) fn parse_segmentation_descriptor(
             Some(device_restrictions),
         )
     } else {
-        let _reserved = reader.read_bslbf(5)?; // reserved bits when delivery not restricted
+        let _reserved = reader.read_bslbf("reserved", 5)?; // reserved bits when delivery not restricted
         (None, None, None, None)
     };
 
@@ -296,7 +522,7 @@ pub(crate) fn parse_segmentation_descriptor(
                 "Segmentation descriptor too short for component count",
             ));
         }
-        let component_count = reader.read_uimsbf(8)? as u8;
+        let component_count = reader.read_uimsbf("component_count", 8)? as u8;
 
         // Each component is 6 bytes (48 bits)
         let component_data_bits = component_count as usize * 48;
@@ -309,9 +535,11 @@ pub(crate) fn parse_segmentation_descriptor(
 
         // Skip component data
         for _ in 0..component_count {
-            let _component_tag = reader.read_uimsbf(8)?;
-            let _reserved = reader.read_bslbf(7)?;
-            let _pts_offset = reader.read_uimsbf(33)?;
+            reader.begin("component");
+            let _component_tag = reader.read_uimsbf("component_tag", 8)?;
+            let _reserved = reader.read_bslbf("reserved", 7)?;
+            let _pts_offset = reader.read_uimsbf("pts_offset", 33)?;
+            reader.end();
         }
     }
 
@@ -323,7 +551,7 @@ pub(crate) fn parse_segmentation_descriptor(
                 "Segmentation descriptor too short for duration",
             ));
         }
-        Some(reader.read_uimsbf(40)?)
+        Some(reader.read_uimsbf("segmentation_duration", 40)?)
     } else {
         None
     };
@@ -336,9 +564,9 @@ pub(crate) fn parse_segmentation_descriptor(
         ));
     }
 
-    let segmentation_upid_type_byte = reader.read_uimsbf(8)? as u8;
+    let segmentation_upid_type_byte = reader.read_uimsbf("segmentation_upid_type", 8)? as u8;
     let segmentation_upid_type = SegmentationUpidType::from(segmentation_upid_type_byte);
-    let segmentation_upid_length = reader.read_uimsbf(8)? as u8;
+    let segmentation_upid_length = reader.read_uimsbf("segmentation_upid_length", 8)? as u8;
 
     // Read UPID data - cap to available bytes, accounting for minimum 3 bytes needed after UPID
     let current_bits_used = reader.get_offset() - start_offset;
@@ -348,10 +576,7 @@ pub(crate) fn parse_segmentation_descriptor(
     let max_upid_bytes = max_upid_bits / 8;
     let actual_upid_length = std::cmp::min(segmentation_upid_length as usize, max_upid_bytes);
 
-    let mut segmentation_upid = Vec::new();
-    for _ in 0..actual_upid_length {
-        segmentation_upid.push(reader.read_uimsbf(8)? as u8);
-    }
+    let segmentation_upid = reader.read_bytes("segmentation_upid", actual_upid_length)?;
 
     // Read segmentation type, segment num, and segments expected (3 bytes)
     if (reader.get_offset() - start_offset) + 24 > max_bits {
@@ -361,24 +586,24 @@ pub(crate) fn parse_segmentation_descriptor(
         ));
     }
 
-    let segmentation_type_id = reader.read_uimsbf(8)? as u8;
-    let segment_num = reader.read_uimsbf(8)? as u8;
-    let segments_expected = reader.read_uimsbf(8)? as u8;
+    let segmentation_type_id = reader.read_uimsbf("segmentation_type_id", 8)? as u8;
+    let segment_num = reader.read_uimsbf("segment_num", 8)? as u8;
+    let segments_expected = reader.read_uimsbf("segments_expected", 8)? as u8;
 
     // Sub-segment fields are present for certain segmentation types (2 additional bytes)
-    let (sub_segment_num, sub_segments_expected) = match segmentation_type_id {
-        0x34 | 0x36 | 0x38 | 0x3A => {
+    let (sub_segment_num, sub_segments_expected) =
+        if SegmentationType::from_id(segmentation_type_id).has_sub_segments() {
             if (reader.get_offset() - start_offset) + 16 <= max_bits {
-                let sub_segment_num = reader.read_uimsbf(8)? as u8;
-                let sub_segments_expected = reader.read_uimsbf(8)? as u8;
+                let sub_segment_num = reader.read_uimsbf("sub_segment_num", 8)? as u8;
+                let sub_segments_expected = reader.read_uimsbf("sub_segments_expected", 8)? as u8;
                 (Some(sub_segment_num), Some(sub_segments_expected))
             } else {
                 // Not enough bytes for sub-segment fields
                 (None, None)
             }
-        }
-        _ => (None, None),
-    };
+        } else {
+            (None, None)
+        };
 
     Ok(SegmentationDescriptor {
         segmentation_event_id,
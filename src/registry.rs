@@ -0,0 +1,874 @@
+//! Extension points for vendor-defined payloads.
+//!
+//! SCTE-35 leaves several payloads to be defined by the owner of a registered
+//! identifier. This module lets applications plug in decoders for them so that the
+//! decoded values show up in string, CLI and serde output.
+//!
+//! # MPU decoders
+//!
+//! MPU segmentation UPIDs (0x0C) carry a 32-bit `format_identifier` followed by private
+//! data. Registering an [`MpuDecoder`] for a format identifier makes
+//! [`SegmentationDescriptor::mpu_payload`](crate::SegmentationDescriptor::mpu_payload),
+//! [`format_upid`](crate::upid::format_upid) and the serde output use the decoded value.
+//!
+//! ```rust
+//! use scte35::registry::{MpuPayload, register_mpu_decoder, decode_mpu};
+//! use std::fmt;
+//!
+//! #[derive(Debug)]
+//! struct EpisodeId(u32);
+//!
+//! impl fmt::Display for EpisodeId {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         write!(f, "episode {}", self.0)
+//!     }
+//! }
+//!
+//! impl MpuPayload for EpisodeId {}
+//!
+//! register_mpu_decoder(u32::from_be_bytes(*b"EPID"), |data: &[u8]| {
+//!     let id: [u8; 4] = data.try_into().ok()?;
+//!     Some(Box::new(EpisodeId(u32::from_be_bytes(id))) as Box<dyn MpuPayload>)
+//! });
+//!
+//! let payload = decode_mpu(u32::from_be_bytes(*b"EPID"), &[0, 0, 0, 42]).unwrap();
+//! assert_eq!(payload.to_string(), "episode 42");
+//! ```
+//!
+//! # Private descriptors
+//!
+//! Splice descriptors with a tag and identifier the library does not understand are
+//! parsed as [`SpliceDescriptor::Unknown`](crate::SpliceDescriptor::Unknown). Registering
+//! a [`DescriptorParser`] for a `(tag, identifier)` pair with [`register_descriptor`]
+//! makes the parser produce [`SpliceDescriptor::Private`](crate::SpliceDescriptor::Private)
+//! carrying a [`PrivateDescriptor`], which also handles encoding, display and serde output.
+//!
+//! ```rust
+//! use scte35::registry::{PrivateDescriptor, register_descriptor};
+//! use std::fmt;
+//!
+//! const NETWORK_ID: u32 = u32::from_be_bytes(*b"NETW");
+//!
+//! #[derive(Debug, Clone)]
+//! struct RegionDescriptor {
+//!     region: u16,
+//! }
+//!
+//! impl fmt::Display for RegionDescriptor {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         write!(f, "region {}", self.region)
+//!     }
+//! }
+//!
+//! impl PrivateDescriptor for RegionDescriptor {
+//!     fn tag(&self) -> u8 {
+//!         0xF0
+//!     }
+//!
+//!     fn identifier(&self) -> u32 {
+//!         NETWORK_ID
+//!     }
+//!
+//!     fn private_bytes(&self) -> Vec<u8> {
+//!         self.region.to_be_bytes().to_vec()
+//!     }
+//! }
+//!
+//! register_descriptor(0xF0, NETWORK_ID, |data: &[u8]| {
+//!     let region: [u8; 2] = data.try_into().ok()?;
+//!     Some(Box::new(RegionDescriptor { region: u16::from_be_bytes(region) })
+//!         as Box<dyn PrivateDescriptor>)
+//! });
+//! ```
+//!
+//! # Private commands
+//!
+//! `private_command()` payloads are owned by whoever assigned the `private_command_id`.
+//! Registering a [`PrivateCommandDecoder`] with [`register_private_command`] makes
+//! [`PrivateCommand::payload`](crate::PrivateCommand::payload), the CLI and serde output
+//! use the typed [`PrivateCommandPayload`], whose encoder is used by
+//! [`PrivateCommandBuilder::payload`](crate::builders::PrivateCommandBuilder::payload).
+//!
+//! # Global state
+//!
+//! The `register_*` functions install decoders in process-wide registries. Once
+//! registered, a decoder changes the result of
+//! [`parse_splice_info_section`](crate::parse_splice_info_section),
+//! [`upid_as_string`](crate::SegmentationDescriptor::upid_as_string) and serde output
+//! everywhere in the process, including code in other crates and tests running on other
+//! threads. Register decoders once at startup, and use identifiers in tests that no other
+//! test registers. The [`MpuDecoderRegistry`], [`DescriptorRegistry`] and
+//! [`PrivateCommandRegistry`] types can be used directly for local, non-global decoding.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, LazyLock, RwLock};
+
+/// A decoded MPU private data payload.
+///
+/// The [`Display`](fmt::Display) form is used in UPID strings and CLI output.
+/// [`to_json`](MpuPayload::to_json) optionally provides a structured form for serde
+/// output; by default the display string is used.
+pub trait MpuPayload: fmt::Display + fmt::Debug + Send + Sync {
+    /// Returns the payload as JSON text, or `None` to serialize the display string.
+    ///
+    /// The method exists with or without the `serde` feature so that implementations
+    /// compile either way. Text that is not valid JSON is serialized as a string.
+    fn to_json(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Decodes the private data of MPU UPIDs with a particular format identifier.
+///
+/// Implemented for any `Fn(&[u8]) -> Option<Box<dyn MpuPayload>>`.
+pub trait MpuDecoder: Send + Sync {
+    /// Decodes the private data, returning `None` if it does not match the expected schema.
+    fn decode(&self, private_data: &[u8]) -> Option<Box<dyn MpuPayload>>;
+}
+
+impl<F> MpuDecoder for F
+where
+    F: Fn(&[u8]) -> Option<Box<dyn MpuPayload>> + Send + Sync,
+{
+    fn decode(&self, private_data: &[u8]) -> Option<Box<dyn MpuPayload>> {
+        self(private_data)
+    }
+}
+
+/// A set of MPU decoders keyed by format identifier.
+#[derive(Default, Clone)]
+pub struct MpuDecoderRegistry {
+    decoders: HashMap<u32, Arc<dyn MpuDecoder>>,
+}
+
+impl MpuDecoderRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a decoder for a format identifier, replacing any existing one.
+    pub fn register(&mut self, format_identifier: u32, decoder: impl MpuDecoder + 'static) {
+        self.decoders.insert(format_identifier, Arc::new(decoder));
+    }
+
+    /// Removes the decoder for a format identifier. Returns `true` if one was registered.
+    pub fn unregister(&mut self, format_identifier: u32) -> bool {
+        self.decoders.remove(&format_identifier).is_some()
+    }
+
+    /// Returns `true` if a decoder is registered for the format identifier.
+    pub fn contains(&self, format_identifier: u32) -> bool {
+        self.decoders.contains_key(&format_identifier)
+    }
+
+    /// Decodes private data with the decoder registered for the format identifier.
+    ///
+    /// Returns `None` if no decoder is registered or the decoder rejects the data.
+    pub fn decode(
+        &self,
+        format_identifier: u32,
+        private_data: &[u8],
+    ) -> Option<Box<dyn MpuPayload>> {
+        self.decoders.get(&format_identifier)?.decode(private_data)
+    }
+}
+
+impl fmt::Debug for MpuDecoderRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut identifiers: Vec<_> = self.decoders.keys().copied().collect();
+        identifiers.sort_unstable();
+        f.debug_struct("MpuDecoderRegistry")
+            .field("format_identifiers", &identifiers)
+            .finish()
+    }
+}
+
+/// Process-wide MPU decoders used by string, CLI and serde output.
+static MPU_DECODERS: LazyLock<RwLock<MpuDecoderRegistry>> =
+    LazyLock::new(|| RwLock::new(MpuDecoderRegistry::new()));
+
+/// Registers a process-wide decoder for an MPU format identifier.
+///
+/// Replaces any decoder previously registered for the same identifier.
+pub fn register_mpu_decoder(format_identifier: u32, decoder: impl MpuDecoder + 'static) {
+    MPU_DECODERS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .register(format_identifier, decoder);
+}
+
+/// Removes the process-wide decoder for an MPU format identifier.
+///
+/// Returns `true` if one was registered.
+pub fn unregister_mpu_decoder(format_identifier: u32) -> bool {
+    MPU_DECODERS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .unregister(format_identifier)
+}
+
+/// Decodes MPU private data with the process-wide decoder for its format identifier.
+pub fn decode_mpu(format_identifier: u32, private_data: &[u8]) -> Option<Box<dyn MpuPayload>> {
+    MPU_DECODERS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .decode(format_identifier, private_data)
+}
+
+/// Clones a boxed [`PrivateDescriptor`].
+///
+/// Implemented automatically for every `PrivateDescriptor` that is `Clone`.
+pub trait PrivateDescriptorClone {
+    /// Returns a boxed copy of the descriptor.
+    fn clone_box(&self) -> Box<dyn PrivateDescriptor>;
+}
+
+impl<T> PrivateDescriptorClone for T
+where
+    T: PrivateDescriptor + Clone + 'static,
+{
+    fn clone_box(&self) -> Box<dyn PrivateDescriptor> {
+        Box::new(self.clone())
+    }
+}
+
+/// A decoded proprietary splice descriptor.
+///
+/// The descriptor is encoded as `tag`, `descriptor_length`, `identifier` and the
+/// [`private_bytes`](PrivateDescriptor::private_bytes). The [`Display`](fmt::Display)
+/// form is used in string and CLI output. [`to_json`](PrivateDescriptor::to_json)
+/// optionally provides a structured form for serde output; by default the display
+/// string is used.
+pub trait PrivateDescriptor:
+    PrivateDescriptorClone + fmt::Display + fmt::Debug + Send + Sync
+{
+    /// Returns the `splice_descriptor_tag`.
+    fn tag(&self) -> u8;
+
+    /// Returns the 32-bit descriptor identifier.
+    fn identifier(&self) -> u32;
+
+    /// Encodes the bytes following the identifier.
+    fn private_bytes(&self) -> Vec<u8>;
+
+    /// Returns the descriptor as JSON text, or `None` to serialize the display string.
+    ///
+    /// See [`MpuPayload::to_json`].
+    fn to_json(&self) -> Option<String> {
+        None
+    }
+}
+
+impl Clone for Box<dyn PrivateDescriptor> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Private descriptors are equal when they encode to the same bytes.
+impl PartialEq for dyn PrivateDescriptor {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag() == other.tag()
+            && self.identifier() == other.identifier()
+            && self.private_bytes() == other.private_bytes()
+    }
+}
+
+/// Parses the private bytes of descriptors with a particular tag and identifier.
+///
+/// Implemented for any `Fn(&[u8]) -> Option<Box<dyn PrivateDescriptor>>`.
+pub trait DescriptorParser: Send + Sync {
+    /// Parses the bytes following the identifier, returning `None` if they do not match
+    /// the expected layout.
+    fn parse(&self, private_bytes: &[u8]) -> Option<Box<dyn PrivateDescriptor>>;
+}
+
+impl<F> DescriptorParser for F
+where
+    F: Fn(&[u8]) -> Option<Box<dyn PrivateDescriptor>> + Send + Sync,
+{
+    fn parse(&self, private_bytes: &[u8]) -> Option<Box<dyn PrivateDescriptor>> {
+        self(private_bytes)
+    }
+}
+
+/// A set of private descriptor parsers keyed by `(tag, identifier)`.
+#[derive(Default, Clone)]
+pub struct DescriptorRegistry {
+    parsers: HashMap<(u8, u32), Arc<dyn DescriptorParser>>,
+}
+
+impl DescriptorRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a parser for a descriptor tag and identifier, replacing any existing one.
+    pub fn register(&mut self, tag: u8, identifier: u32, parser: impl DescriptorParser + 'static) {
+        self.parsers.insert((tag, identifier), Arc::new(parser));
+    }
+
+    /// Removes the parser for a descriptor tag and identifier. Returns `true` if one was
+    /// registered.
+    pub fn unregister(&mut self, tag: u8, identifier: u32) -> bool {
+        self.parsers.remove(&(tag, identifier)).is_some()
+    }
+
+    /// Returns `true` if a parser is registered for the descriptor tag and identifier.
+    pub fn contains(&self, tag: u8, identifier: u32) -> bool {
+        self.parsers.contains_key(&(tag, identifier))
+    }
+
+    /// Parses a descriptor body (the bytes following `descriptor_length`, starting with
+    /// the identifier) with the parser registered for its tag and identifier.
+    ///
+    /// Returns `None` if the body is shorter than the identifier, no parser is
+    /// registered or the parser rejects the data.
+    pub fn parse(&self, tag: u8, body: &[u8]) -> Option<Box<dyn PrivateDescriptor>> {
+        let identifier = u32::from_be_bytes(body.get(..4)?.try_into().ok()?);
+        self.parsers.get(&(tag, identifier))?.parse(&body[4..])
+    }
+}
+
+impl fmt::Debug for DescriptorRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut keys: Vec<_> = self.parsers.keys().copied().collect();
+        keys.sort_unstable();
+        f.debug_struct("DescriptorRegistry")
+            .field("descriptors", &keys)
+            .finish()
+    }
+}
+
+/// Process-wide private descriptor parsers used by the splice info section parser.
+static DESCRIPTORS: LazyLock<RwLock<DescriptorRegistry>> =
+    LazyLock::new(|| RwLock::new(DescriptorRegistry::new()));
+
+/// Registers a process-wide parser for a private descriptor tag and identifier.
+///
+/// Replaces any parser previously registered for the same pair.
+pub fn register_descriptor(tag: u8, identifier: u32, parser: impl DescriptorParser + 'static) {
+    DESCRIPTORS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .register(tag, identifier, parser);
+}
+
+/// Removes the process-wide parser for a private descriptor tag and identifier.
+///
+/// Returns `true` if one was registered.
+pub fn unregister_descriptor(tag: u8, identifier: u32) -> bool {
+    DESCRIPTORS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .unregister(tag, identifier)
+}
+
+/// Parses a descriptor body with the process-wide parser for its tag and identifier.
+///
+/// See [`DescriptorRegistry::parse`].
+pub fn parse_private_descriptor(tag: u8, body: &[u8]) -> Option<Box<dyn PrivateDescriptor>> {
+    DESCRIPTORS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .parse(tag, body)
+}
+
+/// A typed `private_command()` payload.
+///
+/// The [`Display`](fmt::Display) form is used in CLI output.
+/// [`to_json`](PrivateCommandPayload::to_json) optionally provides a structured form for
+/// serde output; by default the display string is used.
+pub trait PrivateCommandPayload: fmt::Display + fmt::Debug + Send + Sync {
+    /// Encodes the payload into the command's `private_bytes`.
+    fn private_bytes(&self) -> Vec<u8>;
+
+    /// Returns the payload as JSON text, or `None` to serialize the display string.
+    ///
+    /// See [`MpuPayload::to_json`].
+    fn to_json(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Decodes the `private_bytes` of private commands with a particular `private_command_id`.
+///
+/// Implemented for any `Fn(&[u8]) -> Option<Box<dyn PrivateCommandPayload>>`.
+pub trait PrivateCommandDecoder: Send + Sync {
+    /// Decodes the private bytes, returning `None` if they do not match the expected schema.
+    fn decode(&self, private_bytes: &[u8]) -> Option<Box<dyn PrivateCommandPayload>>;
+}
+
+impl<F> PrivateCommandDecoder for F
+where
+    F: Fn(&[u8]) -> Option<Box<dyn PrivateCommandPayload>> + Send + Sync,
+{
+    fn decode(&self, private_bytes: &[u8]) -> Option<Box<dyn PrivateCommandPayload>> {
+        self(private_bytes)
+    }
+}
+
+/// A set of private command decoders keyed by `private_command_id`.
+#[derive(Default, Clone)]
+pub struct PrivateCommandRegistry {
+    decoders: HashMap<u16, Arc<dyn PrivateCommandDecoder>>,
+}
+
+impl PrivateCommandRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a decoder for a private command ID, replacing any existing one.
+    pub fn register(
+        &mut self,
+        private_command_id: u16,
+        decoder: impl PrivateCommandDecoder + 'static,
+    ) {
+        self.decoders.insert(private_command_id, Arc::new(decoder));
+    }
+
+    /// Removes the decoder for a private command ID. Returns `true` if one was registered.
+    pub fn unregister(&mut self, private_command_id: u16) -> bool {
+        self.decoders.remove(&private_command_id).is_some()
+    }
+
+    /// Returns `true` if a decoder is registered for the private command ID.
+    pub fn contains(&self, private_command_id: u16) -> bool {
+        self.decoders.contains_key(&private_command_id)
+    }
+
+    /// Decodes private bytes with the decoder registered for the private command ID.
+    ///
+    /// Returns `None` if no decoder is registered or the decoder rejects the data.
+    pub fn decode(
+        &self,
+        private_command_id: u16,
+        private_bytes: &[u8],
+    ) -> Option<Box<dyn PrivateCommandPayload>> {
+        self.decoders
+            .get(&private_command_id)?
+            .decode(private_bytes)
+    }
+}
+
+impl fmt::Debug for PrivateCommandRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut ids: Vec<_> = self.decoders.keys().copied().collect();
+        ids.sort_unstable();
+        f.debug_struct("PrivateCommandRegistry")
+            .field("private_command_ids", &ids)
+            .finish()
+    }
+}
+
+/// Process-wide private command decoders used by CLI and serde output.
+static PRIVATE_COMMANDS: LazyLock<RwLock<PrivateCommandRegistry>> =
+    LazyLock::new(|| RwLock::new(PrivateCommandRegistry::new()));
+
+/// Registers a process-wide decoder for a private command ID.
+///
+/// Replaces any decoder previously registered for the same ID.
+pub fn register_private_command(
+    private_command_id: u16,
+    decoder: impl PrivateCommandDecoder + 'static,
+) {
+    PRIVATE_COMMANDS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .register(private_command_id, decoder);
+}
+
+/// Removes the process-wide decoder for a private command ID.
+///
+/// Returns `true` if one was registered.
+pub fn unregister_private_command(private_command_id: u16) -> bool {
+    PRIVATE_COMMANDS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .unregister(private_command_id)
+}
+
+/// Decodes private bytes with the process-wide decoder for the private command ID.
+pub fn decode_private_command(
+    private_command_id: u16,
+    private_bytes: &[u8],
+) -> Option<Box<dyn PrivateCommandPayload>> {
+    PRIVATE_COMMANDS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .decode(private_command_id, private_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Counter(u8);
+
+    impl fmt::Display for Counter {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "count={}", self.0)
+        }
+    }
+
+    impl MpuPayload for Counter {}
+
+    fn decode_counter(data: &[u8]) -> Option<Box<dyn MpuPayload>> {
+        match data {
+            [count] => Some(Box::new(Counter(*count))),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_mpu_decoder_registry() {
+        let mut registry = MpuDecoderRegistry::new();
+        assert!(registry.decode(1, &[7]).is_none());
+
+        registry.register(1, decode_counter);
+        assert!(registry.contains(1));
+        assert_eq!(registry.decode(1, &[7]).unwrap().to_string(), "count=7");
+        // Decoders may reject data that does not match their schema
+        assert!(registry.decode(1, &[7, 8]).is_none());
+        assert!(registry.decode(2, &[7]).is_none());
+
+        assert!(registry.unregister(1));
+        assert!(!registry.unregister(1));
+        assert!(registry.decode(1, &[7]).is_none());
+    }
+
+    #[test]
+    fn test_global_mpu_decoder() {
+        let format_identifier = u32::from_be_bytes(*b"TST1");
+        register_mpu_decoder(format_identifier, decode_counter);
+        assert_eq!(
+            decode_mpu(format_identifier, &[3]).unwrap().to_string(),
+            "count=3"
+        );
+        assert_eq!(decode_mpu(format_identifier, &[3]).unwrap().to_json(), None);
+        assert!(unregister_mpu_decoder(format_identifier));
+        assert!(decode_mpu(format_identifier, &[3]).is_none());
+    }
+
+    #[test]
+    fn test_registered_mpu_decoder_in_upid_output() {
+        use crate::builders::{SegmentationDescriptorBuilder, Upid};
+        use crate::types::SegmentationType;
+
+        #[derive(Debug)]
+        struct Structured(u8);
+
+        impl fmt::Display for Structured {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "value {}", self.0)
+            }
+        }
+
+        impl MpuPayload for Structured {
+            fn to_json(&self) -> Option<String> {
+                Some(format!(r#"{{"value":{}}}"#, self.0))
+            }
+        }
+
+        let format_identifier = u32::from_be_bytes(*b"TST2");
+        register_mpu_decoder(format_identifier, |data: &[u8]| match data {
+            [value] => Some(Box::new(Structured(*value)) as Box<dyn MpuPayload>),
+            _ => None,
+        });
+
+        let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::new_mpu(format_identifier, vec![9]))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(descriptor.mpu_payload().unwrap().to_string(), "value 9");
+        assert_eq!(
+            descriptor.upid_as_string().as_deref(),
+            Some("MPU(format: TST2, data: value 9)")
+        );
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&descriptor).unwrap();
+            assert_eq!(json["mpu_payload"]["value"], 9);
+        }
+
+        // Data the decoder rejects falls back to the raw form
+        let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .upid(Upid::new_mpu(format_identifier, vec![1, 2]))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(descriptor.mpu_payload().is_none());
+        assert_eq!(
+            descriptor.upid_as_string().as_deref(),
+            Some("MPU(format: TST2, data: 0x0102)")
+        );
+
+        unregister_mpu_decoder(format_identifier);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Region(u16);
+
+    impl fmt::Display for Region {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "region {}", self.0)
+        }
+    }
+
+    impl PrivateDescriptor for Region {
+        fn tag(&self) -> u8 {
+            0xF0
+        }
+
+        fn identifier(&self) -> u32 {
+            u32::from_be_bytes(*b"TST3")
+        }
+
+        fn private_bytes(&self) -> Vec<u8> {
+            self.0.to_be_bytes().to_vec()
+        }
+
+        fn to_json(&self) -> Option<String> {
+            Some(format!(r#"{{"region":{}}}"#, self.0))
+        }
+    }
+
+    fn parse_region(data: &[u8]) -> Option<Box<dyn PrivateDescriptor>> {
+        let region: [u8; 2] = data.try_into().ok()?;
+        Some(Box::new(Region(u16::from_be_bytes(region))))
+    }
+
+    #[test]
+    fn test_descriptor_registry() {
+        let identifier = u32::from_be_bytes(*b"TST3");
+        let mut registry = DescriptorRegistry::new();
+        registry.register(0xF0, identifier, parse_region);
+        assert!(registry.contains(0xF0, identifier));
+        assert!(!registry.contains(0xF1, identifier));
+
+        let parsed = registry.parse(0xF0, b"TST3\x01\x02").unwrap();
+        assert_eq!(parsed.to_string(), "region 258");
+        assert_eq!(parsed.private_bytes(), vec![0x01, 0x02]);
+        // Equality and cloning go through the encoded form
+        assert!(*parsed.clone() == *(Box::new(Region(258)) as Box<dyn PrivateDescriptor>));
+
+        assert!(registry.parse(0xF1, b"TST3\x01\x02").is_none());
+        assert!(registry.parse(0xF0, b"TST4\x01\x02").is_none());
+        assert!(registry.parse(0xF0, b"TST3\x01").is_none());
+        assert!(registry.parse(0xF0, b"TST").is_none());
+        assert!(registry.unregister(0xF0, identifier));
+        assert!(registry.parse(0xF0, b"TST3\x01\x02").is_none());
+    }
+
+    #[test]
+    fn test_private_descriptor_round_trip() {
+        use crate::builders::{SpliceInfoSectionBuilder, TimeSignalBuilder};
+        use crate::descriptors::SpliceDescriptor;
+        use crate::parse_splice_info_section;
+
+        let section = SpliceInfoSectionBuilder::new()
+            .time_signal(TimeSignalBuilder::new().build().unwrap())
+            .add_descriptor(SpliceDescriptor::Private(Box::new(Region(7))))
+            .build()
+            .unwrap();
+        #[cfg(feature = "crc-validation")]
+        let bytes = {
+            use crate::encoding::CrcEncodable;
+            section.encode_with_crc().unwrap()
+        };
+        #[cfg(not(feature = "crc-validation"))]
+        let bytes = {
+            use crate::encoding::Encodable;
+            section.encode_to_vec().unwrap()
+        };
+
+        // Without a registered parser the descriptor stays raw
+        let parsed = parse_splice_info_section(&bytes).unwrap();
+        assert_eq!(
+            parsed.splice_descriptors[0],
+            SpliceDescriptor::Unknown {
+                tag: 0xF0,
+                length: 6,
+                data: b"TST3\x00\x07".to_vec(),
+            }
+        );
+
+        register_descriptor(0xF0, u32::from_be_bytes(*b"TST3"), parse_region);
+        let parsed = parse_splice_info_section(&bytes).unwrap();
+        unregister_descriptor(0xF0, u32::from_be_bytes(*b"TST3"));
+
+        let descriptor = &parsed.splice_descriptors[0];
+        assert_eq!(descriptor, &section.splice_descriptors[0]);
+        assert_eq!(descriptor.tag(), 0xF0);
+        assert_eq!(descriptor.length(), Some(6));
+        assert_eq!(descriptor.as_str().as_deref(), Some("region 7"));
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(descriptor).unwrap();
+            assert_eq!(json["descriptor_type"], "Private");
+            assert_eq!(json["tag"], 0xF0);
+            assert_eq!(json["identifier"], u32::from_be_bytes(*b"TST3"));
+            assert_eq!(json["private_bytes"], "AAc=");
+            assert_eq!(json["value"]["region"], 7);
+
+            // Reads back as the raw descriptor, which encodes to the same bytes
+            use crate::encoding::Encodable;
+            let json = serde_json::to_string(&parsed).unwrap();
+            let restored: crate::SpliceInfoSection = serde_json::from_str(&json).unwrap();
+            let raw = &restored.splice_descriptors[0];
+            assert_eq!(
+                raw,
+                &SpliceDescriptor::Unknown {
+                    tag: 0xF0,
+                    length: 6,
+                    data: b"TST3\x00\x07".to_vec(),
+                }
+            );
+            assert_eq!(
+                raw.encode_to_vec().unwrap(),
+                descriptor.encode_to_vec().unwrap()
+            );
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Blob(usize);
+
+    impl fmt::Display for Blob {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} bytes", self.0)
+        }
+    }
+
+    impl PrivateDescriptor for Blob {
+        fn tag(&self) -> u8 {
+            0xF1
+        }
+
+        fn identifier(&self) -> u32 {
+            u32::from_be_bytes(*b"TST5")
+        }
+
+        fn private_bytes(&self) -> Vec<u8> {
+            vec![0xAB; self.0]
+        }
+    }
+
+    #[test]
+    fn test_private_descriptor_too_long() {
+        use crate::descriptors::SpliceDescriptor;
+        use crate::encoding::{Encodable, EncodingError};
+
+        let longest = SpliceDescriptor::Private(Box::new(Blob(251)));
+        assert_eq!(longest.length(), Some(255));
+        assert_eq!(longest.encode_to_vec().unwrap().len(), 257);
+
+        let too_long = SpliceDescriptor::Private(Box::new(Blob(252)));
+        assert_eq!(too_long.length(), None);
+        assert!(matches!(
+            too_long.encode_to_vec(),
+            Err(EncodingError::ValueTooLarge {
+                field: "descriptor_length",
+                max_value: 255,
+                actual_value: 256,
+            })
+        ));
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&too_long).unwrap();
+            assert!(serde_json::from_value::<SpliceDescriptor>(json).is_err());
+        }
+    }
+
+    #[derive(Debug)]
+    struct Volume(u8);
+
+    impl fmt::Display for Volume {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "volume {}%", self.0)
+        }
+    }
+
+    impl PrivateCommandPayload for Volume {
+        fn private_bytes(&self) -> Vec<u8> {
+            vec![self.0]
+        }
+    }
+
+    fn decode_volume(data: &[u8]) -> Option<Box<dyn PrivateCommandPayload>> {
+        match data {
+            [volume] if *volume <= 100 => Some(Box::new(Volume(*volume))),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_private_command_registry() {
+        let mut registry = PrivateCommandRegistry::new();
+        registry.register(0x0100, decode_volume);
+        assert!(registry.contains(0x0100));
+        assert_eq!(
+            registry.decode(0x0100, &[80]).unwrap().to_string(),
+            "volume 80%"
+        );
+        assert!(registry.decode(0x0100, &[101]).is_none());
+        assert!(registry.decode(0x0101, &[80]).is_none());
+        assert!(registry.unregister(0x0100));
+        assert!(registry.decode(0x0100, &[80]).is_none());
+    }
+
+    #[test]
+    fn test_private_command_round_trip() {
+        use crate::builders::{PrivateCommandBuilder, SpliceInfoSectionBuilder};
+        use crate::parse_splice_info_section;
+        use crate::types::SpliceCommand;
+
+        let command = PrivateCommandBuilder::new(0x0101)
+            .payload(&Volume(42))
+            .build()
+            .unwrap();
+        let section = SpliceInfoSectionBuilder::new()
+            .private_command(command)
+            .build()
+            .unwrap();
+        assert_eq!(section.splice_command_type, 0xFF);
+        #[cfg(feature = "crc-validation")]
+        let bytes = {
+            use crate::encoding::CrcEncodable;
+            section.encode_with_crc().unwrap()
+        };
+        #[cfg(not(feature = "crc-validation"))]
+        let bytes = {
+            use crate::encoding::Encodable;
+            section.encode_to_vec().unwrap()
+        };
+
+        let parsed = parse_splice_info_section(&bytes).unwrap();
+        let SpliceCommand::PrivateCommand(command) = &parsed.splice_command else {
+            panic!("Expected private command");
+        };
+        assert_eq!(command.private_command_id, 0x0101);
+        assert_eq!(command.private_bytes, vec![42]);
+        assert!(command.payload().is_none());
+
+        register_private_command(0x0101, decode_volume);
+        assert_eq!(command.payload().unwrap().to_string(), "volume 42%");
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(command).unwrap();
+            assert_eq!(json["private_command_id"], 0x0101);
+            assert_eq!(json["private_bytes"], "Kg==");
+            assert_eq!(json["payload"], "volume 42%");
+        }
+        unregister_private_command(0x0101);
+    }
+}
@@ -229,6 +229,22 @@ impl Serialize for SegmentationDescriptor {
             state.serialize_field("upid_string", &upid_string)?;
         }
 
+        if let Some(mid_upids) = self.mid_upids() {
+            state.serialize_field("mid_upids", &mid_upids)?;
+        }
+
+        if let Some(umid) = self.umid() {
+            state.serialize_field("umid", &umid)?;
+        }
+
+        if let Some(payload) = self.mpu_payload() {
+            state.serialize_field("mpu_payload", &payload_json(payload.to_json(), &payload))?;
+        }
+
+        if let Some(atsc) = self.atsc_content_identifier() {
+            state.serialize_field("atsc_content_identifier", &atsc)?;
+        }
+
         if let Some(_d) = self.duration() {
             let duration_info = DurationInfo::from_ticks(self.segmentation_duration.unwrap_or(0));
             state.serialize_field("duration_info", &duration_info)?;
@@ -238,6 +254,235 @@ impl Serialize for SegmentationDescriptor {
     }
 }
 
+/// Parses the JSON text provided by a registered payload, falling back to its display string.
+fn payload_json(json: Option<String>, display: &dyn fmt::Display) -> serde_json::Value {
+    json.and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_else(|| serde_json::Value::String(display.to_string()))
+}
+
+/// Custom serialization for MID member UPIDs to include the rendered value.
+use crate::upid::MidUpid;
+
+impl Serialize for MidUpid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("MidUpid", 4)?;
+        state.serialize_field("upid_type", &self.upid_type)?;
+        state.serialize_field("upid", &BASE64.encode(&self.upid))?;
+        if let Some(upid_string) = self.upid_as_string() {
+            state.serialize_field("upid_string", &upid_string)?;
+        }
+        if self.upid_type == crate::upid::SegmentationUpidType::MPU
+            && let Some(payload) = crate::upid::decode_mpu_upid(&self.upid)
+        {
+            state.serialize_field("mpu_payload", &payload_json(payload.to_json(), &payload))?;
+        }
+        state.end()
+    }
+}
+
+/// Custom serialization for UMIDs with each part as hex.
+use crate::upid::Umid;
+
+impl Serialize for Umid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let hex = |bytes: &[u8]| -> String { bytes.iter().map(|b| format!("{b:02X}")).collect() };
+        let mut state = serializer.serialize_struct("Umid", 4)?;
+        state.serialize_field("universal_label", &hex(&self.universal_label))?;
+        state.serialize_field("length", &self.length)?;
+        state.serialize_field("instance_number", &hex(&self.instance_number))?;
+        state.serialize_field("material_number", &hex(&self.material_number))?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Umid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct UmidData {
+            universal_label: String,
+            length: u8,
+            instance_number: String,
+            material_number: String,
+        }
+
+        fn part<const N: usize, E: de::Error>(name: &str, text: &str) -> Result<[u8; N], E> {
+            data_encoding::HEXUPPER_PERMISSIVE
+                .decode(text.as_bytes())
+                .ok()
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(|| E::custom(format!("{name} must be {N} bytes of hex")))
+        }
+
+        let data = UmidData::deserialize(deserializer)?;
+        Ok(Umid {
+            universal_label: part("universal_label", &data.universal_label)?,
+            length: data.length,
+            instance_number: part("instance_number", &data.instance_number)?,
+            material_number: part("material_number", &data.material_number)?,
+        })
+    }
+}
+
+/// Custom serialization for ATSC Content Identifiers with the content ID as base64.
+use crate::upid::AtscContentIdentifier;
+
+impl Serialize for AtscContentIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("AtscContentIdentifier", 5)?;
+        state.serialize_field("tsid", &self.tsid)?;
+        state.serialize_field("end_of_day", &self.end_of_day)?;
+        state.serialize_field("unique_for", &self.unique_for)?;
+        state.serialize_field("content_id", &BASE64.encode(&self.content_id))?;
+        if let Ok(text) = std::str::from_utf8(&self.content_id) {
+            state.serialize_field("content_id_string", text)?;
+        }
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for AtscContentIdentifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // `content_id_string` is derived from `content_id` and ignored
+        #[derive(Deserialize)]
+        struct AtscData {
+            tsid: u16,
+            end_of_day: u8,
+            unique_for: u16,
+            #[serde(deserialize_with = "deserialize_bytes")]
+            content_id: Vec<u8>,
+        }
+
+        let data = AtscData::deserialize(deserializer)?;
+        Ok(AtscContentIdentifier {
+            tsid: data.tsid,
+            end_of_day: data.end_of_day,
+            unique_for: data.unique_for,
+            content_id: data.content_id,
+        })
+    }
+}
+
+/// Custom serialization for private descriptors with the raw bytes and decoded value.
+use crate::registry::PrivateDescriptor;
+
+impl Serialize for dyn PrivateDescriptor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PrivateDescriptor", 4)?;
+        state.serialize_field("tag", &self.tag())?;
+        state.serialize_field("identifier", &self.identifier())?;
+        state.serialize_field("private_bytes", &BASE64.encode(&self.private_bytes()))?;
+        state.serialize_field("value", &payload_json(self.to_json(), &self))?;
+        state.end()
+    }
+}
+
+/// Deserializes descriptors by their `descriptor_type`.
+///
+/// Private descriptors become [`SpliceDescriptor::Unknown`], since the parser that produced
+/// them may not be registered; re-parsing the encoded section decodes them again.
+use crate::descriptors::{
+    AudioDescriptor, AvailDescriptor, DtmfDescriptor, SpliceDescriptor, TimeDescriptor,
+};
+
+impl<'de> Deserialize<'de> for SpliceDescriptor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // `value` of private descriptors is derived from `private_bytes` and ignored
+        #[derive(Deserialize)]
+        #[serde(tag = "descriptor_type")]
+        enum DescriptorData {
+            Segmentation(SegmentationDescriptor),
+            Avail(AvailDescriptor),
+            Dtmf(DtmfDescriptor),
+            Time(TimeDescriptor),
+            Audio(AudioDescriptor),
+            Private {
+                tag: u8,
+                identifier: u32,
+                #[serde(deserialize_with = "deserialize_bytes")]
+                private_bytes: Vec<u8>,
+            },
+            Unknown {
+                tag: u8,
+                length: u8,
+                #[serde(deserialize_with = "deserialize_bytes")]
+                data: Vec<u8>,
+            },
+        }
+
+        Ok(match DescriptorData::deserialize(deserializer)? {
+            DescriptorData::Segmentation(desc) => SpliceDescriptor::Segmentation(desc),
+            DescriptorData::Avail(desc) => SpliceDescriptor::Avail(desc),
+            DescriptorData::Dtmf(desc) => SpliceDescriptor::Dtmf(desc),
+            DescriptorData::Time(desc) => SpliceDescriptor::Time(desc),
+            DescriptorData::Audio(desc) => SpliceDescriptor::Audio(desc),
+            DescriptorData::Private {
+                tag,
+                identifier,
+                private_bytes,
+            } => {
+                let length = crate::descriptors::private_descriptor_length(&private_bytes)
+                    .map_err(|length| {
+                        de::Error::custom(format!(
+                            "private descriptor length {length} exceeds 255 bytes"
+                        ))
+                    })?;
+                let mut data = identifier.to_be_bytes().to_vec();
+                data.extend(private_bytes);
+                SpliceDescriptor::Unknown { tag, length, data }
+            }
+            DescriptorData::Unknown { tag, length, data } => {
+                SpliceDescriptor::Unknown { tag, length, data }
+            }
+        })
+    }
+}
+
+/// Custom serialization for private commands to include the decoded payload.
+use crate::types::PrivateCommand;
+
+impl Serialize for PrivateCommand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PrivateCommand", 4)?;
+        state.serialize_field("private_command_id", &self.private_command_id)?;
+        state.serialize_field("private_command_length", &self.private_command_length)?;
+        state.serialize_field("private_bytes", &BASE64.encode(&self.private_bytes))?;
+        if let Some(payload) = self.payload() {
+            state.serialize_field("payload", &payload_json(payload.to_json(), &payload))?;
+        }
+        state.end()
+    }
+}
+
 /// Custom serialization for SpliceTime to include duration info.
 use crate::time::{BreakDuration, SpliceTime};
 
@@ -427,6 +672,123 @@ mod tests {
         assert!(json.contains("\"segmentation_type_id\": 48"));
     }
 
+    #[test]
+    fn test_mid_upid_serialization() {
+        let mut upid = vec![0x03, 12];
+        upid.extend_from_slice(b"TEST12345678");
+        upid.extend_from_slice(&[0x0F, 7]);
+        upid.extend_from_slice(b"urn:x:1");
+
+        let descriptor = upid_descriptor(SegmentationUpidType::MID, upid);
+
+        let json: serde_json::Value = serde_json::to_value(&descriptor).unwrap();
+        assert_eq!(json["upid_string"], "MID(AdID: TEST12345678; URI: urn:x:1)");
+        let members = json["mid_upids"].as_array().unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0]["upid_type"]["value"], 3);
+        assert_eq!(members[0]["upid"], "VEVTVDEyMzQ1Njc4");
+        assert_eq!(members[0]["upid_string"], "TEST12345678");
+        assert_eq!(members[1]["upid_type"]["value"], 15);
+        assert_eq!(members[1]["upid_string"], "urn:x:1");
+
+        // Non-MID descriptors have no member list
+        let descriptor = SegmentationDescriptor {
+            segmentation_upid_type: SegmentationUpidType::AdID,
+            segmentation_upid_length: 12,
+            segmentation_upid: b"TEST12345678".to_vec(),
+            ..descriptor
+        };
+        let json: serde_json::Value = serde_json::to_value(&descriptor).unwrap();
+        assert!(json.get("mid_upids").is_none());
+        assert!(json.get("umid").is_none());
+    }
+
+    fn upid_descriptor(
+        upid_type: SegmentationUpidType,
+        segmentation_upid: Vec<u8>,
+    ) -> SegmentationDescriptor {
+        SegmentationDescriptor {
+            segmentation_event_id: 1,
+            segmentation_event_cancel_indicator: false,
+            program_segmentation_flag: true,
+            segmentation_duration_flag: false,
+            delivery_not_restricted_flag: true,
+            web_delivery_allowed_flag: None,
+            no_regional_blackout_flag: None,
+            archive_allowed_flag: None,
+            device_restrictions: None,
+            segmentation_duration: None,
+            segmentation_upid_type: upid_type,
+            segmentation_upid_length: segmentation_upid.len() as u8,
+            segmentation_upid,
+            segmentation_type_id: 0x30,
+            segmentation_type: SegmentationType::ProviderAdvertisementStart,
+            segment_num: 1,
+            segments_expected: 1,
+            sub_segment_num: None,
+            sub_segments_expected: None,
+        }
+    }
+
+    #[test]
+    fn test_umid_serialization() {
+        // UMIDs are broken down into their SMPTE 330M parts
+        let umid = crate::upid::parse_umid(
+            "060A2B34.01010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2.D718BFDA",
+        )
+        .unwrap();
+        let descriptor = upid_descriptor(SegmentationUpidType::UMID, umid.to_vec());
+        let json: serde_json::Value = serde_json::to_value(&descriptor).unwrap();
+        assert_eq!(json["umid"]["universal_label"], "060A2B340101010501010D20");
+        assert_eq!(json["umid"]["length"], 0x13);
+        assert_eq!(json["umid"]["instance_number"], "000000");
+        assert_eq!(
+            json["umid"]["material_number"],
+            "D2C9036C8F195343AB7014D2D718BFDA"
+        );
+        assert!(json.get("atsc_content_identifier").is_none());
+
+        let parsed: Umid = serde_json::from_value(json["umid"].clone()).unwrap();
+        assert_eq!(parsed, descriptor.umid().unwrap());
+        let reparsed: SegmentationDescriptor = serde_json::from_value(json).unwrap();
+        assert_eq!(reparsed, descriptor);
+
+        let short = serde_json::json!({
+            "universal_label": "060A2B34",
+            "length": 0x13,
+            "instance_number": "000000",
+            "material_number": "D2C9036C8F195343AB7014D2D718BFDA",
+        });
+        let error = serde_json::from_value::<Umid>(short).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("universal_label must be 12 bytes")
+        );
+    }
+
+    #[test]
+    fn test_atsc_content_identifier_serialization() {
+        // ATSC Content Identifiers expose their header fields
+        let descriptor = upid_descriptor(
+            SegmentationUpidType::ATSCContentIdentifier,
+            vec![0x12, 0x34, 0xC5, 0x60, b'X', b'Y', b'Z'],
+        );
+        let json: serde_json::Value = serde_json::to_value(&descriptor).unwrap();
+        let atsc = &json["atsc_content_identifier"];
+        assert_eq!(atsc["tsid"], 0x1234);
+        assert_eq!(atsc["end_of_day"], 2);
+        assert_eq!(atsc["unique_for"], 0x160);
+        assert_eq!(atsc["content_id"], "WFla");
+        assert_eq!(atsc["content_id_string"], "XYZ");
+        assert!(json.get("umid").is_none());
+
+        let parsed: AtscContentIdentifier = serde_json::from_value(atsc.clone()).unwrap();
+        assert_eq!(parsed, descriptor.atsc_content_identifier().unwrap());
+        let reparsed: SegmentationDescriptor = serde_json::from_value(json).unwrap();
+        assert_eq!(reparsed, descriptor);
+    }
+
     #[test]
     fn test_binary_data_serialization() {
         use crate::types::PrivateCommand;
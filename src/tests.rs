@@ -122,7 +122,10 @@ fn test_upid_adid_example_no_crc_validation() {
 
     // Check for CUEI descriptor (common in SCTE-35)
     if let Some(first_desc) = section.splice_descriptors.first() {
-        assert!(first_desc.length() > 0, "Descriptor should have content");
+        assert!(
+            first_desc.length().is_some_and(|length| length > 0),
+            "Descriptor should have content"
+        );
     }
 }
 
@@ -270,7 +273,10 @@ fn test_time_signal_placement_opportunity_end() {
 
     // Check for segmentation descriptor (common for placement opportunities)
     if let Some(first_desc) = section.splice_descriptors.first() {
-        assert!(first_desc.length() > 0, "Descriptor should have content");
+        assert!(
+            first_desc.length().is_some_and(|length| length > 0),
+            "Descriptor should have content"
+        );
         // Descriptor tag 2 is typically segmentation_descriptor
         assert_eq!(first_desc.tag(), 2, "Should be segmentation descriptor");
     }
@@ -356,6 +362,59 @@ fn test_duration_conversions() {
     assert!(splice_time_none.to_duration().is_none());
 }
 
+#[test]
+fn test_effective_splice_pts() {
+    // Splice insert from the README example: PTS 0x07369c02e, no pts_adjustment
+    let buffer = BASE64
+        .decode("/DAvAAAAAAAA///wFAVIAACPf+/+c2nALv4AUsz1AAAAAAAKAAhDVUVJAAABNWLbowo=".as_bytes())
+        .unwrap();
+    let mut section = parse_splice_info_section(&buffer).unwrap();
+    assert_eq!(section.effective_program_pts(), Some(Pts::new(0x07369c02e)));
+
+    // pts_adjustment is added modulo 2^33
+    section.pts_adjustment = 0x1_FFFF_FFFF - 0x07369c02e + 10;
+    let times = section.effective_splice_pts();
+    assert_eq!(
+        times,
+        vec![EffectiveSplicePts {
+            component_tag: None,
+            pts: Pts::new(9),
+        }]
+    );
+
+    // Component-level splice insert yields one entry per timed component
+    section.pts_adjustment = 100;
+    if let SpliceCommand::SpliceInsert(ref mut insert) = section.splice_command {
+        insert.program_splice_flag = 0;
+        insert.splice_time = None;
+        insert.components = vec![
+            SpliceInsertComponent {
+                component_tag: 0x01,
+                splice_time: Some(SpliceTime {
+                    time_specified_flag: 1,
+                    pts_time: Some(1_000),
+                }),
+            },
+            SpliceInsertComponent {
+                component_tag: 0x02,
+                splice_time: None,
+            },
+        ];
+    }
+    assert_eq!(
+        section.effective_splice_pts(),
+        vec![EffectiveSplicePts {
+            component_tag: Some(0x01),
+            pts: Pts::new(1_100),
+        }]
+    );
+    assert_eq!(section.effective_program_pts(), None);
+
+    // Commands without PTS times have no splice points
+    section.splice_command = SpliceCommand::SpliceNull;
+    assert!(section.effective_splice_pts().is_empty());
+}
+
 #[test]
 fn test_splice_descriptor_as_str() {
     // Test with valid UTF-8 bytes
@@ -501,7 +560,11 @@ fn test_parse_splice_info_section() {
             0x00,
             "Descriptor tag should be 0x00 (Avail Descriptor)"
         );
-        assert_eq!(descriptor.length(), 8, "Descriptor length should be 8");
+        assert_eq!(
+            descriptor.length(),
+            Some(8),
+            "Descriptor length should be 8"
+        );
 
         // For unknown descriptors, validate the raw bytes
         if let Some(raw_bytes) = descriptor.raw_bytes() {
@@ -872,7 +935,7 @@ fn test_segmentation_descriptor_upid_as_string() {
 
     assert_eq!(
         isan_descriptor.upid_as_string(),
-        Some("0000-003a-8d00-0000-0000-1000".to_string())
+        Some("0000-003A-8D00-0000-M-0000-1000-T".to_string())
     );
 
     // Test unknown UPID type (should return base64)
@@ -968,7 +1031,10 @@ fn test_format_helper_functions() {
     let isan_bytes = vec![
         0x00, 0x00, 0x00, 0x3a, 0x8d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00,
     ];
-    assert_eq!(format_isan(&isan_bytes), "0000-003a-8d00-0000-0000-1000");
+    assert_eq!(
+        format_isan(&isan_bytes),
+        "0000-003A-8D00-0000-M-0000-1000-T"
+    );
 
     // Test ISAN with wrong length (should fallback to base64)
     let short_isan = vec![0x12, 0x34];
@@ -1077,3 +1143,133 @@ fn test_mpu_upid_example() {
     // Verify CRC
     assert_eq!(section.crc_32, 0x9E869364);
 }
+
+#[test]
+fn test_segmentation_type_metadata() {
+    use crate::types::{SegmentationBoundary, SegmentationCategory};
+
+    // Every start/end type points at an opposite counterpart in the same category
+    for id in 0..=0xFFu8 {
+        let segmentation_type = SegmentationType::from_id(id);
+        if let Some(paired) = segmentation_type.paired_type() {
+            assert_ne!(segmentation_type.is_start(), paired.is_start());
+            assert_eq!(segmentation_type.category(), paired.category());
+            let round_trip = paired.paired_type().unwrap();
+            assert_eq!(round_trip.is_start(), segmentation_type.is_start());
+        }
+        // Only start types carry sub-segment fields
+        if segmentation_type.has_sub_segments() {
+            assert!(segmentation_type.is_start());
+        }
+    }
+
+    let sub_segment_ids: Vec<u8> = (0..=0xFFu8)
+        .filter(|&id| SegmentationType::from_id(id).has_sub_segments())
+        .collect();
+    assert_eq!(sub_segment_ids, vec![0x34, 0x36, 0x38, 0x3A, 0x44, 0x46]);
+
+    let end = SegmentationType::ProgramEarlyTermination.info();
+    assert_eq!(end.boundary, SegmentationBoundary::End);
+    assert_eq!(end.paired_type, Some(SegmentationType::ProgramStart));
+    assert!(!end.duration_expected);
+
+    assert!(SegmentationType::OpeningCreditEndDeprecated.is_deprecated());
+    assert!(!SegmentationType::ChapterEnd.is_deprecated());
+    assert_eq!(
+        SegmentationType::ContentIdentification.info().boundary,
+        SegmentationBoundary::Point
+    );
+    assert_eq!(
+        SegmentationType::DistributorAdBlockEnd.category(),
+        SegmentationCategory::Advertisement
+    );
+    assert_eq!(
+        SegmentationType::NetworkStart.category(),
+        SegmentationCategory::Network
+    );
+    assert!(SegmentationType::BreakStart.duration_expected());
+    assert_eq!(
+        SegmentationType::BreakEnd.paired_type(),
+        Some(SegmentationType::BreakStart)
+    );
+}
+
+#[test]
+fn test_parse_sections() {
+    let time_signal = BASE64
+        .decode(b"/DA0AAAAAAAA///wBQb+cr0AUAAeAhxDVUVJSAAAjn/PAAGlmbAICAAAAAAsoKGKNAIAmsnRfg==")
+        .unwrap();
+    let short_time_signal = BASE64
+        .decode(b"/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==")
+        .unwrap();
+
+    // Trailing bytes after a section are not part of its CRC
+    let mut buffer = time_signal.clone();
+    buffer.extend_from_slice(&[0x00, 0x01, 0x02]);
+    assert!(parse_splice_info_section(&buffer).is_ok());
+    #[cfg(feature = "crc-validation")]
+    assert!(crate::crc::validate_message_crc(&buffer).unwrap());
+
+    // Sections back to back, with stuffing between and after them
+    let mut buffer = time_signal.clone();
+    buffer.extend_from_slice(&short_time_signal);
+    buffer.extend_from_slice(&[0xFF; 5]);
+    buffer.extend_from_slice(&time_signal);
+    buffer.extend_from_slice(&[0xFF; 2]);
+
+    let sections: Vec<_> = parse_sections(&buffer)
+        .collect::<Result<_, _>>()
+        .expect("all sections should parse");
+    assert_eq!(sections.len(), 3);
+    assert_eq!(sections[0].splice_command_type, 0x06);
+    assert_eq!(sections[0].splice_descriptors.len(), 1);
+    assert_eq!(sections[1].splice_descriptors.len(), 0);
+    assert_eq!(sections[2], sections[0]);
+
+    let mut iter = parse_sections(&buffer);
+    iter.next();
+    assert_eq!(iter.offset(), time_signal.len());
+
+    // A truncated final section ends the iteration with an error
+    let truncated = &buffer[..buffer.len() - 10];
+    let results: Vec<_> = parse_sections(truncated).collect();
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok() && results[1].is_ok());
+    assert_eq!(
+        results[2].as_ref().unwrap_err().kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+
+    assert_eq!(parse_sections(&[]).count(), 0);
+    assert_eq!(parse_sections(&[0xFF, 0xFF]).count(), 0);
+}
+
+#[test]
+fn test_encode_sections_round_trip() {
+    use crate::builders::{SpliceInfoSectionBuilder, SpliceInsertBuilder};
+    use crate::encoding::encode_sections;
+
+    let heartbeat = SpliceInfoSectionBuilder::new()
+        .splice_null()
+        .build()
+        .unwrap();
+    let insert = SpliceInfoSectionBuilder::new()
+        .splice_insert(
+            SpliceInsertBuilder::new(42)
+                .immediate()
+                .duration(Duration::from_secs(30))
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let buffer = encode_sections([&heartbeat, &insert, &heartbeat]).unwrap();
+    let sections: Vec<_> = parse_sections(&buffer)
+        .collect::<Result<_, _>>()
+        .expect("encoded sections should parse");
+    assert_eq!(sections.len(), 3);
+    assert_eq!(sections[0].splice_command, heartbeat.splice_command);
+    assert_eq!(sections[1].splice_command, insert.splice_command);
+    assert_eq!(sections[2].splice_command, heartbeat.splice_command);
+}
@@ -3,7 +3,53 @@
 //! This module contains structures for representing time information in SCTE-35,
 //! including splice times, durations, and date/time values.
 
-use std::time::Duration;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Number of PTS ticks per second (90kHz clock).
+pub const PTS_TICKS_PER_SECOND: u64 = 90_000;
+
+/// Largest value representable by a 33-bit PTS field.
+pub const PTS_MAX: u64 = 0x1_FFFF_FFFF;
+
+/// Number of distinct 33-bit PTS values; PTS arithmetic wraps modulo this value.
+const PTS_MODULUS: u64 = PTS_MAX + 1;
+
+/// Converts a duration to 90kHz ticks without applying any 33-bit wraparound.
+///
+/// Durations beyond `u64::MAX` ticks saturate, so range checks against field limits
+/// still reject them.
+pub(crate) fn duration_to_ticks(duration: &Duration) -> u64 {
+    duration
+        .as_secs()
+        .saturating_mul(PTS_TICKS_PER_SECOND)
+        .saturating_add(subsec_ticks(duration))
+}
+
+/// Converts a duration to 90kHz ticks modulo 2^33.
+///
+/// 2^64 is a multiple of 2^33, so wrapping `u64` arithmetic followed by masking gives
+/// the exact remainder for any duration.
+fn duration_to_wrapped_ticks(duration: &Duration) -> u64 {
+    duration
+        .as_secs()
+        .wrapping_mul(PTS_TICKS_PER_SECOND)
+        .wrapping_add(subsec_ticks(duration))
+        & PTS_MAX
+}
+
+fn subsec_ticks(duration: &Duration) -> u64 {
+    duration.subsec_nanos() as u64 * PTS_TICKS_PER_SECOND / 1_000_000_000
+}
+
+/// Converts 90kHz ticks to a duration.
+pub(crate) fn ticks_to_duration(ticks: u64) -> Duration {
+    let seconds = ticks / PTS_TICKS_PER_SECOND;
+    let nanos = ((ticks % PTS_TICKS_PER_SECOND) * 1_000_000_000) / PTS_TICKS_PER_SECOND;
+    Duration::new(seconds, nanos as u32)
+}
 
 /// Represents a splice time with optional PTS (Presentation Time Stamp).
 ///
@@ -43,11 +89,16 @@ impl SpliceTime {
     /// assert_eq!(duration, Duration::from_secs(1));
     /// ```
     pub fn to_duration(&self) -> Option<Duration> {
-        self.pts_time.map(|pts| {
-            let seconds = pts / 90_000;
-            let nanos = ((pts % 90_000) * 1_000_000_000) / 90_000;
-            Duration::new(seconds, nanos as u32)
-        })
+        self.pts_time.map(ticks_to_duration)
+    }
+
+    /// Returns the PTS time as a wrapping [`Pts`] value, if a time is specified.
+    ///
+    /// Note that this is the raw value from the message; use
+    /// [`SpliceInfoSection::effective_splice_pts`](crate::SpliceInfoSection::effective_splice_pts)
+    /// to obtain times with `pts_adjustment` applied.
+    pub fn pts(&self) -> Option<Pts> {
+        self.pts_time.map(Pts::new)
     }
 }
 
@@ -88,9 +139,7 @@ impl BreakDuration {
     /// assert_eq!(duration, Duration::from_secs(30));
     /// ```
     pub fn to_duration(&self) -> Duration {
-        let seconds = self.duration / 90_000;
-        let nanos = ((self.duration % 90_000) * 1_000_000_000) / 90_000;
-        Duration::new(seconds, nanos as u32)
+        ticks_to_duration(self.duration)
     }
 }
 
@@ -106,6 +155,301 @@ impl From<&BreakDuration> for Duration {
     }
 }
 
+/// A 33-bit presentation timestamp in 90kHz ticks.
+///
+/// PTS values in MPEG-2 transport streams roll over roughly every 26.5 hours, so
+/// all arithmetic on `Pts` is performed modulo 2^33. Comparisons between two
+/// timestamps are only meaningful when they are less than half the PTS range
+/// (about 13.25 hours) apart, which is how [`Pts::wrapping_cmp`] and
+/// [`Pts::signed_distance`] interpret them.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::Pts;
+/// use std::cmp::Ordering;
+/// use std::time::Duration;
+///
+/// let near_rollover = Pts::new(0x1_FFFF_FFFF - 89_999);
+/// let later = near_rollover + Duration::from_secs(2);
+///
+/// // The addition wrapped around the 33-bit boundary...
+/// assert_eq!(later.ticks(), 90_000);
+/// // ...but the ordering is still preserved.
+/// assert_eq!(near_rollover.wrapping_cmp(later), Ordering::Less);
+/// assert_eq!(near_rollover.ticks_until(later), 180_000);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Pts(u64);
+
+impl Pts {
+    /// The largest representable PTS value.
+    pub const MAX: Pts = Pts(PTS_MAX);
+
+    /// Creates a PTS from a tick count, discarding any bits above the 33rd.
+    pub const fn new(ticks: u64) -> Self {
+        Pts(ticks & PTS_MAX)
+    }
+
+    /// Returns the PTS value in 90kHz ticks.
+    pub const fn ticks(self) -> u64 {
+        self.0
+    }
+
+    /// Creates a PTS from a duration measured from PTS zero, wrapping at 2^33 ticks.
+    pub fn from_duration(duration: Duration) -> Self {
+        Pts::new(duration_to_wrapped_ticks(&duration))
+    }
+
+    /// Converts the PTS to the duration elapsed since PTS zero.
+    pub fn to_duration(self) -> Duration {
+        ticks_to_duration(self.0)
+    }
+
+    /// Adds a number of ticks, wrapping at 2^33.
+    pub const fn wrapping_add(self, ticks: u64) -> Self {
+        Pts::new(self.0.wrapping_add(ticks))
+    }
+
+    /// Subtracts a number of ticks, wrapping at 2^33.
+    pub const fn wrapping_sub(self, ticks: u64) -> Self {
+        Pts::new(self.0.wrapping_sub(ticks))
+    }
+
+    /// Returns the number of ticks from `self` forward to `later`, accounting for wraparound.
+    ///
+    /// The result is always in `0..2^33`; if `later` is actually earlier than `self`,
+    /// the distance goes the long way around the PTS range.
+    pub const fn ticks_until(self, later: Pts) -> u64 {
+        later.0.wrapping_sub(self.0) & PTS_MAX
+    }
+
+    /// Returns the shortest signed distance in ticks from `self` to `other`.
+    ///
+    /// Positive values mean `other` is later than `self`. The result is in the range
+    /// `-2^32..=2^32`, so a timestamp just after a rollover is considered later than
+    /// one just before it. Two timestamps exactly half the range apart are ordered by
+    /// their tick values, so swapping the arguments always negates the result.
+    pub const fn signed_distance(self, other: Pts) -> i64 {
+        let forward = self.ticks_until(other);
+        let half = PTS_MODULUS / 2;
+        if forward > half || (forward == half && other.0 < self.0) {
+            forward as i64 - PTS_MODULUS as i64
+        } else {
+            forward as i64
+        }
+    }
+
+    /// Compares two timestamps taking wraparound into account.
+    ///
+    /// `other` is considered greater when it lies less than half the PTS range ahead of `self`.
+    /// `Pts` deliberately does not implement `PartialOrd`: this order is not transitive
+    /// across the whole range, so it must not be used for sorting.
+    pub fn wrapping_cmp(self, other: Pts) -> Ordering {
+        0.cmp(&self.signed_distance(other))
+    }
+
+    /// Returns `true` if `other` is within `window` of `self` in either direction.
+    pub fn is_within(self, other: Pts, window: Duration) -> bool {
+        self.signed_distance(other).unsigned_abs() <= duration_to_ticks(&window)
+    }
+}
+
+impl From<u64> for Pts {
+    fn from(ticks: u64) -> Self {
+        Pts::new(ticks)
+    }
+}
+
+impl From<Pts> for u64 {
+    fn from(pts: Pts) -> Self {
+        pts.ticks()
+    }
+}
+
+impl Add<Duration> for Pts {
+    type Output = Pts;
+
+    fn add(self, rhs: Duration) -> Pts {
+        self.wrapping_add(duration_to_wrapped_ticks(&rhs))
+    }
+}
+
+impl Sub<Duration> for Pts {
+    type Output = Pts;
+
+    fn sub(self, rhs: Duration) -> Pts {
+        self.wrapping_sub(duration_to_wrapped_ticks(&rhs))
+    }
+}
+
+impl Add<u64> for Pts {
+    type Output = Pts;
+
+    fn add(self, rhs: u64) -> Pts {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl Sub<u64> for Pts {
+    type Output = Pts;
+
+    fn sub(self, rhs: u64) -> Pts {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl fmt::Display for Pts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({:.6}s)", self.0, self.to_duration().as_secs_f64())
+    }
+}
+
+/// A splice point with the section's `pts_adjustment` applied.
+///
+/// Returned by [`SpliceInfoSection::effective_splice_pts`](crate::SpliceInfoSection::effective_splice_pts).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EffectiveSplicePts {
+    /// Component tag for component-level splices, `None` for program-level splices
+    pub component_tag: Option<u8>,
+    /// Splice time with `pts_adjustment` added, modulo 2^33
+    pub pts: Pts,
+}
+
+/// Seconds between the Unix epoch (1970-01-01) and the GPS epoch (1980-01-06T00:00:00Z).
+pub const GPS_EPOCH_UNIX_SECONDS: u64 = 315_964_800;
+
+/// GPS-UTC offsets announced by the IERS, as (Unix time the offset took effect, offset).
+const GPS_LEAP_SECONDS: [(u64, u32); 18] = [
+    (362_793_600, 1),    // 1981-07-01
+    (394_329_600, 2),    // 1982-07-01
+    (425_865_600, 3),    // 1983-07-01
+    (489_024_000, 4),    // 1985-07-01
+    (567_993_600, 5),    // 1988-01-01
+    (631_152_000, 6),    // 1990-01-01
+    (662_688_000, 7),    // 1991-01-01
+    (709_948_800, 8),    // 1992-07-01
+    (741_484_800, 9),    // 1993-07-01
+    (773_020_800, 10),   // 1994-07-01
+    (820_454_400, 11),   // 1996-01-01
+    (867_715_200, 12),   // 1997-07-01
+    (915_148_800, 13),   // 1999-01-01
+    (1_136_073_600, 14), // 2006-01-01
+    (1_230_768_000, 15), // 2009-01-01
+    (1_341_100_800, 16), // 2012-07-01
+    (1_435_708_800, 17), // 2015-07-01
+    (1_483_228_800, 18), // 2017-01-01
+];
+
+/// Table of GPS-UTC leap-second offsets used to convert `utc_splice_time` values.
+///
+/// SCTE-35 expresses `utc_splice_time` as seconds since the GPS epoch
+/// (1980-01-06T00:00:00Z), which does not include leap seconds. Converting to UTC
+/// requires subtracting the GPS-UTC offset in effect at that time. The default table
+/// contains every leap second up to 2017-01-01 (offset 18); further entries can be
+/// added with [`LeapSeconds::with_leap_second`] when new leap seconds are announced.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::time::LeapSeconds;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let leap_seconds = LeapSeconds::default();
+///
+/// // 2024-01-01T00:00:00Z is 1_388_102_418 GPS seconds
+/// let time = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+/// assert_eq!(leap_seconds.utc_to_gps(time), Some(1_388_102_418));
+/// assert_eq!(leap_seconds.gps_to_utc(1_388_102_418), time);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeapSeconds {
+    /// (Unix time the offset took effect, GPS-UTC offset), sorted by time
+    entries: Vec<(u64, u32)>,
+}
+
+impl LeapSeconds {
+    /// Creates a table with no leap seconds, treating GPS time as UTC.
+    pub fn none() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Creates a table with a single constant GPS-UTC offset.
+    pub fn fixed(offset: u32) -> Self {
+        Self {
+            entries: vec![(GPS_EPOCH_UNIX_SECONDS, offset)],
+        }
+    }
+
+    /// Adds a GPS-UTC offset that takes effect at the given UTC time.
+    ///
+    /// Replaces any existing entry for the same time.
+    pub fn with_leap_second(mut self, effective: SystemTime, offset: u32) -> Self {
+        let effective = effective
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        match self
+            .entries
+            .binary_search_by_key(&effective, |&(time, _)| time)
+        {
+            Ok(index) => self.entries[index].1 = offset,
+            Err(index) => self.entries.insert(index, (effective, offset)),
+        }
+        self
+    }
+
+    /// Returns the GPS-UTC offset in effect at the given UTC time.
+    pub fn offset_at(&self, time: SystemTime) -> u32 {
+        let unix_seconds = time
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.entries
+            .iter()
+            .rev()
+            .find(|&&(effective, _)| effective <= unix_seconds)
+            .map_or(0, |&(_, offset)| offset)
+    }
+
+    /// Converts seconds since the GPS epoch to UTC.
+    pub fn gps_to_utc(&self, gps_seconds: u32) -> SystemTime {
+        let gps_unix_seconds = GPS_EPOCH_UNIX_SECONDS + u64::from(gps_seconds);
+        // Entries are in UTC; compare in GPS time by adding each entry's own offset
+        let offset = self
+            .entries
+            .iter()
+            .rev()
+            .find(|&&(effective, offset)| effective + u64::from(offset) <= gps_unix_seconds)
+            .map_or(0, |&(_, offset)| offset);
+        UNIX_EPOCH + Duration::from_secs(gps_unix_seconds - u64::from(offset))
+    }
+
+    /// Converts a UTC time to whole seconds since the GPS epoch.
+    ///
+    /// Returns `None` if the time is before the GPS epoch or beyond the range of
+    /// a 32-bit `utc_splice_time`.
+    pub fn utc_to_gps(&self, time: SystemTime) -> Option<u32> {
+        let unix_seconds = time.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        let gps_seconds =
+            unix_seconds.checked_sub(GPS_EPOCH_UNIX_SECONDS)? + u64::from(self.offset_at(time));
+        u32::try_from(gps_seconds).ok()
+    }
+}
+
+impl Default for LeapSeconds {
+    /// Returns the table of leap seconds announced up to 2017-01-01.
+    fn default() -> Self {
+        Self {
+            entries: GPS_LEAP_SECONDS.to_vec(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,4 +499,146 @@ mod tests {
         let duration: Duration = break_duration_ref.into();
         assert_eq!(duration, Duration::from_secs(5));
     }
+
+    #[test]
+    fn test_pts_new_masks_to_33_bits() {
+        assert_eq!(Pts::new(PTS_MAX + 1).ticks(), 0);
+        assert_eq!(Pts::new(PTS_MAX + 10).ticks(), 9);
+        assert_eq!(Pts::MAX.ticks(), 0x1_FFFF_FFFF);
+    }
+
+    #[test]
+    fn test_pts_wrapping_arithmetic() {
+        let pts = Pts::new(PTS_MAX - 9);
+        assert_eq!(pts.wrapping_add(20).ticks(), 10);
+        assert_eq!(Pts::new(10).wrapping_sub(20).ticks(), PTS_MAX - 9);
+        assert_eq!((pts + Duration::from_secs(1)).ticks(), 89_990);
+        assert_eq!(
+            (Pts::new(0) - Duration::from_secs(1)).ticks(),
+            PTS_MAX - 89_999
+        );
+        assert_eq!((Pts::new(5) + 10u64).ticks(), 15);
+        assert_eq!((Pts::new(5) - 10u64).ticks(), PTS_MAX - 4);
+    }
+
+    #[test]
+    fn test_pts_distance_across_rollover() {
+        let before = Pts::new(PTS_MAX - 44_999);
+        let after = Pts::new(45_000);
+
+        assert_eq!(before.ticks_until(after), 90_000);
+        assert_eq!(after.ticks_until(before), PTS_MODULUS - 90_000);
+        assert_eq!(before.signed_distance(after), 90_000);
+        assert_eq!(after.signed_distance(before), -90_000);
+    }
+
+    #[test]
+    fn test_pts_comparison_within_window() {
+        let before = Pts::new(PTS_MAX - 44_999);
+        let after = Pts::new(45_000);
+
+        assert_eq!(before.wrapping_cmp(after), Ordering::Less);
+        assert_eq!(after.wrapping_cmp(before), Ordering::Greater);
+        assert_eq!(before.wrapping_cmp(before), Ordering::Equal);
+        assert!(before.is_within(after, Duration::from_secs(1)));
+        assert!(!before.is_within(after, Duration::from_millis(999)));
+        assert!(after.is_within(before, Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_pts_comparison_at_half_range_is_antisymmetric() {
+        let low = Pts::new(1_000);
+        let high = low.wrapping_add(PTS_MODULUS / 2);
+
+        assert_eq!(low.signed_distance(high), 1 << 32);
+        assert_eq!(high.signed_distance(low), -(1 << 32));
+        assert_eq!(low.wrapping_cmp(high), Ordering::Less);
+        assert_eq!(high.wrapping_cmp(low), Ordering::Greater);
+
+        // The same holds when the pair straddles the rollover
+        let high = Pts::new(PTS_MAX - 1_000);
+        let low = high.wrapping_add(PTS_MODULUS / 2);
+        assert_eq!(low.wrapping_cmp(high), Ordering::Less);
+        assert_eq!(high.wrapping_cmp(low), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_pts_duration_conversion() {
+        assert_eq!(Pts::from_duration(Duration::from_secs(1)).ticks(), 90_000);
+        assert_eq!(Pts::new(135_000).to_duration(), Duration::from_millis(1500));
+
+        // Durations beyond the 33-bit range (~95443.7s) wrap
+        let beyond_range = Duration::from_secs(95_444);
+        assert_eq!(
+            Pts::from_duration(beyond_range).ticks(),
+            95_444 * 90_000 - PTS_MODULUS
+        );
+
+        // Durations beyond u64::MAX ticks still wrap exactly instead of overflowing
+        let expected = ((u64::MAX as u128 * 90_000 + 89_999) % PTS_MODULUS as u128) as u64;
+        assert_eq!(Pts::from_duration(Duration::MAX).ticks(), expected);
+        assert_eq!((Pts::new(0) + Duration::MAX).ticks(), expected);
+        assert_eq!(
+            (Pts::new(0) - Duration::MAX).ticks(),
+            PTS_MODULUS - expected
+        );
+        assert!(Pts::new(0).is_within(Pts::new(PTS_MAX), Duration::MAX));
+    }
+
+    #[test]
+    fn test_splice_time_pts() {
+        let splice_time = SpliceTime {
+            time_specified_flag: 1,
+            pts_time: Some(90_000),
+        };
+        assert_eq!(splice_time.pts(), Some(Pts::new(90_000)));
+        assert_eq!(SpliceTime::default().pts(), None);
+    }
+
+    #[test]
+    fn test_leap_seconds_gps_conversion() {
+        let leap_seconds = LeapSeconds::default();
+
+        // GPS epoch: no leap seconds yet
+        assert_eq!(
+            leap_seconds.gps_to_utc(0),
+            UNIX_EPOCH + Duration::from_secs(GPS_EPOCH_UNIX_SECONDS)
+        );
+
+        // 2017-01-01T00:00:00Z, the first instant with an 18 second offset
+        let new_year_2017 = UNIX_EPOCH + Duration::from_secs(1_483_228_800);
+        let gps_seconds = 1_483_228_800 - GPS_EPOCH_UNIX_SECONDS as u32 + 18;
+        assert_eq!(leap_seconds.offset_at(new_year_2017), 18);
+        assert_eq!(leap_seconds.utc_to_gps(new_year_2017), Some(gps_seconds));
+        assert_eq!(leap_seconds.gps_to_utc(gps_seconds), new_year_2017);
+
+        // One second earlier the offset was still 17
+        let before = new_year_2017 - Duration::from_secs(1);
+        assert_eq!(leap_seconds.offset_at(before), 17);
+        assert_eq!(leap_seconds.utc_to_gps(before), Some(gps_seconds - 2));
+        assert_eq!(leap_seconds.gps_to_utc(gps_seconds - 2), before);
+
+        // Out of range
+        assert_eq!(leap_seconds.utc_to_gps(UNIX_EPOCH), None);
+        assert_eq!(
+            leap_seconds.utc_to_gps(UNIX_EPOCH + Duration::from_secs(5_000_000_000)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_leap_seconds_custom_table() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+
+        assert_eq!(LeapSeconds::none().offset_at(time), 0);
+        assert_eq!(LeapSeconds::fixed(18).offset_at(time), 18);
+
+        // A hypothetical future leap second
+        let future = UNIX_EPOCH + Duration::from_secs(1_900_000_000);
+        let leap_seconds = LeapSeconds::default().with_leap_second(future, 19);
+        assert_eq!(leap_seconds.offset_at(time), 18);
+        assert_eq!(leap_seconds.offset_at(future), 19);
+        let gps_seconds = leap_seconds.utc_to_gps(future).unwrap();
+        assert_eq!(leap_seconds.gps_to_utc(gps_seconds), future);
+    }
 }
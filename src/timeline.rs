@@ -0,0 +1,404 @@
+//! Mapping of splice PTS values to wall-clock time.
+//!
+//! SCTE-35 messages express splice points as 33-bit PTS values on the program clock.
+//! A [`Timeline`] ties that clock to UTC using a single anchor (a PTS value and the
+//! wall-clock time it corresponds to) and converts any splice time, break end or
+//! segmentation end to a [`SystemTime`].
+
+use crate::descriptors::{SegmentationDescriptor, SpliceDescriptor, TimeDescriptor};
+use crate::time::{EffectiveSplicePts, Pts, ticks_to_duration};
+use crate::types::{SpliceCommand, SpliceInfoSection};
+use std::time::{Duration, SystemTime};
+
+/// Number of 27MHz PCR ticks per 90kHz PTS tick.
+const PCR_TICKS_PER_PTS_TICK: u64 = 300;
+
+/// Converts PTS values on a program clock to wall-clock time.
+///
+/// The timeline is anchored by a PTS value and the UTC time it corresponds to. PTS values
+/// are interpreted relative to the anchor using the shortest wrapping distance, so values up
+/// to ~13.25 hours either side of the anchor convert correctly across a 33-bit rollover.
+/// For long-running streams, call [`Timeline::observe`] with recent PTS values to move the
+/// anchor forward as the stream progresses.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::{Pts, Timeline};
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// // PTS 0x1_FFFF_0000 was presented at 2024-01-01T00:00:00Z
+/// let anchor_time = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+/// let timeline = Timeline::new(Pts::new(0x1_FFFF_0000), anchor_time);
+///
+/// // A splice 10 seconds later, after the PTS has rolled over
+/// let splice_pts = Pts::new(0x1_FFFF_0000) + Duration::from_secs(10);
+/// assert!(splice_pts.ticks() < 0x1_FFFF_0000);
+/// assert_eq!(
+///     timeline.to_system_time(splice_pts),
+///     anchor_time + Duration::from_secs(10)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeline {
+    anchor_pts: Pts,
+    anchor_time: SystemTime,
+}
+
+impl Timeline {
+    /// Creates a timeline from a user-supplied PTS/UTC pair.
+    pub fn new(anchor_pts: Pts, anchor_time: SystemTime) -> Self {
+        Self {
+            anchor_pts,
+            anchor_time,
+        }
+    }
+
+    /// Creates a timeline from a program clock reference sample.
+    ///
+    /// `pcr` is the full 27MHz PCR value (`pcr_base * 300 + pcr_extension`) and
+    /// `anchor_time` the wall-clock time at which it was received.
+    pub fn from_pcr(pcr: u64, anchor_time: SystemTime) -> Self {
+        Self::new(Pts::new(pcr / PCR_TICKS_PER_PTS_TICK), anchor_time)
+    }
+
+    /// Creates a timeline from a time descriptor and the PTS it applies to.
+    ///
+    /// A `time_descriptor()` carries the TAI time corresponding to the splice time of
+    /// the command it accompanies, so `pts` should be that splice time with
+    /// `pts_adjustment` applied.
+    ///
+    /// Returns `None` if the descriptor's fields are malformed.
+    pub fn from_time_descriptor(descriptor: &TimeDescriptor, pts: Pts) -> Option<Self> {
+        descriptor
+            .to_system_time()
+            .map(|anchor_time| Self::new(pts, anchor_time))
+    }
+
+    /// Creates a timeline from a section carrying a time descriptor.
+    ///
+    /// The first time descriptor in the section is paired with the section's program
+    /// splice time. Returns `None` if the section has no time descriptor or no
+    /// program-level splice time.
+    pub fn from_section(section: &SpliceInfoSection) -> Option<Self> {
+        let pts = section.effective_program_pts()?;
+        section
+            .splice_descriptors
+            .iter()
+            .find_map(|descriptor| match descriptor {
+                SpliceDescriptor::Time(time) => Self::from_time_descriptor(time, pts),
+                _ => None,
+            })
+    }
+
+    /// Returns the PTS value of the anchor.
+    pub fn anchor_pts(&self) -> Pts {
+        self.anchor_pts
+    }
+
+    /// Returns the wall-clock time of the anchor.
+    pub fn anchor_time(&self) -> SystemTime {
+        self.anchor_time
+    }
+
+    /// Moves the anchor to a recently observed PTS value.
+    ///
+    /// Calling this periodically (at least every few hours of stream time) keeps
+    /// conversions correct across any number of 33-bit rollovers.
+    pub fn observe(&mut self, pts: Pts) {
+        self.anchor_time = self.to_system_time(pts);
+        self.anchor_pts = pts;
+    }
+
+    /// Converts a PTS value to wall-clock time.
+    pub fn to_system_time(&self, pts: Pts) -> SystemTime {
+        let distance = self.anchor_pts.signed_distance(pts);
+        let offset = ticks_to_duration(distance.unsigned_abs());
+        if distance >= 0 {
+            self.anchor_time + offset
+        } else {
+            self.anchor_time - offset
+        }
+    }
+
+    /// Converts a wall-clock time to the PTS value presented at that time.
+    pub fn to_pts(&self, time: SystemTime) -> Pts {
+        match time.duration_since(self.anchor_time) {
+            Ok(after) => self.anchor_pts + after,
+            Err(before) => self.anchor_pts - before.duration(),
+        }
+    }
+
+    /// Returns the wall-clock time of every splice point in the section.
+    ///
+    /// `pts_adjustment` is applied before conversion; see
+    /// [`SpliceInfoSection::effective_splice_pts`].
+    pub fn splice_times(
+        &self,
+        section: &SpliceInfoSection,
+    ) -> Vec<(EffectiveSplicePts, SystemTime)> {
+        section
+            .effective_splice_pts()
+            .into_iter()
+            .map(|point| (point, self.to_system_time(point.pts)))
+            .collect()
+    }
+
+    /// Returns the wall-clock time of the section's program-level splice point.
+    pub fn splice_time(&self, section: &SpliceInfoSection) -> Option<SystemTime> {
+        section
+            .effective_program_pts()
+            .map(|pts| self.to_system_time(pts))
+    }
+
+    /// Returns the wall-clock time at which a `splice_insert()` break ends.
+    ///
+    /// This is the program splice time plus the break duration. Returns `None` for
+    /// other commands, immediate splices and inserts without a break duration.
+    pub fn break_end(&self, section: &SpliceInfoSection) -> Option<SystemTime> {
+        let SpliceCommand::SpliceInsert(insert) = &section.splice_command else {
+            return None;
+        };
+        let duration = insert.break_duration.as_ref()?.to_duration();
+        self.end_time(section, duration)
+    }
+
+    /// Returns the wall-clock time at which a segment ends.
+    ///
+    /// This is the section's program splice time plus the descriptor's
+    /// segmentation duration. Returns `None` if either is absent.
+    pub fn segmentation_end(
+        &self,
+        section: &SpliceInfoSection,
+        descriptor: &SegmentationDescriptor,
+    ) -> Option<SystemTime> {
+        self.end_time(section, descriptor.duration()?)
+    }
+
+    fn end_time(&self, section: &SpliceInfoSection, duration: Duration) -> Option<SystemTime> {
+        let start = section.effective_program_pts()?;
+        // Convert the start first so durations longer than half the PTS range still
+        // produce a time after the start.
+        Some(self.to_system_time(start) + duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builders::{
+        BreakDurationBuilder, SegmentationDescriptorBuilder, SpliceInfoSectionBuilder,
+        SpliceInsertBuilder, TimeSignalBuilder,
+    };
+    use crate::types::SegmentationType;
+    use std::time::UNIX_EPOCH;
+
+    fn anchor_time() -> SystemTime {
+        // 2024-01-01T00:00:00Z
+        UNIX_EPOCH + Duration::from_secs(1_704_067_200)
+    }
+
+    #[test]
+    fn test_conversion_around_anchor() {
+        let timeline = Timeline::new(Pts::new(900_000), anchor_time());
+
+        assert_eq!(timeline.to_system_time(Pts::new(900_000)), anchor_time());
+        assert_eq!(
+            timeline.to_system_time(Pts::new(945_000)),
+            anchor_time() + Duration::from_millis(500)
+        );
+        assert_eq!(
+            timeline.to_system_time(Pts::new(0)),
+            anchor_time() - Duration::from_secs(10)
+        );
+        assert_eq!(
+            timeline.to_pts(anchor_time() - Duration::from_secs(10)),
+            Pts::new(0)
+        );
+        assert_eq!(
+            timeline.to_pts(anchor_time() + Duration::from_secs(1)),
+            Pts::new(990_000)
+        );
+    }
+
+    #[test]
+    fn test_conversion_across_rollover() {
+        let timeline = Timeline::new(Pts::MAX, anchor_time());
+
+        // One tick after the anchor is PTS 0
+        assert_eq!(
+            timeline.to_system_time(Pts::new(89_999)),
+            anchor_time() + Duration::from_secs(1)
+        );
+        assert_eq!(
+            timeline.to_pts(anchor_time() + Duration::from_secs(1)),
+            Pts::new(89_999)
+        );
+    }
+
+    #[test]
+    fn test_observe_tracks_multiple_rollovers() {
+        let mut timeline = Timeline::new(Pts::new(0), anchor_time());
+
+        // Walk the stream forward in 6 hour steps for three days
+        let step = Duration::from_secs(6 * 3600);
+        let mut pts = Pts::new(0);
+        for _ in 0..12 {
+            pts = pts + step;
+            timeline.observe(pts);
+        }
+
+        assert_eq!(timeline.anchor_pts(), pts);
+        assert_eq!(
+            timeline.anchor_time(),
+            anchor_time() + Duration::from_secs(72 * 3600)
+        );
+        assert_eq!(
+            timeline.to_system_time(pts + Duration::from_secs(5)),
+            anchor_time() + Duration::from_secs(72 * 3600 + 5)
+        );
+    }
+
+    #[test]
+    fn test_from_pcr() {
+        // PCR of 10 seconds at 27MHz, plus a sub-PTS-tick extension
+        let timeline = Timeline::from_pcr(10 * 27_000_000 + 150, anchor_time());
+        assert_eq!(timeline.anchor_pts(), Pts::new(900_000));
+    }
+
+    #[test]
+    fn test_from_section_with_time_descriptor() {
+        let time_signal = TimeSignalBuilder::new()
+            .at_pts(Duration::from_secs(100))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        // TAI is 37 seconds ahead of UTC
+        let tai_seconds = 1_704_067_200u64 + 37;
+        let descriptor = TimeDescriptor {
+            identifier: 0x43554549,
+            tai_seconds: tai_seconds.to_be_bytes()[2..].to_vec(),
+            tai_ns: 500_000_000u32.to_be_bytes().to_vec(),
+            utc_offset: 37u16.to_be_bytes().to_vec(),
+        };
+
+        let section = SpliceInfoSectionBuilder::new()
+            .pts_adjustment(900_000)
+            .time_signal(time_signal)
+            .add_descriptor(SpliceDescriptor::Time(descriptor))
+            .build()
+            .unwrap();
+
+        let timeline = Timeline::from_section(&section).unwrap();
+        assert_eq!(timeline.anchor_pts(), Pts::new(9_900_000));
+        assert_eq!(
+            timeline.anchor_time(),
+            anchor_time() + Duration::from_millis(500)
+        );
+        assert_eq!(
+            timeline.splice_time(&section),
+            Some(anchor_time() + Duration::from_millis(500))
+        );
+
+        // A section without a time descriptor cannot anchor a timeline
+        let time_signal = TimeSignalBuilder::new().build().unwrap();
+        let section = SpliceInfoSectionBuilder::new()
+            .time_signal(time_signal)
+            .build()
+            .unwrap();
+        assert_eq!(Timeline::from_section(&section), None);
+    }
+
+    #[test]
+    fn test_time_descriptor_round_trip_through_parser() {
+        let time_signal = TimeSignalBuilder::new()
+            .at_pts(Duration::from_secs(1))
+            .unwrap()
+            .build()
+            .unwrap();
+        let descriptor = TimeDescriptor {
+            identifier: 0x43554549,
+            tai_seconds: vec![0x00, 0x00, 0x65, 0x92, 0x00, 0x25],
+            tai_ns: vec![0x00, 0x00, 0x00, 0x00],
+            utc_offset: vec![0x00, 0x25],
+        };
+        let section = SpliceInfoSectionBuilder::new()
+            .time_signal(time_signal)
+            .add_descriptor(SpliceDescriptor::Time(descriptor.clone()))
+            .build()
+            .unwrap();
+
+        #[cfg(feature = "crc-validation")]
+        let bytes = {
+            use crate::encoding::CrcEncodable;
+            section.encode_with_crc().unwrap()
+        };
+        #[cfg(not(feature = "crc-validation"))]
+        let bytes = {
+            use crate::encoding::Encodable;
+            section.encode_to_vec().unwrap()
+        };
+
+        let parsed = crate::parse_splice_info_section(&bytes).unwrap();
+        assert_eq!(
+            parsed.splice_descriptors,
+            vec![SpliceDescriptor::Time(descriptor)]
+        );
+        assert_eq!(
+            Timeline::from_section(&parsed).map(|t| t.anchor_time()),
+            Some(UNIX_EPOCH + Duration::from_secs(0x6592_0000))
+        );
+    }
+
+    #[test]
+    fn test_break_and_segmentation_end() {
+        let timeline = Timeline::new(Pts::new(0), anchor_time());
+
+        let insert = SpliceInsertBuilder::new(1)
+            .at_pts(Duration::from_secs(20))
+            .unwrap()
+            .duration(Duration::from_secs(30))
+            .build()
+            .unwrap();
+        let section = SpliceInfoSectionBuilder::new()
+            .splice_insert(insert)
+            .build()
+            .unwrap();
+        assert_eq!(
+            timeline.break_end(&section),
+            Some(anchor_time() + Duration::from_secs(50))
+        );
+        assert_eq!(
+            BreakDurationBuilder::new(Duration::from_secs(30))
+                .build()
+                .unwrap()
+                .to_duration(),
+            Duration::from_secs(30)
+        );
+
+        let time_signal = TimeSignalBuilder::new()
+            .at_pts(Duration::from_secs(60))
+            .unwrap()
+            .build()
+            .unwrap();
+        let descriptor = SegmentationDescriptorBuilder::new(
+            2,
+            SegmentationType::ProviderPlacementOpportunityStart,
+        )
+        .duration(Duration::from_secs(120))
+        .unwrap()
+        .build()
+        .unwrap();
+        let section = SpliceInfoSectionBuilder::new()
+            .time_signal(time_signal)
+            .add_segmentation_descriptor(descriptor.clone())
+            .build()
+            .unwrap();
+        assert_eq!(timeline.break_end(&section), None);
+        assert_eq!(
+            timeline.segmentation_end(&section, &descriptor),
+            Some(anchor_time() + Duration::from_secs(180))
+        );
+    }
+}
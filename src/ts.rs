@@ -0,0 +1,603 @@
+//! Scanning of MPEG-2 transport streams for SCTE-35 sections.
+//!
+//! [`TsScanner`] consumes a transport stream in chunks of any size and reports every
+//! SCTE-35 section it finds, together with the most recent video PTS and PCR, so a cue
+//! can be placed on the stream's timeline. SCTE-35 PIDs are discovered from the program
+//! map tables (`stream_type` 0x86); streams without PSI can name them explicitly with
+//! [`TsScanner::add_scte35_pid`]. Continuity counter gaps and loss of packet sync are
+//! reported along the way.
+
+use crate::parser::parse_splice_info_section;
+use crate::time::Pts;
+use crate::types::SpliceInfoSection;
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+
+/// Size of a transport stream packet in bytes.
+pub const PACKET_SIZE: usize = 188;
+
+/// `stream_type` assigned to SCTE-35 in the program map table.
+pub const SCTE35_STREAM_TYPE: u8 = 0x86;
+
+const SYNC_BYTE: u8 = 0x47;
+const PAT_PID: u16 = 0x0000;
+const NULL_PID: u16 = 0x1FFF;
+
+/// `stream_type` values of video elementary streams (MPEG-1/2, MPEG-4, AVC, HEVC, VVC).
+const VIDEO_STREAM_TYPES: &[u8] = &[0x01, 0x02, 0x10, 0x1B, 0x24, 0x33];
+
+/// Where a section started and what the stream clock read at that point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Origin {
+    packet: u64,
+    offset: u64,
+    video_pts: Option<Pts>,
+    pcr: Option<u64>,
+}
+
+/// An SCTE-35 section found in a transport stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TsCue {
+    /// Zero-based index of the packet in which the section starts
+    pub packet: u64,
+    /// Byte offset of that packet from the start of the stream
+    pub offset: u64,
+    /// PID that carried the section
+    pub pid: u16,
+    /// Most recent PTS seen on a video PID before the section started
+    pub video_pts: Option<Pts>,
+    /// Most recent PCR seen before the section started, in 27MHz units
+    pub pcr: Option<u64>,
+    /// The complete section, from `table_id` to `CRC_32`
+    pub section: Vec<u8>,
+}
+
+impl TsCue {
+    /// Parses the section, validating its CRC when the `crc-validation` feature is enabled.
+    pub fn parse(&self) -> Result<SpliceInfoSection, io::Error> {
+        parse_splice_info_section(&self.section)
+    }
+}
+
+/// Something found while scanning a transport stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TsEvent {
+    /// A complete SCTE-35 section
+    Cue(TsCue),
+    /// A packet whose `continuity_counter` does not follow the previous packet on its PID
+    ContinuityError {
+        /// Zero-based index of the packet
+        packet: u64,
+        /// Byte offset of the packet
+        offset: u64,
+        /// PID of the packet
+        pid: u16,
+        /// The counter value that should have followed
+        expected: u8,
+        /// The counter value found
+        found: u8,
+    },
+    /// The stream did not contain a sync byte where a packet should start
+    SyncLost {
+        /// Byte offset of the missing sync byte
+        offset: u64,
+    },
+}
+
+/// Section reassembly state of one PID.
+#[derive(Default)]
+struct SectionBuffer {
+    data: Vec<u8>,
+    /// Set while a section is in progress
+    origin: Option<Origin>,
+}
+
+impl SectionBuffer {
+    /// Moves every complete section out of the buffer.
+    fn take_complete(&mut self, complete: &mut Vec<(Origin, Vec<u8>)>) {
+        while let Some(origin) = self.origin {
+            // 0xFF in place of a table_id marks stuffing up to the end of the packet
+            if self.data.first().is_none_or(|&table_id| table_id == 0xFF) {
+                self.data.clear();
+                self.origin = None;
+                break;
+            }
+            if self.data.len() < 3 {
+                break;
+            }
+            let length = 3 + ((((self.data[1] & 0x0F) as usize) << 8) | self.data[2] as usize);
+            if self.data.len() < length {
+                break;
+            }
+            complete.push((origin, self.data.drain(..length).collect()));
+        }
+    }
+}
+
+/// Incremental SCTE-35 scanner for MPEG-2 transport streams.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::ts::{TsEvent, TsScanner};
+///
+/// // A time_signal() section carried in a single packet on PID 0x1F4
+/// let section = [
+///     0xFC, 0x30, 0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xF0, 0x05, 0x06,
+///     0xFE, 0x42, 0x3A, 0x35, 0xBD, 0x00, 0x00, 0xBB, 0x0C, 0x73, 0xF4,
+/// ];
+/// let mut packet = vec![0x47, 0x41, 0xF4, 0x10, 0x00];
+/// packet.extend_from_slice(&section);
+/// packet.resize(188, 0xFF);
+///
+/// let mut scanner = TsScanner::new();
+/// scanner.add_scte35_pid(0x1F4);
+/// let events = scanner.push(&packet);
+///
+/// let TsEvent::Cue(cue) = &events[0] else { panic!("expected a cue") };
+/// assert_eq!(cue.pid, 0x1F4);
+/// assert_eq!(cue.section, section);
+/// assert_eq!(cue.parse().unwrap().splice_command_type, 0x06);
+/// ```
+#[derive(Default)]
+pub struct TsScanner {
+    /// Bytes received but not yet processed
+    pending: Vec<u8>,
+    /// Byte offset of `pending[0]`
+    offset: u64,
+    packets: u64,
+    /// Set while searching for the next sync byte
+    out_of_sync: bool,
+    pmt_pids: BTreeSet<u16>,
+    scte35_pids: BTreeSet<u16>,
+    video_pids: BTreeSet<u16>,
+    continuity: HashMap<u16, u8>,
+    sections: HashMap<u16, SectionBuffer>,
+    video_pts: Option<Pts>,
+    pcr: Option<u64>,
+}
+
+impl TsScanner {
+    /// Creates a scanner positioned at the start of a stream.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treats `pid` as carrying SCTE-35 sections even if no PMT lists it.
+    pub fn add_scte35_pid(&mut self, pid: u16) {
+        self.scte35_pids.insert(pid);
+    }
+
+    /// Returns the SCTE-35 PIDs known so far, in ascending order.
+    pub fn scte35_pids(&self) -> impl Iterator<Item = u16> + '_ {
+        self.scte35_pids.iter().copied()
+    }
+
+    /// Returns the number of packets processed so far.
+    pub fn packets(&self) -> u64 {
+        self.packets
+    }
+
+    /// Feeds the next chunk of the stream, returning what was found in it.
+    ///
+    /// Chunks need not be aligned to packet boundaries; a partial packet at the end is
+    /// kept until the next call.
+    pub fn push(&mut self, data: &[u8]) -> Vec<TsEvent> {
+        let mut events = Vec::new();
+        let mut pending = std::mem::take(&mut self.pending);
+        pending.extend_from_slice(data);
+
+        let mut start = 0;
+        while pending.len() - start >= PACKET_SIZE {
+            if pending[start] != SYNC_BYTE {
+                if !self.out_of_sync {
+                    events.push(TsEvent::SyncLost {
+                        offset: self.offset,
+                    });
+                    self.out_of_sync = true;
+                }
+                start += 1;
+                self.offset += 1;
+                continue;
+            }
+            self.out_of_sync = false;
+            self.handle_packet(&pending[start..start + PACKET_SIZE], &mut events);
+            start += PACKET_SIZE;
+            self.offset += PACKET_SIZE as u64;
+            self.packets += 1;
+        }
+
+        pending.drain(..start);
+        self.pending = pending;
+        events
+    }
+
+    fn handle_packet(&mut self, packet: &[u8], events: &mut Vec<TsEvent>) {
+        let transport_error = packet[1] & 0x80 != 0;
+        let unit_start = packet[1] & 0x40 != 0;
+        let pid = (((packet[1] & 0x1F) as u16) << 8) | packet[2] as u16;
+        let adaptation_field_control = (packet[3] >> 4) & 0x03;
+        let continuity_counter = packet[3] & 0x0F;
+        if transport_error || pid == NULL_PID {
+            return;
+        }
+
+        let mut payload_start = 4;
+        let mut discontinuity = false;
+        if adaptation_field_control & 0x02 != 0 {
+            let length = packet[4] as usize;
+            if length > 0 {
+                let flags = packet[5];
+                discontinuity = flags & 0x80 != 0;
+                if flags & 0x10 != 0 && length >= 7 {
+                    self.pcr = Some(read_pcr(&packet[6..12]));
+                }
+            }
+            payload_start = 5 + length;
+        }
+        if adaptation_field_control & 0x01 == 0 {
+            return;
+        }
+
+        // The counter only advances on packets with payload; a single repeat is a
+        // permitted duplicate whose payload is discarded
+        if let Some(previous) = self.continuity.insert(pid, continuity_counter)
+            && !discontinuity
+        {
+            if continuity_counter == previous {
+                return;
+            }
+            let expected = (previous + 1) & 0x0F;
+            if continuity_counter != expected {
+                events.push(TsEvent::ContinuityError {
+                    packet: self.packets,
+                    offset: self.offset,
+                    pid,
+                    expected,
+                    found: continuity_counter,
+                });
+                // Whatever section was in progress is now missing data
+                if let Some(buffer) = self.sections.get_mut(&pid) {
+                    buffer.data.clear();
+                    buffer.origin = None;
+                }
+            }
+        }
+
+        let Some(payload) = packet.get(payload_start..).filter(|p| !p.is_empty()) else {
+            return;
+        };
+
+        if self.video_pids.contains(&pid) {
+            if unit_start && let Some(pts) = read_pes_pts(payload) {
+                self.video_pts = Some(pts);
+            }
+        } else if pid == PAT_PID || self.pmt_pids.contains(&pid) || self.scte35_pids.contains(&pid)
+        {
+            for (origin, section) in self.reassemble(pid, payload, unit_start) {
+                match section[0] {
+                    0x00 if pid == PAT_PID => self.read_pat(&section),
+                    0x02 if self.pmt_pids.contains(&pid) => self.read_pmt(&section),
+                    0xFC if self.scte35_pids.contains(&pid) => events.push(TsEvent::Cue(TsCue {
+                        packet: origin.packet,
+                        offset: origin.offset,
+                        pid,
+                        video_pts: origin.video_pts,
+                        pcr: origin.pcr,
+                        section,
+                    })),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Adds a packet payload to the section buffer of `pid` and returns the sections it
+    /// completes.
+    fn reassemble(&mut self, pid: u16, payload: &[u8], unit_start: bool) -> Vec<(Origin, Vec<u8>)> {
+        let origin = Origin {
+            packet: self.packets,
+            offset: self.offset,
+            video_pts: self.video_pts,
+            pcr: self.pcr,
+        };
+        let buffer = self.sections.entry(pid).or_default();
+        let mut complete = Vec::new();
+
+        if unit_start {
+            let Some((&pointer_field, rest)) = payload.split_first() else {
+                return complete;
+            };
+            let pointer_field = (pointer_field as usize).min(rest.len());
+            // Bytes before the pointer target finish the section started earlier
+            if buffer.origin.is_some() {
+                buffer.data.extend_from_slice(&rest[..pointer_field]);
+                buffer.take_complete(&mut complete);
+            }
+            buffer.data.clear();
+            buffer.data.extend_from_slice(&rest[pointer_field..]);
+            buffer.origin = Some(origin);
+        } else if buffer.origin.is_some() {
+            buffer.data.extend_from_slice(payload);
+        }
+        buffer.take_complete(&mut complete);
+        complete
+    }
+
+    /// Records the PMT PIDs listed in a program association section.
+    fn read_pat(&mut self, section: &[u8]) {
+        let end = section.len().saturating_sub(4);
+        for program in section.get(8..end).unwrap_or_default().chunks_exact(4) {
+            let program_number = u16::from_be_bytes([program[0], program[1]]);
+            if program_number != 0 {
+                self.pmt_pids
+                    .insert((((program[2] & 0x1F) as u16) << 8) | program[3] as u16);
+            }
+        }
+    }
+
+    /// Records the SCTE-35 and video PIDs listed in a program map section.
+    fn read_pmt(&mut self, section: &[u8]) {
+        if section.len() < 16 {
+            return;
+        }
+        let end = section.len() - 4;
+        let program_info_length = (((section[10] & 0x0F) as usize) << 8) | section[11] as usize;
+        let mut index = 12 + program_info_length;
+        while index + 5 <= end {
+            let stream_type = section[index];
+            let pid = (((section[index + 1] & 0x1F) as u16) << 8) | section[index + 2] as u16;
+            let es_info_length =
+                (((section[index + 3] & 0x0F) as usize) << 8) | section[index + 4] as usize;
+            if stream_type == SCTE35_STREAM_TYPE {
+                self.scte35_pids.insert(pid);
+            } else if VIDEO_STREAM_TYPES.contains(&stream_type) {
+                self.video_pids.insert(pid);
+            }
+            index += 5 + es_info_length;
+        }
+    }
+}
+
+/// Reads a 27MHz PCR from the six bytes following the adaptation field flags.
+fn read_pcr(bytes: &[u8]) -> u64 {
+    let base = ((bytes[0] as u64) << 25)
+        | ((bytes[1] as u64) << 17)
+        | ((bytes[2] as u64) << 9)
+        | ((bytes[3] as u64) << 1)
+        | ((bytes[4] as u64) >> 7);
+    let extension = (((bytes[4] & 0x01) as u64) << 8) | bytes[5] as u64;
+    base * 300 + extension
+}
+
+/// Reads the PTS from the header of a PES packet, if it carries one.
+fn read_pes_pts(payload: &[u8]) -> Option<Pts> {
+    if payload.len() < 14 || payload[..3] != [0x00, 0x00, 0x01] || payload[7] & 0x80 == 0 {
+        return None;
+    }
+    let bytes = &payload[9..14];
+    Some(Pts::new(
+        (((bytes[0] >> 1) & 0x07) as u64) << 30
+            | (bytes[1] as u64) << 22
+            | ((bytes[2] >> 1) as u64) << 15
+            | (bytes[3] as u64) << 7
+            | (bytes[4] >> 1) as u64,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCTE35_PID: u16 = 0x1F4;
+    const VIDEO_PID: u16 = 0x100;
+    const PMT_PID: u16 = 0x1000;
+
+    /// time_signal() at PTS 0x423A35BD
+    const SECTION: [u8; 25] = [
+        0xFC, 0x30, 0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xF0, 0x05, 0x06, 0xFE,
+        0x42, 0x3A, 0x35, 0xBD, 0x00, 0x00, 0xBB, 0x0C, 0x73, 0xF4,
+    ];
+
+    fn packet(pid: u16, unit_start: bool, counter: u8, payload: &[u8]) -> Vec<u8> {
+        let mut packet = vec![
+            SYNC_BYTE,
+            ((unit_start as u8) << 6) | (pid >> 8) as u8,
+            pid as u8,
+            0x10 | counter,
+        ];
+        packet.extend_from_slice(payload);
+        packet.resize(PACKET_SIZE, 0xFF);
+        packet
+    }
+
+    fn psi(pid: u16, section: &[u8]) -> Vec<u8> {
+        let mut payload = vec![0x00];
+        payload.extend_from_slice(section);
+        packet(pid, true, 0, &payload)
+    }
+
+    fn pat() -> Vec<u8> {
+        // CRCs of PSI sections are not checked by the scanner
+        let mut section = vec![0x00, 0xB0, 0x0D, 0x00, 0x01, 0xC1, 0x00, 0x00, 0x00, 0x01];
+        section.extend_from_slice(&[0xE0 | (PMT_PID >> 8) as u8, PMT_PID as u8]);
+        section.extend_from_slice(&[0; 4]);
+        psi(PAT_PID, &section)
+    }
+
+    fn pmt() -> Vec<u8> {
+        let mut section = vec![
+            0x02, 0xB0, 0x17, 0x00, 0x01, 0xC1, 0x00, 0x00, 0xE1, 0x00, 0xF0,
+        ];
+        section.push(0x00);
+        section.extend_from_slice(&[0x1B, 0xE1, 0x00, 0xF0, 0x00]);
+        section.extend_from_slice(&[0x86, 0xE1, 0xF4, 0xF0, 0x00]);
+        section.extend_from_slice(&[0; 4]);
+        psi(PMT_PID, &section)
+    }
+
+    fn video(counter: u8, pts: u64) -> Vec<u8> {
+        let mut pes = vec![0x00, 0x00, 0x01, 0xE0, 0x00, 0x00, 0x80, 0x80, 0x05];
+        pes.extend_from_slice(&[
+            0x21 | ((pts >> 29) & 0x0E) as u8,
+            (pts >> 22) as u8,
+            0x01 | ((pts >> 14) & 0xFE) as u8,
+            (pts >> 7) as u8,
+            0x01 | ((pts << 1) & 0xFE) as u8,
+        ]);
+        packet(VIDEO_PID, true, counter, &pes)
+    }
+
+    fn cues(events: &[TsEvent]) -> Vec<&TsCue> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                TsEvent::Cue(cue) => Some(cue),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_discovers_pids_from_pmt() {
+        let mut stream = [pat(), pmt(), video(0, 900_000)].concat();
+        let mut payload = vec![0x00];
+        payload.extend_from_slice(&SECTION);
+        stream.extend(packet(SCTE35_PID, true, 0, &payload));
+
+        let mut scanner = TsScanner::new();
+        // Odd chunk sizes exercise the partial-packet buffering
+        let events: Vec<TsEvent> = stream
+            .chunks(100)
+            .flat_map(|chunk| scanner.push(chunk))
+            .collect();
+
+        assert_eq!(scanner.packets(), 4);
+        assert_eq!(scanner.scte35_pids().collect::<Vec<_>>(), vec![SCTE35_PID]);
+        let cues = cues(&events);
+        assert_eq!(cues.len(), 1);
+        assert_eq!(cues[0].packet, 3);
+        assert_eq!(cues[0].offset, 3 * PACKET_SIZE as u64);
+        assert_eq!(cues[0].video_pts, Some(Pts::new(900_000)));
+        assert_eq!(cues[0].section, SECTION);
+    }
+
+    #[test]
+    fn test_section_spanning_packets() {
+        // The section starts at the end of one packet and finishes in the next, which then
+        // starts a second copy after its pointer field
+        let mut payload = vec![0xFF; PACKET_SIZE - 4];
+        let tail = payload.len() - 3;
+        payload[0] = (tail - 1) as u8;
+        payload[tail..].copy_from_slice(&SECTION[..3]);
+        let first_packet = packet(SCTE35_PID, true, 0, &payload);
+        let mut second = vec![(SECTION.len() - 3) as u8];
+        second.extend_from_slice(&SECTION[3..]);
+        second.extend_from_slice(&SECTION);
+
+        let mut scanner = TsScanner::new();
+        scanner.add_scte35_pid(SCTE35_PID);
+        let mut events = scanner.push(&first_packet);
+        events.extend(scanner.push(&packet(SCTE35_PID, true, 1, &second)));
+
+        let cues = cues(&events);
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].packet, 0);
+        assert_eq!(cues[0].section, SECTION);
+        assert_eq!(cues[1].packet, 1);
+        assert_eq!(cues[1].section, SECTION);
+    }
+
+    #[test]
+    fn test_continuity_and_sync_errors() {
+        let mut scanner = TsScanner::new();
+        let mut events = scanner.push(&[video(0, 0), video(1, 0), video(3, 0)].concat());
+        events.extend(scanner.push(&[0x00; 10]));
+        events.extend(scanner.push(&video(4, 0)));
+
+        assert_eq!(
+            events,
+            vec![
+                TsEvent::ContinuityError {
+                    packet: 2,
+                    offset: 2 * PACKET_SIZE as u64,
+                    pid: VIDEO_PID,
+                    expected: 2,
+                    found: 3,
+                },
+                TsEvent::SyncLost {
+                    offset: 3 * PACKET_SIZE as u64,
+                },
+            ]
+        );
+        assert_eq!(scanner.packets(), 4);
+    }
+
+    #[test]
+    fn test_adaptation_field_pcr() {
+        // Adaptation field with PCR (base 1, extension 2) followed by the section
+        let mut stuffed = vec![SYNC_BYTE, 0x41, 0xF4, 0x30, 0x07, 0x10];
+        stuffed.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x80, 0x02]);
+        stuffed.push(0x00);
+        stuffed.extend_from_slice(&SECTION);
+        stuffed.resize(PACKET_SIZE, 0xFF);
+        // Adaptation field only: updates the PCR without a payload
+        let mut pcr_only = vec![SYNC_BYTE, 0x01, 0x00, 0x20, 0xB7, 0x10];
+        pcr_only.extend_from_slice(&[0x00, 0x00, 0x00, 0x01, 0x00, 0x00]);
+        pcr_only.resize(PACKET_SIZE, 0xFF);
+
+        let mut scanner = TsScanner::new();
+        scanner.add_scte35_pid(SCTE35_PID);
+        let mut events = scanner.push(&stuffed);
+        events.extend(scanner.push(&pcr_only));
+        let mut payload = vec![0x00];
+        payload.extend_from_slice(&SECTION);
+        events.extend(scanner.push(&packet(SCTE35_PID, true, 1, &payload)));
+
+        let cues = cues(&events);
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].pcr, Some(302));
+        assert_eq!(cues[0].section, SECTION);
+        assert_eq!(cues[1].pcr, Some(600));
+    }
+
+    #[test]
+    fn test_duplicate_packet_is_discarded() {
+        let mut payload = vec![0x00];
+        payload.extend_from_slice(&SECTION);
+        let cue = packet(SCTE35_PID, true, 5, &payload);
+
+        let mut scanner = TsScanner::new();
+        scanner.add_scte35_pid(SCTE35_PID);
+        let events = scanner.push(&[cue.clone(), cue].concat());
+
+        // The repeat is neither a second cue nor a continuity error
+        assert_eq!(events.len(), 1);
+        assert_eq!(cues(&events)[0].packet, 0);
+    }
+
+    #[test]
+    fn test_pointer_field_and_stuffing() {
+        // A pointer field skipping junk left from an unknown section, two sections back to
+        // back, then stuffing to the end of the packet
+        let mut payload = vec![0x03, 0xAA, 0xBB, 0xCC];
+        payload.extend_from_slice(&SECTION);
+        payload.extend_from_slice(&SECTION);
+
+        let mut scanner = TsScanner::new();
+        scanner.add_scte35_pid(SCTE35_PID);
+        let mut events = scanner.push(&packet(SCTE35_PID, true, 0, &payload));
+        // The stuffing ended the buffered data, so a continuation is ignored
+        events.extend(scanner.push(&packet(SCTE35_PID, false, 1, &SECTION)));
+
+        let cues = cues(&events);
+        assert_eq!(cues.len(), 2);
+        assert!(cues.iter().all(|cue| cue.section == SECTION));
+    }
+
+    #[test]
+    fn test_read_pcr() {
+        // base 1, extension 2
+        assert_eq!(read_pcr(&[0x00, 0x00, 0x00, 0x00, 0x80, 0x02]), 302);
+    }
+}
@@ -4,8 +4,9 @@
 //! commands, and related components.
 
 use crate::descriptors::SpliceDescriptor;
-use crate::time::{BreakDuration, SpliceTime};
+use crate::time::{BreakDuration, EffectiveSplicePts, LeapSeconds, Pts, SpliceTime};
 use std::fmt;
+use std::time::SystemTime;
 
 /// Represents a complete SCTE-35 splice information section.
 ///
@@ -69,6 +70,203 @@ pub struct SpliceInfoSection {
     pub crc_32: u32,
 }
 
+impl SpliceInfoSection {
+    /// Returns the splice times carried by the command with `pts_adjustment` applied.
+    ///
+    /// SCTE-35 requires `pts_adjustment` to be added to every `pts_time` in the message
+    /// (modulo 2^33) before the time is used. This method does that for whichever
+    /// times are present:
+    ///
+    /// - `time_signal()` - the single signalled time
+    /// - `splice_insert()` - the program splice time, or one entry per component
+    ///
+    /// Immediate splices and commands without PTS times yield an empty list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use scte35::Pts;
+    /// use scte35::builders::{SpliceInfoSectionBuilder, TimeSignalBuilder};
+    /// use std::time::Duration;
+    ///
+    /// let time_signal = TimeSignalBuilder::new()
+    ///     .at_pts(Duration::from_secs(10))?
+    ///     .build()?;
+    /// let section = SpliceInfoSectionBuilder::new()
+    ///     .pts_adjustment(0x1_FFFF_FFFF) // -1 tick modulo 2^33
+    ///     .time_signal(time_signal)
+    ///     .build()?;
+    ///
+    /// let times = section.effective_splice_pts();
+    /// assert_eq!(times.len(), 1);
+    /// assert_eq!(times[0].component_tag, None);
+    /// assert_eq!(times[0].pts, Pts::new(899_999));
+    /// # Ok::<(), scte35::builders::BuilderError>(())
+    /// ```
+    pub fn effective_splice_pts(&self) -> Vec<EffectiveSplicePts> {
+        let adjust = |splice_time: &SpliceTime| {
+            splice_time
+                .pts()
+                .map(|pts| pts.wrapping_add(self.pts_adjustment))
+        };
+
+        match &self.splice_command {
+            SpliceCommand::TimeSignal(time_signal) => adjust(&time_signal.splice_time)
+                .map(|pts| EffectiveSplicePts {
+                    component_tag: None,
+                    pts,
+                })
+                .into_iter()
+                .collect(),
+            SpliceCommand::SpliceInsert(insert) => {
+                if insert.program_splice_flag == 1 {
+                    insert
+                        .splice_time
+                        .as_ref()
+                        .and_then(adjust)
+                        .map(|pts| EffectiveSplicePts {
+                            component_tag: None,
+                            pts,
+                        })
+                        .into_iter()
+                        .collect()
+                } else {
+                    insert
+                        .components
+                        .iter()
+                        .filter_map(|component| {
+                            component.splice_time.as_ref().and_then(adjust).map(|pts| {
+                                EffectiveSplicePts {
+                                    component_tag: Some(component.component_tag),
+                                    pts,
+                                }
+                            })
+                        })
+                        .collect()
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the program-level splice time with `pts_adjustment` applied, if any.
+    ///
+    /// This is a convenience wrapper around [`effective_splice_pts`](Self::effective_splice_pts)
+    /// for the common case of program-level `time_signal()` and `splice_insert()` commands.
+    pub fn effective_program_pts(&self) -> Option<Pts> {
+        self.effective_splice_pts()
+            .into_iter()
+            .find(|point| point.component_tag.is_none())
+            .map(|point| point.pts)
+    }
+}
+
+/// Formats the section as a one-line, human-readable summary.
+///
+/// The line names the command, the program splice time in seconds (with
+/// `pts_adjustment` applied) and each segmentation event, e.g.
+/// `time_signal @ 1234.567s: Provider Placement Opportunity Start, event 0x4800008e, dur 30.0s, UPID AdID ABCD0001000H`.
+/// Splice insert details come first; segmentation events are separated by `"; "`.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::builders::*;
+/// use scte35::SegmentationType;
+/// use std::time::Duration;
+///
+/// let descriptor = SegmentationDescriptorBuilder::new(
+///     0x4800008e,
+///     SegmentationType::ProviderPlacementOpportunityStart,
+/// )
+/// .duration(Duration::from_secs(30))?
+/// .upid(Upid::AdId("ABCD0001000H".to_string()))?
+/// .build()?;
+/// let section = SpliceInfoSectionBuilder::new()
+///     .time_signal(TimeSignalBuilder::new().at_pts(Duration::from_millis(1_234_567))?.build()?)
+///     .add_segmentation_descriptor(descriptor)
+///     .build()?;
+///
+/// assert_eq!(
+///     section.to_string(),
+///     "time_signal @ 1234.567s: Provider Placement Opportunity Start, event 0x4800008e, \
+///      dur 30.0s, UPID AdID ABCD0001000H"
+/// );
+/// # Ok::<(), BuilderError>(())
+/// ```
+impl fmt::Display for SpliceInfoSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.splice_command.snake_case_name())?;
+        if let Some(pts) = self.effective_program_pts() {
+            write!(f, " @ {:.3}s", pts.to_duration().as_secs_f64())?;
+        } else if let SpliceCommand::SpliceInsert(insert) = &self.splice_command
+            && insert.splice_immediate_flag == 1
+        {
+            write!(f, " @ immediate")?;
+        }
+
+        let mut groups = Vec::new();
+        if let SpliceCommand::SpliceInsert(insert) = &self.splice_command {
+            let mut parts = Vec::new();
+            if insert.splice_event_cancel_indicator == 1 {
+                parts.push("cancel".to_string());
+            } else {
+                parts.push(
+                    if insert.out_of_network_indicator == 1 {
+                        "out"
+                    } else {
+                        "in"
+                    }
+                    .to_string(),
+                );
+            }
+            parts.push(format!("event 0x{:08x}", insert.splice_event_id));
+            if let Some(duration) = &insert.break_duration {
+                parts.push(format!("dur {:.1}s", duration.to_duration().as_secs_f64()));
+            }
+            groups.push(parts.join(", "));
+        }
+        for descriptor in &self.splice_descriptors {
+            let SpliceDescriptor::Segmentation(segmentation) = descriptor else {
+                continue;
+            };
+            let mut parts = vec![
+                segmentation.segmentation_type.to_string(),
+                format!("event 0x{:08x}", segmentation.segmentation_event_id),
+            ];
+            if segmentation.segmentation_event_cancel_indicator {
+                parts.push("cancel".to_string());
+            }
+            if let Some(duration) = segmentation.duration() {
+                parts.push(format!("dur {:.1}s", duration.as_secs_f64()));
+            }
+            if let Some(members) = segmentation.mid_upids() {
+                let members: Vec<String> = members.iter().map(ToString::to_string).collect();
+                parts.push(format!("UPID MID [{}]", members.join(", ")));
+            } else if !segmentation.segmentation_upid.is_empty() {
+                let value = segmentation.upid_as_string().unwrap_or_else(|| {
+                    let hex: String = segmentation
+                        .segmentation_upid
+                        .iter()
+                        .map(|byte| format!("{byte:02x}"))
+                        .collect();
+                    format!("0x{hex}")
+                });
+                parts.push(format!(
+                    "UPID {} {value}",
+                    segmentation.segmentation_upid_type.short_name()
+                ));
+            }
+            groups.push(parts.join(", "));
+        }
+
+        if !groups.is_empty() {
+            write!(f, ": {}", groups.join("; "))?;
+        }
+        Ok(())
+    }
+}
+
 /// Represents the different types of splice commands defined in SCTE-35.
 ///
 /// Each variant contains the specific data structure for that command type.
@@ -93,6 +291,34 @@ pub enum SpliceCommand {
     Unknown,
 }
 
+impl SpliceCommand {
+    /// Returns the command name as used in the specification, e.g. `"TimeSignal"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpliceCommand::SpliceNull => "SpliceNull",
+            SpliceCommand::SpliceSchedule(_) => "SpliceSchedule",
+            SpliceCommand::SpliceInsert(_) => "SpliceInsert",
+            SpliceCommand::TimeSignal(_) => "TimeSignal",
+            SpliceCommand::BandwidthReservation(_) => "BandwidthReservation",
+            SpliceCommand::PrivateCommand(_) => "PrivateCommand",
+            SpliceCommand::Unknown => "Unknown",
+        }
+    }
+
+    /// Returns the command name in the specification's snake_case form, e.g. `"time_signal"`.
+    pub fn snake_case_name(&self) -> &'static str {
+        match self {
+            SpliceCommand::SpliceNull => "splice_null",
+            SpliceCommand::SpliceSchedule(_) => "splice_schedule",
+            SpliceCommand::SpliceInsert(_) => "splice_insert",
+            SpliceCommand::TimeSignal(_) => "time_signal",
+            SpliceCommand::BandwidthReservation(_) => "bandwidth_reservation",
+            SpliceCommand::PrivateCommand(_) => "private_command",
+            SpliceCommand::Unknown => "unknown",
+        }
+    }
+}
+
 /// Represents a splice null command.
 ///
 /// This command indicates no splice operation should be performed.
@@ -108,6 +334,16 @@ pub struct SpliceNull {}
 #[derive(Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpliceSchedule {
+    /// Number of splice events in the schedule
+    pub splice_count: u8,
+    /// The scheduled splice events
+    pub events: Vec<SpliceScheduleEvent>,
+}
+
+/// Represents one event of a splice schedule command.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpliceScheduleEvent {
     /// Unique identifier for this splice event
     pub splice_event_id: u32,
     /// Indicates if the splice event is being cancelled (1 = cancel, 0 = proceed)
@@ -116,18 +352,39 @@ pub struct SpliceSchedule {
     pub reserved: u8,
     /// Indicates whether the splice is going out of or returning to the network (1 = out, 0 = in)
     pub out_of_network_indicator: u8,
-    /// Indicates whether a duration is specified (1 = duration present, 0 = no duration)
+    /// Indicates if this is a program-level splice (1) or component-level splice (0)
+    pub program_splice_flag: u8,
+    /// Indicates whether a break duration is specified (1 = duration present, 0 = no duration)
     pub duration_flag: u8,
-    /// Duration of the splice in 90kHz ticks (present when duration_flag = 1)
-    pub splice_duration: Option<u32>,
-    /// UTC time for the splice to occur in seconds since epoch (present when program_splice_flag = 1)
+    /// Additional reserved bits
+    pub reserved2: u8,
+    /// Splice time in seconds since the GPS epoch, 1980-01-06T00:00:00Z (present when program_splice_flag = 1)
+    ///
+    /// Use [`SpliceScheduleEvent::splice_system_time`] to convert to UTC.
     pub utc_splice_time: Option<u32>,
+    /// Number of components in the component list (present when program_splice_flag = 0)
+    pub component_count: u8,
+    /// Component-specific splice times (present when program_splice_flag = 0)
+    pub components: Vec<ComponentSplice>,
+    /// Duration of the break (present when duration_flag = 1)
+    pub break_duration: Option<BreakDuration>,
     /// Unique identifier for the program
     pub unique_program_id: u16,
-    /// Number of components in the component list
-    pub num_splice: u8,
-    /// List of component-specific splice information
-    pub component_list: Vec<ComponentSplice>,
+    /// Identification for a specific avail within one unique_program_id
+    pub avail_num: u8,
+    /// Expected number of individual avails within the current viewing event
+    pub avails_expected: u8,
+}
+
+impl SpliceScheduleEvent {
+    /// Returns the program splice time as UTC.
+    ///
+    /// `utc_splice_time` counts seconds since the GPS epoch, so the GPS-UTC offset from
+    /// `leap_seconds` is removed. Pass [`LeapSeconds::default()`] for the built-in table.
+    pub fn splice_system_time(&self, leap_seconds: &LeapSeconds) -> Option<SystemTime> {
+        self.utc_splice_time
+            .map(|gps_seconds| leap_seconds.gps_to_utc(gps_seconds))
+    }
 }
 
 /// Represents a splice insert command (0x05).
@@ -197,8 +454,9 @@ pub struct BandwidthReservation {
 ///
 /// This command allows for custom, proprietary splice operations
 /// that are not defined in the standard SCTE-35 specification.
+/// Typed payloads can be decoded with decoders registered in [`crate::registry`].
 #[derive(Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct PrivateCommand {
     /// Identifier for the private command type
     pub private_command_id: u16,
@@ -215,25 +473,38 @@ pub struct PrivateCommand {
     pub private_bytes: Vec<u8>,
 }
 
+impl PrivateCommand {
+    /// Decodes the private bytes with the decoder registered for the `private_command_id`.
+    ///
+    /// See [`register_private_command`](crate::registry::register_private_command).
+    /// Returns `None` if no decoder is registered or the decoder rejects the data.
+    pub fn payload(&self) -> Option<Box<dyn crate::registry::PrivateCommandPayload>> {
+        crate::registry::decode_private_command(self.private_command_id, &self.private_bytes)
+    }
+}
+
 /// Represents component-specific splice information for splice schedule commands.
 ///
-/// This structure contains timing and mode information for individual components
-/// when performing component-level splicing operations.
+/// This structure contains the splice time of an individual component when a
+/// scheduled event splices components rather than the whole program.
 #[derive(Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComponentSplice {
     /// Identifier for the specific component (audio/video track)
     pub component_tag: u8,
-    /// Reserved bits for future use
-    pub reserved: u8,
-    /// Indicates the splice mode for this component
-    pub splice_mode_indicator: u8,
-    /// Indicates whether a duration is specified (1 = duration present, 0 = scheduled time present)
-    pub duration_flag: u8,
-    /// Duration of the splice for this component in 90kHz ticks (present when duration_flag = 1)
-    pub splice_duration: Option<u32>,
-    /// UTC time for component splice in seconds since epoch (present when duration_flag = 0)
-    pub utc_splice_time: Option<u32>,
+    /// Component splice time in seconds since the GPS epoch, 1980-01-06T00:00:00Z
+    ///
+    /// Use [`ComponentSplice::splice_system_time`] to convert to UTC.
+    pub utc_splice_time: u32,
+}
+
+impl ComponentSplice {
+    /// Returns the component splice time as UTC.
+    ///
+    /// See [`SpliceScheduleEvent::splice_system_time`].
+    pub fn splice_system_time(&self, leap_seconds: &LeapSeconds) -> SystemTime {
+        leap_seconds.gps_to_utc(self.utc_splice_time)
+    }
 }
 
 /// Represents component-specific timing information for splice insert commands.
@@ -506,6 +777,263 @@ impl SegmentationType {
     }
 }
 
+/// Whether a segmentation type opens or closes a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SegmentationBoundary {
+    /// The type marks the beginning of a segment
+    Start,
+    /// The type marks the end of a segment
+    End,
+    /// The type signals a single point with no start/end pairing
+    Point,
+}
+
+/// Broad grouping of segmentation types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SegmentationCategory {
+    /// Not indicated and content identification (0x00-0x01)
+    Informational,
+    /// Program boundaries and events (0x10-0x19)
+    Program,
+    /// Chapters and (deprecated) credits (0x20-0x21, 0x24-0x27)
+    Chapter,
+    /// Breaks (0x22-0x23)
+    Break,
+    /// Advertisements, promos and ad blocks (0x30-0x33, 0x3C-0x3F, 0x44-0x47)
+    Advertisement,
+    /// Placement and alternate content opportunities (0x34-0x3B, 0x42-0x43)
+    PlacementOpportunity,
+    /// Unscheduled events (0x40-0x41)
+    UnscheduledEvent,
+    /// Network boundaries (0x50-0x51)
+    Network,
+}
+
+/// Per-type metadata for a [`SegmentationType`].
+///
+/// Returned by [`SegmentationType::info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentationTypeInfo {
+    /// Whether the type starts or ends a segment
+    pub boundary: SegmentationBoundary,
+    /// The matching end type for a start type, or start type for an end type
+    pub paired_type: Option<SegmentationType>,
+    /// Whether `sub_segment_num` and `sub_segments_expected` are carried
+    pub has_sub_segments: bool,
+    /// Whether a `segmentation_duration` is normally signaled
+    pub duration_expected: bool,
+    /// Whether the type is deprecated by the specification
+    pub deprecated: bool,
+    /// The category the type belongs to
+    pub category: SegmentationCategory,
+}
+
+impl SegmentationType {
+    /// Returns the metadata for this segmentation type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use scte35::SegmentationType;
+    /// use scte35::types::{SegmentationBoundary, SegmentationCategory};
+    ///
+    /// let info = SegmentationType::ProviderPlacementOpportunityStart.info();
+    /// assert_eq!(info.boundary, SegmentationBoundary::Start);
+    /// assert_eq!(
+    ///     info.paired_type,
+    ///     Some(SegmentationType::ProviderPlacementOpportunityEnd)
+    /// );
+    /// assert!(info.has_sub_segments);
+    /// assert!(info.duration_expected);
+    /// assert_eq!(info.category, SegmentationCategory::PlacementOpportunity);
+    /// ```
+    pub fn info(&self) -> SegmentationTypeInfo {
+        use SegmentationBoundary::{End, Point, Start};
+        use SegmentationCategory::*;
+        use SegmentationType::*;
+
+        // (boundary, paired type, sub-segments, category)
+        let (boundary, paired_type, has_sub_segments, category) = match self {
+            NotIndicated => (Point, None, false, Informational),
+            ContentIdentification => (Point, None, false, Informational),
+            ProgramStart => (Start, Some(ProgramEnd), false, Program),
+            ProgramEnd => (End, Some(ProgramStart), false, Program),
+            ProgramEarlyTermination => (End, Some(ProgramStart), false, Program),
+            ProgramBreakaway => (Start, Some(ProgramResumption), false, Program),
+            ProgramResumption => (End, Some(ProgramBreakaway), false, Program),
+            ProgramRunoverPlanned => (Point, None, false, Program),
+            ProgramRunoverUnplanned => (Point, None, false, Program),
+            ProgramOverlapStart => (Start, Some(ProgramEnd), false, Program),
+            ProgramBlackoutOverride => (Point, None, false, Program),
+            ProgramJoin => (Start, Some(ProgramEnd), false, Program),
+            ChapterStart => (Start, Some(ChapterEnd), false, Chapter),
+            ChapterEnd => (End, Some(ChapterStart), false, Chapter),
+            BreakStart => (Start, Some(BreakEnd), false, Break),
+            BreakEnd => (End, Some(BreakStart), false, Break),
+            OpeningCreditStartDeprecated => {
+                (Start, Some(OpeningCreditEndDeprecated), false, Chapter)
+            }
+            OpeningCreditEndDeprecated => (End, Some(OpeningCreditStartDeprecated), false, Chapter),
+            ClosingCreditStartDeprecated => {
+                (Start, Some(ClosingCreditEndDeprecated), false, Chapter)
+            }
+            ClosingCreditEndDeprecated => (End, Some(ClosingCreditStartDeprecated), false, Chapter),
+            ProviderAdvertisementStart => {
+                (Start, Some(ProviderAdvertisementEnd), false, Advertisement)
+            }
+            ProviderAdvertisementEnd => {
+                (End, Some(ProviderAdvertisementStart), false, Advertisement)
+            }
+            DistributorAdvertisementStart => (
+                Start,
+                Some(DistributorAdvertisementEnd),
+                false,
+                Advertisement,
+            ),
+            DistributorAdvertisementEnd => (
+                End,
+                Some(DistributorAdvertisementStart),
+                false,
+                Advertisement,
+            ),
+            ProviderPlacementOpportunityStart => (
+                Start,
+                Some(ProviderPlacementOpportunityEnd),
+                true,
+                PlacementOpportunity,
+            ),
+            ProviderPlacementOpportunityEnd => (
+                End,
+                Some(ProviderPlacementOpportunityStart),
+                false,
+                PlacementOpportunity,
+            ),
+            DistributorPlacementOpportunityStart => (
+                Start,
+                Some(DistributorPlacementOpportunityEnd),
+                true,
+                PlacementOpportunity,
+            ),
+            DistributorPlacementOpportunityEnd => (
+                End,
+                Some(DistributorPlacementOpportunityStart),
+                false,
+                PlacementOpportunity,
+            ),
+            ProviderOverlayPlacementOpportunityStart => (
+                Start,
+                Some(ProviderOverlayPlacementOpportunityEnd),
+                true,
+                PlacementOpportunity,
+            ),
+            ProviderOverlayPlacementOpportunityEnd => (
+                End,
+                Some(ProviderOverlayPlacementOpportunityStart),
+                false,
+                PlacementOpportunity,
+            ),
+            DistributorOverlayPlacementOpportunityStart => (
+                Start,
+                Some(DistributorOverlayPlacementOpportunityEnd),
+                true,
+                PlacementOpportunity,
+            ),
+            DistributorOverlayPlacementOpportunityEnd => (
+                End,
+                Some(DistributorOverlayPlacementOpportunityStart),
+                false,
+                PlacementOpportunity,
+            ),
+            ProviderPromoStart => (Start, Some(ProviderPromoEnd), false, Advertisement),
+            ProviderPromoEnd => (End, Some(ProviderPromoStart), false, Advertisement),
+            DistributorPromoStart => (Start, Some(DistributorPromoEnd), false, Advertisement),
+            DistributorPromoEnd => (End, Some(DistributorPromoStart), false, Advertisement),
+            UnscheduledEventStart => (Start, Some(UnscheduledEventEnd), false, UnscheduledEvent),
+            UnscheduledEventEnd => (End, Some(UnscheduledEventStart), false, UnscheduledEvent),
+            AlternateContentOpportunityStart => (
+                Start,
+                Some(AlternateContentOpportunityEnd),
+                false,
+                PlacementOpportunity,
+            ),
+            AlternateContentOpportunityEnd => (
+                End,
+                Some(AlternateContentOpportunityStart),
+                false,
+                PlacementOpportunity,
+            ),
+            ProviderAdBlockStart => (Start, Some(ProviderAdBlockEnd), true, Advertisement),
+            ProviderAdBlockEnd => (End, Some(ProviderAdBlockStart), false, Advertisement),
+            DistributorAdBlockStart => (Start, Some(DistributorAdBlockEnd), true, Advertisement),
+            DistributorAdBlockEnd => (End, Some(DistributorAdBlockStart), false, Advertisement),
+            NetworkStart => (Start, Some(NetworkEnd), false, Network),
+            NetworkEnd => (End, Some(NetworkStart), false, Network),
+        };
+
+        SegmentationTypeInfo {
+            boundary,
+            paired_type,
+            has_sub_segments,
+            // Segments opened by a start type are expected to announce their length
+            duration_expected: boundary == Start,
+            deprecated: matches!(
+                self,
+                OpeningCreditStartDeprecated
+                    | OpeningCreditEndDeprecated
+                    | ClosingCreditStartDeprecated
+                    | ClosingCreditEndDeprecated
+            ),
+            category,
+        }
+    }
+
+    /// Returns `true` if this type marks the beginning of a segment.
+    pub fn is_start(&self) -> bool {
+        self.info().boundary == SegmentationBoundary::Start
+    }
+
+    /// Returns `true` if this type marks the end of a segment.
+    pub fn is_end(&self) -> bool {
+        self.info().boundary == SegmentationBoundary::End
+    }
+
+    /// Returns the matching end type for a start type, or start type for an end type.
+    ///
+    /// ```rust
+    /// use scte35::SegmentationType;
+    ///
+    /// assert_eq!(
+    ///     SegmentationType::BreakStart.paired_type(),
+    ///     Some(SegmentationType::BreakEnd)
+    /// );
+    /// assert_eq!(SegmentationType::ContentIdentification.paired_type(), None);
+    /// ```
+    pub fn paired_type(&self) -> Option<SegmentationType> {
+        self.info().paired_type
+    }
+
+    /// Returns `true` if descriptors of this type carry `sub_segment_num` and
+    /// `sub_segments_expected`.
+    pub fn has_sub_segments(&self) -> bool {
+        self.info().has_sub_segments
+    }
+
+    /// Returns `true` if a `segmentation_duration` is normally signaled for this type.
+    pub fn duration_expected(&self) -> bool {
+        self.info().duration_expected
+    }
+
+    /// Returns `true` if this type is deprecated by the specification.
+    pub fn is_deprecated(&self) -> bool {
+        self.info().deprecated
+    }
+
+    /// Returns the category this type belongs to.
+    pub fn category(&self) -> SegmentationCategory {
+        self.info().category
+    }
+}
+
 impl fmt::Display for SegmentationType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use SegmentationType::*;
@@ -3,7 +3,9 @@
 //! This module contains types and functions related to UPIDs used in
 //! segmentation descriptors for content identification.
 
+use std::error::Error;
 use std::fmt;
+use std::str::FromStr;
 
 /// Represents the different types of UPIDs (Unique Program Identifiers) used in segmentation descriptors.
 ///
@@ -110,6 +112,42 @@ impl From<u8> for SegmentationUpidType {
     }
 }
 
+impl SegmentationUpidType {
+    /// Returns the short name commonly used for this UPID type, e.g. `"AdID"` or `"EIDR"`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use scte35::upid::SegmentationUpidType;
+    ///
+    /// assert_eq!(SegmentationUpidType::AdID.short_name(), "AdID");
+    /// assert_eq!(SegmentationUpidType::Reserved(0x20).short_name(), "Reserved");
+    /// ```
+    pub fn short_name(&self) -> &'static str {
+        use SegmentationUpidType::*;
+        match self {
+            NotUsed => "None",
+            UserDefinedDeprecated => "UserDefined",
+            ISCI => "ISCI",
+            AdID => "AdID",
+            UMID => "UMID",
+            ISANDeprecated => "ISAN(deprecated)",
+            ISAN => "ISAN",
+            TID => "TID",
+            AiringID => "AiringID",
+            ADI => "ADI",
+            EIDR => "EIDR",
+            ATSCContentIdentifier => "ATSC",
+            MPU => "MPU",
+            MID => "MID",
+            ADSInformation => "ADS",
+            URI => "URI",
+            UUID => "UUID",
+            SCR => "SCR",
+            Reserved(_) => "Reserved",
+        }
+    }
+}
+
 impl fmt::Display for SegmentationUpidType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use SegmentationUpidType::*;
@@ -138,6 +176,382 @@ impl fmt::Display for SegmentationUpidType {
     }
 }
 
+/// Error returned when the text form of a UPID cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpidFormatError {
+    /// The UPID type whose text form was being parsed
+    pub upid_type: SegmentationUpidType,
+    /// A description of why the text is invalid
+    pub reason: String,
+}
+
+impl UpidFormatError {
+    fn new(upid_type: SegmentationUpidType, reason: impl Into<String>) -> Self {
+        Self {
+            upid_type,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for UpidFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid {}: {}",
+            self.upid_type.short_name(),
+            self.reason
+        )
+    }
+}
+
+impl Error for UpidFormatError {}
+
+/// A single UPID carried inside a MID (0x0D) segmentation UPID.
+///
+/// A MID UPID is a sequence of `(upid_type, upid_length, upid)` entries, allowing a
+/// segment to be identified by several schemes at once (for example Ad-ID and EIDR).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MidUpid {
+    /// Type of this member UPID
+    pub upid_type: SegmentationUpidType,
+    /// Raw member UPID bytes
+    pub upid: Vec<u8>,
+}
+
+impl MidUpid {
+    /// Returns the member UPID as a human-readable string, if it has a text form.
+    ///
+    /// See [`format_upid`].
+    pub fn upid_as_string(&self) -> Option<String> {
+        format_upid(self.upid_type, &self.upid)
+    }
+}
+
+impl fmt::Display for MidUpid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.upid_as_string() {
+            Some(value) => write!(f, "{}: {value}", self.upid_type.short_name()),
+            None => write!(f, "{}: (empty)", self.upid_type.short_name()),
+        }
+    }
+}
+
+/// A basic UMID (SMPTE 330M) carried in a UMID (0x04) segmentation UPID.
+///
+/// The 32-byte basic UMID is a 12-byte universal label, a 1-byte length, a 3-byte
+/// instance number and a 16-byte material number. Its canonical text form is eight
+/// dot-separated groups of 8 hex digits, e.g.
+/// `060A2B34.01010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2.D718BFDA`.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::Umid;
+///
+/// let umid: Umid = "060A2B34.01010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2.D718BFDA"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(umid.length, 0x13);
+/// assert_eq!(umid.instance_number, [0x00, 0x00, 0x00]);
+/// assert_eq!(umid.material_number[0], 0xD2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Umid {
+    /// SMPTE universal label identifying the UMID (starts with `06 0A 2B 34`)
+    pub universal_label: [u8; 12],
+    /// Length of the remaining UMID value (0x13 for a basic UMID)
+    pub length: u8,
+    /// Instance number distinguishing copies of the same material
+    pub instance_number: [u8; 3],
+    /// Globally unique material number
+    pub material_number: [u8; 16],
+}
+
+impl Umid {
+    /// Decodes a UMID from its 32-byte binary form.
+    ///
+    /// Returns `None` if the data is not exactly 32 bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 32 {
+            return None;
+        }
+        let mut umid = Umid {
+            universal_label: [0; 12],
+            length: bytes[12],
+            instance_number: [0; 3],
+            material_number: [0; 16],
+        };
+        umid.universal_label.copy_from_slice(&bytes[..12]);
+        umid.instance_number.copy_from_slice(&bytes[13..16]);
+        umid.material_number.copy_from_slice(&bytes[16..]);
+        Some(umid)
+    }
+
+    /// Encodes the UMID into its 32-byte binary form.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[..12].copy_from_slice(&self.universal_label);
+        bytes[12] = self.length;
+        bytes[13..16].copy_from_slice(&self.instance_number);
+        bytes[16..].copy_from_slice(&self.material_number);
+        bytes
+    }
+}
+
+impl fmt::Display for Umid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_umid(&self.to_bytes()))
+    }
+}
+
+impl FromStr for Umid {
+    type Err = UpidFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_umid(s).map(|bytes| Umid::from_bytes(&bytes).expect("parse_umid returns 32 bytes"))
+    }
+}
+
+/// An ATSC Content Identifier (ATSC A/57B) carried in a 0x0B segmentation UPID.
+///
+/// The identifier is a 16-bit transport stream ID, 2 reserved bits, a 5-bit
+/// `end_of_day` hour, a 9-bit `unique_for` day count and a variable-length content ID.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::AtscContentIdentifier;
+///
+/// let id = AtscContentIdentifier::from_bytes(&[0x12, 0x34, 0xC5, 0x60, b'A', b'B'])
+///     .unwrap();
+/// assert_eq!(id.tsid, 0x1234);
+/// assert_eq!(id.end_of_day, 2);
+/// assert_eq!(id.unique_for, 0x160);
+/// assert_eq!(id.content_id, b"AB");
+/// assert_eq!(
+///     id.to_string(),
+///     "ATSC(tsid: 0x1234, end_of_day: 2, unique_for: 352, content_id: \"AB\")"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AtscContentIdentifier {
+    /// Transport stream ID of the originating broadcast
+    pub tsid: u16,
+    /// Hour of the day (0-23, UTC) at which the content ID may be reused, 5 bits
+    pub end_of_day: u8,
+    /// Number of days the content ID remains unique, 9 bits
+    pub unique_for: u16,
+    /// Content identifier bytes
+    pub content_id: Vec<u8>,
+}
+
+impl AtscContentIdentifier {
+    /// Decodes an ATSC Content Identifier from its binary form.
+    ///
+    /// Returns `None` if the data is shorter than the 4-byte fixed header.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 4 {
+            return None;
+        }
+        Some(AtscContentIdentifier {
+            tsid: u16::from_be_bytes([bytes[0], bytes[1]]),
+            end_of_day: (bytes[2] >> 1) & 0x1F,
+            unique_for: (((bytes[2] & 0x01) as u16) << 8) | bytes[3] as u16,
+            content_id: bytes[4..].to_vec(),
+        })
+    }
+
+    /// Encodes the identifier into its binary form, with the reserved bits set.
+    ///
+    /// `end_of_day` and `unique_for` are masked to 5 and 9 bits respectively.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + self.content_id.len());
+        bytes.extend_from_slice(&self.tsid.to_be_bytes());
+        bytes.push(0xC0 | ((self.end_of_day & 0x1F) << 1) | ((self.unique_for >> 8) & 0x01) as u8);
+        bytes.push(self.unique_for as u8);
+        bytes.extend_from_slice(&self.content_id);
+        bytes
+    }
+}
+
+impl fmt::Display for AtscContentIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ATSC(tsid: 0x{:04X}, end_of_day: {}, unique_for: {}, content_id: {})",
+            self.tsid,
+            self.end_of_day,
+            self.unique_for,
+            crate::fmt::format_private_data(&self.content_id)
+        )
+    }
+}
+
+/// Decodes the members of a MID (0x0D) segmentation UPID.
+///
+/// Returns `None` if a member's length runs past the end of the data.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::{SegmentationUpidType, parse_mid};
+///
+/// // Ad-ID "ABCD0001000H" followed by an empty URI
+/// let mut bytes = vec![0x03, 12];
+/// bytes.extend_from_slice(b"ABCD0001000H");
+/// bytes.extend_from_slice(&[0x0F, 0]);
+///
+/// let members = parse_mid(&bytes).unwrap();
+/// assert_eq!(members.len(), 2);
+/// assert_eq!(members[0].upid_type, SegmentationUpidType::AdID);
+/// assert_eq!(members[0].upid, b"ABCD0001000H");
+/// assert_eq!(members[1].upid_type, SegmentationUpidType::URI);
+/// ```
+pub fn parse_mid(bytes: &[u8]) -> Option<Vec<MidUpid>> {
+    let mut members = Vec::new();
+    let mut remaining = bytes;
+    while !remaining.is_empty() {
+        let (&upid_type, rest) = remaining.split_first()?;
+        let (&upid_length, rest) = rest.split_first()?;
+        let upid_length = upid_length as usize;
+        if rest.len() < upid_length {
+            return None;
+        }
+        members.push(MidUpid {
+            upid_type: SegmentationUpidType::from(upid_type),
+            upid: rest[..upid_length].to_vec(),
+        });
+        remaining = &rest[upid_length..];
+    }
+    Some(members)
+}
+
+/// Decodes MPU UPID bytes with the registered decoder for their format identifier.
+///
+/// See [`crate::registry`]. Returns `None` if the bytes are shorter than the 4-byte
+/// format identifier, no decoder is registered or the decoder rejects the data.
+pub fn decode_mpu_upid(bytes: &[u8]) -> Option<Box<dyn crate::registry::MpuPayload>> {
+    let format_identifier = u32::from_be_bytes(bytes.get(..4)?.try_into().ok()?);
+    crate::registry::decode_mpu(format_identifier, &bytes[4..])
+}
+
+/// Formats UPID bytes of the given type as a human-readable string.
+///
+/// Text-based UPIDs are returned as-is, structured types use their canonical form and
+/// other types fall back to base64. Returns `None` for empty data or data that does not
+/// fit the type's format.
+///
+/// TIDs are only returned when they have the 12-character `AA0000000000` structure
+/// checked by [`validate_tid`].
+///
+/// MID UPIDs render each member as `Type: value`.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::{SegmentationUpidType, format_upid};
+///
+/// assert_eq!(
+///     format_upid(SegmentationUpidType::AdID, b"ABCD0001000H"),
+///     Some("ABCD0001000H".to_string())
+/// );
+///
+/// let mut mid = vec![0x03, 12];
+/// mid.extend_from_slice(b"ABCD0001000H");
+/// mid.extend_from_slice(&[0x0F, 11]);
+/// mid.extend_from_slice(b"urn:test:42");
+/// assert_eq!(
+///     format_upid(SegmentationUpidType::MID, &mid),
+///     Some("MID(AdID: ABCD0001000H; URI: urn:test:42)".to_string())
+/// );
+/// ```
+pub fn format_upid(upid_type: SegmentationUpidType, bytes: &[u8]) -> Option<String> {
+    match upid_type {
+        SegmentationUpidType::AiringID => {
+            if bytes.len() == 8 {
+                let mut id = [0u8; 8];
+                id.copy_from_slice(bytes);
+                Some(format_airing_id(u64::from_be_bytes(id)))
+            } else {
+                None
+            }
+        }
+        SegmentationUpidType::URI | SegmentationUpidType::AdID => {
+            std::str::from_utf8(bytes).ok().map(|s| s.to_string())
+        }
+        SegmentationUpidType::TID => std::str::from_utf8(bytes)
+            .ok()
+            .filter(|text| validate_tid(text).is_ok())
+            .map(|text| text.to_string()),
+        SegmentationUpidType::MPU => {
+            // MPU has format identifier (4 bytes) + private data
+            if bytes.len() >= 4 {
+                let format_identifier =
+                    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                let private_data = &bytes[4..];
+
+                // Prefer a registered decoder, otherwise format using our fmt utilities
+                let format_str = crate::fmt::format_identifier_to_string(format_identifier);
+                let data_str = match decode_mpu_upid(bytes) {
+                    Some(payload) => payload.to_string(),
+                    None => crate::fmt::format_private_data(private_data),
+                };
+                Some(format!("MPU(format: {format_str}, data: {data_str})"))
+            } else {
+                None
+            }
+        }
+        SegmentationUpidType::MID => {
+            let members = parse_mid(bytes)?;
+            let rendered: Vec<String> = members.iter().map(|member| member.to_string()).collect();
+            Some(format!("MID({})", rendered.join("; ")))
+        }
+        SegmentationUpidType::UUID => {
+            if bytes.len() == 16 {
+                Some(format_uuid(bytes))
+            } else {
+                None
+            }
+        }
+        SegmentationUpidType::ATSCContentIdentifier => {
+            AtscContentIdentifier::from_bytes(bytes).map(|id| id.to_string())
+        }
+        SegmentationUpidType::UMID => {
+            if bytes.len() == 32 {
+                Some(format_umid(bytes))
+            } else {
+                None
+            }
+        }
+        SegmentationUpidType::EIDR => {
+            if bytes.len() == 12 {
+                Some(format_eidr(bytes))
+            } else {
+                None
+            }
+        }
+        SegmentationUpidType::ISAN => {
+            if bytes.len() == 12 {
+                Some(format_isan(bytes))
+            } else {
+                None
+            }
+        }
+        SegmentationUpidType::ISANDeprecated => {
+            if bytes.len() == 8 {
+                Some(format_isan(bytes))
+            } else {
+                None
+            }
+        }
+        // For other types, return base64 representation for now
+        _ => {
+            if !bytes.is_empty() {
+                Some(format_base64(bytes))
+            } else {
+                None
+            }
+        }
+    }
+}
+
 /// Helper function to format UUID bytes as a standard UUID string.
 pub fn format_uuid(bytes: &[u8]) -> String {
     if bytes.len() != 16 {
@@ -165,28 +579,424 @@ pub fn format_uuid(bytes: &[u8]) -> String {
     )
 }
 
-/// Helper function to format ISAN bytes as an ISAN string.
-pub fn format_isan(bytes: &[u8]) -> String {
-    if bytes.len() >= 12 {
-        // ISAN format: XXXX-XXXX-XXXX-XXXX-XXXX-X (using hex representation)
-        format!(
-            "{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}",
-            bytes[0],
-            bytes[1],
-            bytes[2],
-            bytes[3],
-            bytes[4],
-            bytes[5],
-            bytes[6],
-            bytes[7],
-            bytes[8],
-            bytes[9],
-            bytes[10],
-            bytes[11]
-        )
+/// Characters of the ISO 7064 Mod 37,36 alphabet, indexed by value.
+const MOD_37_36_ALPHABET: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Computes the ISO 7064 Mod 37,36 check character for a string of `0-9A-Z` characters.
+///
+/// Returns `None` if the input contains characters outside that alphabet.
+pub(crate) fn iso7064_mod37_36(data: &str) -> Option<char> {
+    let mut product = 36u32;
+    for c in data.chars() {
+        let value = c.to_digit(36)?;
+        let mut sum = (product + value) % 36;
+        if sum == 0 {
+            sum = 36;
+        }
+        product = (sum * 2) % 37;
+    }
+    let check = (37 - product) % 36;
+    Some(MOD_37_36_ALPHABET[check as usize] as char)
+}
+
+/// Formats EIDR bytes in the canonical `10.<prefix>/XXXX-XXXX-XXXX-XXXX-XXXX-C` form.
+///
+/// The 12-byte binary EIDR is a 16-bit DOI sub-prefix followed by an 80-bit suffix.
+/// The trailing character is the ISO 7064 Mod 37,36 check character over the suffix.
+/// Data that is not 12 bytes long is formatted as base64.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::format_eidr;
+///
+/// let bytes = [0x14, 0x78, 0x77, 0x91, 0x85, 0x34, 0x2C, 0x23, 0x90, 0x30, 0x86, 0x10];
+/// assert_eq!(format_eidr(&bytes), "10.5240/7791-8534-2C23-9030-8610-5");
+/// ```
+pub fn format_eidr(bytes: &[u8]) -> String {
+    if bytes.len() != 12 {
+        return format_base64(bytes);
+    }
+
+    let prefix = u16::from_be_bytes([bytes[0], bytes[1]]);
+    let suffix: String = bytes[2..].iter().map(|b| format!("{b:02X}")).collect();
+    let check = iso7064_mod37_36(&suffix).unwrap_or('?');
+    let groups: Vec<&str> = (0..5).map(|i| &suffix[i * 4..i * 4 + 4]).collect();
+    format!("10.{prefix}/{}-{check}", groups.join("-"))
+}
+
+/// Parses an EIDR in canonical text form into its 12-byte binary representation.
+///
+/// Accepts `10.<prefix>/XXXX-XXXX-XXXX-XXXX-XXXX-C` (hex digits in either case) and
+/// verifies the ISO 7064 Mod 37,36 check character.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::parse_eidr;
+///
+/// let bytes = parse_eidr("10.5240/7791-8534-2C23-9030-8610-5").unwrap();
+/// assert_eq!(&bytes[..2], &[0x14, 0x78]);
+///
+/// // Wrong check character
+/// assert!(parse_eidr("10.5240/7791-8534-2C23-9030-8610-6").is_err());
+/// ```
+pub fn parse_eidr(text: &str) -> Result<[u8; 12], UpidFormatError> {
+    let error = |reason: &str| UpidFormatError::new(SegmentationUpidType::EIDR, reason);
+
+    let text = text.trim();
+    let rest = text
+        .strip_prefix("10.")
+        .ok_or_else(|| error("EIDR must start with the DOI prefix \"10.\""))?;
+    let (prefix, suffix) = rest
+        .split_once('/')
+        .ok_or_else(|| error("EIDR must contain a '/' after the DOI prefix"))?;
+    let prefix: u16 = prefix
+        .parse()
+        .map_err(|_| error("EIDR sub-prefix must be a number between 0 and 65535"))?;
+
+    let parts: Vec<&str> = suffix.split('-').collect();
+    if parts.len() != 6 || parts[..5].iter().any(|group| group.len() != 4) || parts[5].len() != 1 {
+        return Err(error(
+            "EIDR suffix must have the form XXXX-XXXX-XXXX-XXXX-XXXX-C",
+        ));
+    }
+    let digits = parts[..5].concat().to_ascii_uppercase();
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(error("EIDR suffix must contain only hexadecimal digits"));
+    }
+
+    let expected = iso7064_mod37_36(&digits).unwrap_or('?');
+    let check = parts[5].to_ascii_uppercase();
+    if !check.starts_with(expected) {
+        return Err(error(&format!(
+            "check character '{check}' does not match expected '{expected}'"
+        )));
+    }
+
+    let mut bytes = [0u8; 12];
+    bytes[..2].copy_from_slice(&prefix.to_be_bytes());
+    for (i, byte) in bytes[2..].iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16)
+            .map_err(|_| error("EIDR suffix must contain only hexadecimal digits"))?;
+    }
+    Ok(bytes)
+}
+
+/// Formats UMID bytes in the canonical hex-dotted form.
+///
+/// The 32 bytes are rendered as eight dot-separated groups of 8 upper-case hex digits.
+/// Data that is not 32 bytes long is formatted as base64.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::format_umid;
+///
+/// let mut bytes = [0u8; 32];
+/// bytes[..4].copy_from_slice(&[0x06, 0x0A, 0x2B, 0x34]);
+/// bytes[12] = 0x13;
+/// assert_eq!(
+///     format_umid(&bytes),
+///     "060A2B34.00000000.00000000.13000000.00000000.00000000.00000000.00000000"
+/// );
+/// ```
+pub fn format_umid(bytes: &[u8]) -> String {
+    if bytes.len() != 32 {
+        return format_base64(bytes);
+    }
+
+    let groups: Vec<String> = bytes
+        .chunks(4)
+        .map(|chunk| chunk.iter().map(|b| format!("{b:02X}")).collect())
+        .collect();
+    groups.join(".")
+}
+
+/// Parses a UMID in hex-dotted text form into its 32-byte binary representation.
+///
+/// Accepts eight dot-separated groups of 8 hex digits (either case), or the same 64
+/// hex digits without separators.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::parse_umid;
+///
+/// let bytes =
+///     parse_umid("060A2B34.01010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2.D718BFDA")
+///         .unwrap();
+/// assert_eq!(&bytes[..4], &[0x06, 0x0A, 0x2B, 0x34]);
+/// assert!(parse_umid("060A2B34.01010105").is_err());
+/// ```
+pub fn parse_umid(text: &str) -> Result<[u8; 32], UpidFormatError> {
+    let error = |reason: &str| UpidFormatError::new(SegmentationUpidType::UMID, reason);
+
+    let text = text.trim();
+    let digits: String = if text.contains('.') {
+        let groups: Vec<&str> = text.split('.').collect();
+        if groups.len() != 8 || groups.iter().any(|group| group.len() != 8) {
+            return Err(error(
+                "UMID must be eight dot-separated groups of 8 hex digits",
+            ));
+        }
+        groups.concat()
     } else {
-        format_base64(bytes)
+        text.to_string()
+    };
+    if digits.len() != 64 {
+        return Err(error(&format!(
+            "UMID must be 64 hex digits, got {}",
+            digits.len()
+        )));
+    }
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(error("UMID must contain only hexadecimal digits"));
+    }
+
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16)
+            .map_err(|_| error("UMID must contain only hexadecimal digits"))?;
+    }
+    Ok(bytes)
+}
+
+/// Formats ISAN bytes in the human-readable form with check characters.
+///
+/// 12-byte V-ISANs (UPID type 0x06) render as `XXXX-XXXX-XXXX-XXXX-C-XXXX-XXXX-C`
+/// (root, episode, check, version, check) and 8-byte ISANs (deprecated UPID type 0x05)
+/// as `XXXX-XXXX-XXXX-XXXX-C`. The check characters are ISO 7064 Mod 37,36 over the
+/// root and episode, and over the root, episode and version respectively.
+/// Data of any other length is formatted as base64.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::format_isan;
+///
+/// let visan = [0x00, 0x00, 0x00, 0x00, 0xD0, 0x7A, 0x00, 0x90, 0x00, 0x00, 0x00, 0x00];
+/// assert_eq!(format_isan(&visan), "0000-0000-D07A-0090-Q-0000-0000-X");
+/// assert_eq!(format_isan(&visan[..8]), "0000-0000-D07A-0090-Q");
+/// ```
+pub fn format_isan(bytes: &[u8]) -> String {
+    if bytes.len() != 8 && bytes.len() != 12 {
+        return format_base64(bytes);
+    }
+
+    let digits: String = bytes.iter().map(|b| format!("{b:02X}")).collect();
+    let group = |i: usize| &digits[i * 4..i * 4 + 4];
+    let check = |len: usize| iso7064_mod37_36(&digits[..len]).unwrap_or('?');
+
+    let mut formatted = format!(
+        "{}-{}-{}-{}-{}",
+        group(0),
+        group(1),
+        group(2),
+        group(3),
+        check(16)
+    );
+    if bytes.len() == 12 {
+        formatted.push_str(&format!("-{}-{}-{}", group(4), group(5), check(24)));
+    }
+    formatted
+}
+
+/// Parses an ISAN or V-ISAN from its human-readable text form.
+///
+/// Accepts an optional `ISAN ` prefix, four groups of root/episode hex digits and
+/// optionally two groups of version digits. Check characters may be omitted, but are
+/// verified when present. Returns 8 bytes for an ISAN without version and 12 bytes
+/// for a V-ISAN.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::parse_isan;
+///
+/// let visan = parse_isan("ISAN 0000-0000-D07A-0090-Q-0000-0000-X").unwrap();
+/// assert_eq!(visan.len(), 12);
+/// assert_eq!(parse_isan("0000-0000-D07A-0090").unwrap(), visan[..8]);
+///
+/// // Wrong check character
+/// assert!(parse_isan("0000-0000-D07A-0090-A").is_err());
+/// ```
+pub fn parse_isan(text: &str) -> Result<Vec<u8>, UpidFormatError> {
+    let error = |reason: &str| UpidFormatError::new(SegmentationUpidType::ISAN, reason);
+    let invalid_form = || error("ISAN must have the form XXXX-XXXX-XXXX-XXXX[-C][-XXXX-XXXX[-C]]");
+
+    let text = text.trim();
+    let text = text
+        .strip_prefix("ISAN ")
+        .or_else(|| text.strip_prefix("isan "))
+        .unwrap_or(text)
+        .to_ascii_uppercase();
+
+    let mut groups = text.split('-').peekable();
+    let mut digits = String::new();
+    let mut checks = Vec::new();
+    for segment_groups in [4, 2] {
+        if groups.peek().is_none() {
+            break;
+        }
+        for _ in 0..segment_groups {
+            let group = groups.next().ok_or_else(invalid_form)?;
+            if group.len() != 4 {
+                return Err(invalid_form());
+            }
+            digits.push_str(group);
+        }
+        if let Some(check) = groups.next_if(|group| group.len() == 1) {
+            checks.push((digits.len(), check.chars().next().unwrap_or('?')));
+        }
+    }
+    if groups.next().is_some() || digits.len() < 16 {
+        return Err(invalid_form());
+    }
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(error("ISAN must contain only hexadecimal digits"));
+    }
+
+    for (len, check) in checks {
+        let expected = iso7064_mod37_36(&digits[..len]).unwrap_or('?');
+        if check != expected {
+            return Err(error(&format!(
+                "check character '{check}' does not match expected '{expected}'"
+            )));
+        }
+    }
+
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| error("ISAN must contain only hexadecimal digits"))
+}
+
+/// Checks that text is a well-formed Ad-ID code.
+///
+/// The code must be 12 upper-case letters and digits, and its 4-character company prefix
+/// must start with a letter. The format suffix (`H` for HD, `D` for 3D) is not checked.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::validate_ad_id;
+///
+/// assert!(validate_ad_id("ABCD0001000H").is_ok());
+/// assert!(validate_ad_id("1BCD0001000H").is_err());
+/// assert!(validate_ad_id("abcd0001000h").is_err());
+/// ```
+pub fn validate_ad_id(text: &str) -> Result<(), UpidFormatError> {
+    let error = |reason: &str| UpidFormatError::new(SegmentationUpidType::AdID, reason);
+
+    if text.len() != 12 {
+        return Err(error(&format!(
+            "Ad-ID must be 12 characters, got {}",
+            text.len()
+        )));
+    }
+    if !text
+        .bytes()
+        .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+    {
+        return Err(error(
+            "Ad-ID must contain only upper-case letters and digits",
+        ));
+    }
+    if !text.as_bytes()[0].is_ascii_uppercase() {
+        return Err(error("Ad-ID company prefix must start with a letter"));
+    }
+    Ok(())
+}
+
+/// Checks that text is a well-formed ISCI code.
+///
+/// An ISCI code is 8 characters: a 4-letter upper-case company prefix followed by
+/// 4 digits.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::validate_isci;
+///
+/// assert!(validate_isci("ABCD1234").is_ok());
+/// assert!(validate_isci("ABC12345").is_err());
+/// ```
+pub fn validate_isci(text: &str) -> Result<(), UpidFormatError> {
+    let error = |reason: &str| UpidFormatError::new(SegmentationUpidType::ISCI, reason);
+
+    let bytes = text.as_bytes();
+    if bytes.len() != 8 {
+        return Err(error(&format!(
+            "ISCI must be 8 characters, got {}",
+            bytes.len()
+        )));
+    }
+    if !bytes[..4].iter().all(u8::is_ascii_uppercase) {
+        return Err(error("ISCI prefix must be 4 upper-case letters"));
+    }
+    if !bytes[4..].iter().all(u8::is_ascii_digit) {
+        return Err(error("ISCI code must end with 4 digits"));
     }
+    Ok(())
+}
+
+/// Checks that text is a well-formed TID (Tribune Media Systems program identifier).
+///
+/// A TID is 12 characters: a 2-letter upper-case type prefix (e.g. `MV`, `EP`, `SH`,
+/// `SP`) followed by 10 digits.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::validate_tid;
+///
+/// assert!(validate_tid("EP0123456789").is_ok());
+/// assert!(validate_tid("EP012345678X").is_err());
+/// ```
+pub fn validate_tid(text: &str) -> Result<(), UpidFormatError> {
+    let error = |reason: &str| UpidFormatError::new(SegmentationUpidType::TID, reason);
+
+    let bytes = text.as_bytes();
+    if bytes.len() != 12 {
+        return Err(error(&format!(
+            "TID must be 12 characters, got {}",
+            bytes.len()
+        )));
+    }
+    if !bytes[..2].iter().all(u8::is_ascii_uppercase) {
+        return Err(error("TID prefix must be 2 upper-case letters"));
+    }
+    if !bytes[2..].iter().all(u8::is_ascii_digit) {
+        return Err(error("TID must end with 10 digits"));
+    }
+    Ok(())
+}
+
+/// Formats an Airing ID as a zero-padded 64-bit hex integer, e.g. `0x000000002CA0A18A`.
+pub fn format_airing_id(airing_id: u64) -> String {
+    format!("0x{airing_id:016X}")
+}
+
+/// Parses an Airing ID from its hex text form.
+///
+/// Accepts up to 16 hex digits (either case) with an optional `0x` prefix.
+///
+/// # Example
+/// ```rust
+/// use scte35::upid::parse_airing_id;
+///
+/// assert_eq!(parse_airing_id("0x000000002CA0A18A").unwrap(), 0x2CA0A18A);
+/// assert_eq!(parse_airing_id("2ca0a18a").unwrap(), 0x2CA0A18A);
+/// assert!(parse_airing_id("0x1_0000_0000_0000_0000").is_err());
+/// ```
+pub fn parse_airing_id(text: &str) -> Result<u64, UpidFormatError> {
+    let error = |reason: &str| UpidFormatError::new(SegmentationUpidType::AiringID, reason);
+
+    let text = text.trim();
+    let digits = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix("0X"))
+        .unwrap_or(text);
+    if digits.is_empty() || digits.len() > 16 {
+        return Err(error("Airing ID must be 1-16 hex digits"));
+    }
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(error("Airing ID must contain only hexadecimal digits"));
+    }
+    u64::from_str_radix(digits, 16).map_err(|e| error(&e.to_string()))
 }
 
 /// Helper function to format bytes as base64 string, with fallback when base64 feature is disabled.
@@ -253,6 +1063,257 @@ mod tests {
             0x00, 0x00, 0x00, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x00,
         ];
         let formatted = format_isan(&isan_bytes);
-        assert_eq!(formatted, "0000-0001-2345-6789-abcd-ef00");
+        assert_eq!(formatted, "0000-0001-2345-6789-O-ABCD-EF00-O");
+        assert_eq!(format_isan(&isan_bytes[..8]), "0000-0001-2345-6789-O");
+        assert_eq!(
+            format_upid(SegmentationUpidType::ISANDeprecated, &isan_bytes[..8]),
+            Some("0000-0001-2345-6789-O".to_string())
+        );
+        // Each form only renders at its own length
+        assert_eq!(
+            format_upid(SegmentationUpidType::ISANDeprecated, &isan_bytes),
+            None
+        );
+        assert_eq!(
+            format_upid(SegmentationUpidType::ISAN, &isan_bytes[..8]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_atsc_content_identifier() {
+        let bytes = [0xAB, 0xCD, 0xEF, 0xFF, b'x', b'y', b'z'];
+        let id = AtscContentIdentifier::from_bytes(&bytes).unwrap();
+        assert_eq!(id.tsid, 0xABCD);
+        assert_eq!(id.end_of_day, 23);
+        assert_eq!(id.unique_for, 511);
+        assert_eq!(id.content_id, b"xyz");
+        assert_eq!(id.to_bytes(), bytes);
+
+        // Reserved bits are always written as 1
+        let id = AtscContentIdentifier {
+            tsid: 1,
+            end_of_day: 0,
+            unique_for: 0,
+            content_id: vec![],
+        };
+        assert_eq!(id.to_bytes(), vec![0x00, 0x01, 0xC0, 0x00]);
+        assert_eq!(
+            format_upid(SegmentationUpidType::ATSCContentIdentifier, &id.to_bytes()),
+            Some("ATSC(tsid: 0x0001, end_of_day: 0, unique_for: 0, content_id: empty)".to_string())
+        );
+
+        assert!(AtscContentIdentifier::from_bytes(&bytes[..3]).is_none());
+        assert_eq!(
+            format_upid(SegmentationUpidType::ATSCContentIdentifier, &bytes[..3]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_umid_round_trip() {
+        let text = "060A2B34.01010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2.D718BFDA";
+        let bytes = parse_umid(text).unwrap();
+        assert_eq!(format_umid(&bytes), text);
+        assert_eq!(
+            format_upid(SegmentationUpidType::UMID, &bytes),
+            Some(text.to_string())
+        );
+        assert_eq!(
+            parse_umid(&text.replace('.', "").to_lowercase()).unwrap(),
+            bytes
+        );
+
+        let umid = Umid::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            umid.universal_label,
+            [
+                0x06, 0x0A, 0x2B, 0x34, 0x01, 0x01, 0x01, 0x05, 0x01, 0x01, 0x0D, 0x20
+            ]
+        );
+        assert_eq!(umid.length, 0x13);
+        assert_eq!(umid.instance_number, [0x00, 0x00, 0x00]);
+        assert_eq!(&umid.material_number[12..], &[0xD7, 0x18, 0xBF, 0xDA]);
+        assert_eq!(umid.to_bytes(), bytes);
+        assert_eq!(umid.to_string(), text);
+        assert!(Umid::from_bytes(&bytes[..31]).is_none());
+
+        for text in [
+            "",
+            "060A2B34.01010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2",
+            "060A2B34.01010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2.D718BFDX",
+            "060A2B3401.010105.01010D20.13000000.D2C9036C.8F195343.AB7014D2.D718BFDA",
+        ] {
+            assert!(text.parse::<Umid>().is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_airing_id_and_tid_text() {
+        let bytes = [0x00, 0x00, 0x00, 0x00, 0x2C, 0xA0, 0xA1, 0x8A];
+        assert_eq!(
+            format_upid(SegmentationUpidType::AiringID, &bytes),
+            Some("0x000000002CA0A18A".to_string())
+        );
+        assert_eq!(
+            format_upid(SegmentationUpidType::AiringID, &bytes[..7]),
+            None
+        );
+        assert_eq!(parse_airing_id("0x000000002CA0A18A").unwrap(), 0x2CA0A18A);
+        assert_eq!(parse_airing_id("0XFFFFFFFFFFFFFFFF").unwrap(), u64::MAX);
+        for text in ["", "0x", "0x2CA0A18G", "0x00000000000000001", "-1"] {
+            assert!(parse_airing_id(text).is_err(), "{text}");
+        }
+
+        assert!(validate_tid("MV0000000001").is_ok());
+        assert_eq!(
+            format_upid(SegmentationUpidType::TID, b"MV0000000001"),
+            Some("MV0000000001".to_string())
+        );
+        assert_eq!(
+            format_upid(SegmentationUpidType::TID, b"mv0000000001"),
+            None
+        );
+        assert_eq!(format_upid(SegmentationUpidType::TID, b"MV000000001"), None);
+        for text in [
+            "MV000000001",
+            "mv0000000001",
+            "M10000000001",
+            "MV00000000O1",
+        ] {
+            let err = validate_tid(text).unwrap_err();
+            assert_eq!(err.upid_type, SegmentationUpidType::TID, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_validate_ad_id_and_isci() {
+        assert!(validate_ad_id("ABCD0001000H").is_ok());
+        assert!(validate_ad_id("ABC123456789").is_ok());
+        for text in [
+            "",
+            "ABCD0001000",
+            "ABCD0001000HH",
+            "ABCD-001000H",
+            "9BCD0001000H",
+        ] {
+            let err = validate_ad_id(text).unwrap_err();
+            assert_eq!(err.upid_type, SegmentationUpidType::AdID, "{text}");
+        }
+
+        assert!(validate_isci("ABCD1234").is_ok());
+        for text in ["ABCD123", "ABCD12345", "abcd1234", "AB1D1234", "ABCD12E4"] {
+            let err = validate_isci(text).unwrap_err();
+            assert_eq!(err.upid_type, SegmentationUpidType::ISCI, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_parse_isan() {
+        let visan = parse_isan("0000-0001-2345-6789-O-ABCD-EF00-O").unwrap();
+        assert_eq!(
+            visan,
+            vec![
+                0x00, 0x00, 0x00, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x00
+            ]
+        );
+        assert_eq!(
+            parse_isan("isan 0000-0001-2345-6789-abcd-ef00").unwrap(),
+            visan
+        );
+        assert_eq!(
+            parse_isan("0000-0001-2345-6789-O").unwrap(),
+            visan[..8].to_vec()
+        );
+
+        for text in [
+            "",
+            "0000-0001-2345",
+            "0000-0001-2345-6789-O-ABCD",
+            "0000-0001-2345-6789-O-ABCD-EF00-O-0000",
+            "0000-0001-2345-678G",
+            "0000-0001-2345-6789-P",
+            "0000-0001-2345-6789-O-ABCD-EF00-P",
+        ] {
+            assert!(parse_isan(text).is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_parse_mid() {
+        let mut bytes = vec![0x03, 12];
+        bytes.extend_from_slice(b"ABCD0001000H");
+        bytes.extend_from_slice(&[0x0A, 12]);
+        bytes.extend_from_slice(&[
+            0x14, 0x78, 0x00, 0x00, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0,
+        ]);
+
+        let members = parse_mid(&bytes).unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].upid_type, SegmentationUpidType::AdID);
+        assert_eq!(
+            members[0].upid_as_string(),
+            Some("ABCD0001000H".to_string())
+        );
+        assert_eq!(members[1].upid_type, SegmentationUpidType::EIDR);
+        assert_eq!(members[1].upid.len(), 12);
+
+        // Empty MID has no members
+        assert_eq!(parse_mid(&[]), Some(vec![]));
+
+        // Truncated member
+        assert_eq!(parse_mid(&[0x03, 12, b'A']), None);
+        assert_eq!(parse_mid(&[0x03]), None);
+        assert_eq!(format_upid(SegmentationUpidType::MID, &[0x03, 12]), None);
+    }
+
+    #[test]
+    fn test_iso7064_mod37_36() {
+        assert_eq!(iso7064_mod37_36("779185342C2390308610"), Some('5'));
+        assert_eq!(
+            iso7064_mod37_36("lowercase"),
+            Some(iso7064_mod37_36("LOWERCASE").unwrap())
+        );
+        assert_eq!(iso7064_mod37_36("NOT-VALID"), None);
+    }
+
+    #[test]
+    fn test_eidr_text_round_trip() {
+        let text = "10.5240/7791-8534-2C23-9030-8610-5";
+        let bytes = parse_eidr(text).unwrap();
+        assert_eq!(
+            bytes,
+            [
+                0x14, 0x78, 0x77, 0x91, 0x85, 0x34, 0x2C, 0x23, 0x90, 0x30, 0x86, 0x10
+            ]
+        );
+        assert_eq!(format_eidr(&bytes), text);
+        assert_eq!(
+            format_upid(SegmentationUpidType::EIDR, &bytes),
+            Some(text.to_string())
+        );
+
+        // Lower-case input is normalized
+        assert_eq!(
+            parse_eidr("10.5240/7791-8534-2c23-9030-8610-5").unwrap(),
+            bytes
+        );
+    }
+
+    #[test]
+    fn test_eidr_rejects_malformed_text() {
+        for text in [
+            "",
+            "11.5240/7791-8534-2C23-9030-8610-5",
+            "10.5240-7791-8534-2C23-9030-8610-5",
+            "10.70000/7791-8534-2C23-9030-8610-5",
+            "10.5240/7791-8534-2C23-9030-8610",
+            "10.5240/7791-8534-2C23-9030-861-5",
+            "10.5240/7791-8534-2C23-9030-86G0-5",
+            "10.5240/7791-8534-2C23-9030-8610-X",
+        ] {
+            let error = parse_eidr(text).unwrap_err();
+            assert_eq!(error.upid_type, SegmentationUpidType::EIDR, "{text}");
+        }
     }
 }
@@ -0,0 +1,413 @@
+//! Semantic validation of parsed SCTE-35 messages.
+//!
+//! Parsing is deliberately lenient so that real-world messages can be inspected even when
+//! they bend the specification. This module reports those deviations as a list of
+//! [`ValidationIssue`]s instead of failing the parse.
+
+use crate::descriptors::{SegmentationDescriptor, SpliceDescriptor};
+use crate::types::{SegmentationType, SpliceCommand, SpliceInfoSection};
+use crate::upid::{SegmentationUpidType, validate_ad_id, validate_isci, validate_tid};
+use std::fmt;
+
+/// How serious a validation issue is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Severity {
+    /// Informational note; the message is valid
+    Info,
+    /// The message is usable but deviates from recommended practice
+    Warning,
+    /// The message violates the specification
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A single problem found while validating a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ValidationIssue {
+    /// How serious the issue is
+    pub severity: Severity,
+    /// Stable machine-readable identifier, e.g. `"deprecated-segmentation-type"`
+    pub code: &'static str,
+    /// Where in the message the issue was found, e.g. `"splice_descriptors[0]"`
+    pub location: String,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} [{}] {}",
+            self.severity, self.location, self.code, self.message
+        )
+    }
+}
+
+/// Validates a parsed splice information section.
+///
+/// Returns every issue found, in message order. An empty list means no problems were
+/// detected.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::builders::{SegmentationDescriptorBuilder, SpliceInfoSectionBuilder, TimeSignalBuilder};
+/// use scte35::validation::{Severity, validate};
+/// use scte35::SegmentationType;
+///
+/// let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::OpeningCreditStartDeprecated)
+///     .build()
+///     .unwrap();
+/// let section = SpliceInfoSectionBuilder::new()
+///     .time_signal(TimeSignalBuilder::new().build().unwrap())
+///     .add_segmentation_descriptor(descriptor)
+///     .build()
+///     .unwrap();
+///
+/// let issues = validate(&section);
+/// assert!(issues.iter().any(|issue| issue.code == "deprecated-segmentation-type"));
+/// assert!(issues.iter().all(|issue| issue.severity < Severity::Error));
+/// ```
+pub fn validate(section: &SpliceInfoSection) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    validate_header(section, &mut issues);
+
+    for (index, descriptor) in section.splice_descriptors.iter().enumerate() {
+        let location = format!("splice_descriptors[{index}]");
+        if let SpliceDescriptor::Segmentation(segmentation) = descriptor {
+            validate_segmentation_descriptor(segmentation, &location, &mut issues);
+        }
+    }
+
+    issues
+}
+
+fn validate_header(section: &SpliceInfoSection, issues: &mut Vec<ValidationIssue>) {
+    let mut push = |severity, code, location: &str, message: String| {
+        issues.push(ValidationIssue {
+            severity,
+            code,
+            location: location.to_string(),
+            message,
+        })
+    };
+
+    if section.table_id != 0xFC {
+        push(
+            Severity::Error,
+            "invalid-table-id",
+            "table_id",
+            format!("table_id is 0x{:02X}, expected 0xFC", section.table_id),
+        );
+    }
+    if section.section_syntax_indicator != 0 || section.private_indicator != 0 {
+        push(
+            Severity::Error,
+            "invalid-section-flags",
+            "section_syntax_indicator",
+            "section_syntax_indicator and private_indicator must be 0".to_string(),
+        );
+    }
+    if section.protocol_version != 0 {
+        push(
+            Severity::Error,
+            "unsupported-protocol-version",
+            "protocol_version",
+            format!(
+                "protocol_version {} is not defined; only version 0 is",
+                section.protocol_version
+            ),
+        );
+    }
+    if section.encrypted_packet != 0 {
+        push(
+            Severity::Info,
+            "encrypted-packet",
+            "encrypted_packet",
+            "the message is encrypted; its command and descriptors cannot be checked".to_string(),
+        );
+    }
+    if matches!(section.splice_command, SpliceCommand::Unknown) {
+        push(
+            Severity::Warning,
+            "unknown-splice-command",
+            "splice_command_type",
+            format!(
+                "splice_command_type 0x{:02X} is reserved",
+                section.splice_command_type
+            ),
+        );
+    }
+}
+
+fn validate_segmentation_descriptor(
+    descriptor: &SegmentationDescriptor,
+    location: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let mut push = |severity, code, message: String| {
+        issues.push(ValidationIssue {
+            severity,
+            code,
+            location: location.to_string(),
+            message,
+        })
+    };
+
+    let segmentation_type = SegmentationType::from_id(descriptor.segmentation_type_id);
+    if segmentation_type == SegmentationType::NotIndicated && descriptor.segmentation_type_id != 0 {
+        push(
+            Severity::Warning,
+            "unknown-segmentation-type",
+            format!(
+                "segmentation_type_id 0x{:02X} is not defined",
+                descriptor.segmentation_type_id
+            ),
+        );
+        return;
+    }
+
+    if descriptor.segmentation_event_cancel_indicator {
+        return;
+    }
+
+    let info = segmentation_type.info();
+    let label = format!(
+        "{} (0x{:02X})",
+        segmentation_type, descriptor.segmentation_type_id
+    );
+
+    if info.deprecated {
+        push(
+            Severity::Warning,
+            "deprecated-segmentation-type",
+            format!("segmentation type {label} is deprecated"),
+        );
+    }
+
+    let has_sub_segment_fields =
+        descriptor.sub_segment_num.is_some() || descriptor.sub_segments_expected.is_some();
+    if has_sub_segment_fields && !info.has_sub_segments {
+        push(
+            Severity::Error,
+            "unexpected-sub-segments",
+            format!("segmentation type {label} does not carry sub-segment fields"),
+        );
+    }
+
+    if info.duration_expected && !descriptor.segmentation_duration_flag {
+        push(
+            Severity::Info,
+            "missing-segmentation-duration",
+            format!("segmentation type {label} normally signals a segmentation_duration"),
+        );
+    }
+
+    match descriptor.mid_upids() {
+        Some(members) => {
+            for member in members {
+                validate_upid_format(member.upid_type, &member.upid, &mut push);
+            }
+        }
+        None => validate_upid_format(
+            descriptor.segmentation_upid_type,
+            &descriptor.segmentation_upid,
+            &mut push,
+        ),
+    }
+}
+
+/// Checks the text format of Ad-ID, ISCI and TID UPIDs.
+fn validate_upid_format(
+    upid_type: SegmentationUpidType,
+    upid: &[u8],
+    push: &mut impl FnMut(Severity, &'static str, String),
+) {
+    let (code, result) = match upid_type {
+        SegmentationUpidType::AdID => (
+            "invalid-ad-id",
+            std::str::from_utf8(upid).map(validate_ad_id),
+        ),
+        SegmentationUpidType::ISCI => {
+            ("invalid-isci", std::str::from_utf8(upid).map(validate_isci))
+        }
+        SegmentationUpidType::TID => ("invalid-tid", std::str::from_utf8(upid).map(validate_tid)),
+        _ => return,
+    };
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(error)) => push(Severity::Warning, code, error.to_string()),
+        Err(_) => push(
+            Severity::Warning,
+            code,
+            format!("{} UPID is not valid text", upid_type.short_name()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builders::{
+        SegmentationDescriptorBuilder, SpliceInfoSectionBuilder, TimeSignalBuilder, Upid,
+    };
+    use std::time::Duration;
+
+    fn section_with(descriptor: SegmentationDescriptor) -> SpliceInfoSection {
+        SpliceInfoSectionBuilder::new()
+            .time_signal(TimeSignalBuilder::new().build().unwrap())
+            .add_segmentation_descriptor(descriptor)
+            .build()
+            .unwrap()
+    }
+
+    fn codes(section: &SpliceInfoSection) -> Vec<&'static str> {
+        validate(section)
+            .into_iter()
+            .map(|issue| issue.code)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_placement_opportunity() {
+        let descriptor = SegmentationDescriptorBuilder::new(
+            1,
+            SegmentationType::ProviderPlacementOpportunityStart,
+        )
+        .duration(Duration::from_secs(30))
+        .unwrap()
+        .sub_segment(1, 2)
+        .build()
+        .unwrap();
+
+        assert!(validate(&section_with(descriptor)).is_empty());
+    }
+
+    #[test]
+    fn test_unexpected_sub_segments() {
+        let mut descriptor =
+            SegmentationDescriptorBuilder::new(1, SegmentationType::ProviderAdvertisementEnd)
+                .build()
+                .unwrap();
+        descriptor.sub_segment_num = Some(1);
+        descriptor.sub_segments_expected = Some(2);
+
+        let issues = validate(&section_with(descriptor));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert_eq!(issues[0].code, "unexpected-sub-segments");
+        assert_eq!(issues[0].location, "splice_descriptors[0]");
+    }
+
+    #[test]
+    fn test_deprecated_and_missing_duration() {
+        let descriptor =
+            SegmentationDescriptorBuilder::new(1, SegmentationType::ClosingCreditStartDeprecated)
+                .build()
+                .unwrap();
+
+        assert_eq!(
+            codes(&section_with(descriptor)),
+            vec![
+                "deprecated-segmentation-type",
+                "missing-segmentation-duration"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_text_upids() {
+        let mut descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramEnd)
+            .upid(Upid::AdId("ABCD0001000H".to_string()))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(validate(&section_with(descriptor.clone())).is_empty());
+
+        descriptor.segmentation_upid = b"abcd0001000h".to_vec();
+        let issues = validate(&section_with(descriptor));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "invalid-ad-id");
+        assert_eq!(issues[0].severity, Severity::Warning);
+
+        // MID members are checked individually
+        let mut descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramEnd)
+            .upid(Upid::Mid(vec![
+                Upid::Isci("ABCD1234".to_string()),
+                Upid::Uri("urn:example".to_string()),
+            ]))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(validate(&section_with(descriptor.clone())).is_empty());
+
+        descriptor.segmentation_upid[2..10].copy_from_slice(b"ABCDEFGH");
+        assert_eq!(codes(&section_with(descriptor)), vec!["invalid-isci"]);
+
+        let mut descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramEnd)
+            .upid(Upid::Tid("SH0000000001".to_string()))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(validate(&section_with(descriptor.clone())).is_empty());
+        descriptor.segmentation_upid = b"SH00000000\xFF1".to_vec();
+        assert_eq!(codes(&section_with(descriptor)), vec!["invalid-tid"]);
+    }
+
+    #[test]
+    fn test_header_rules() {
+        let mut section = SpliceInfoSectionBuilder::new()
+            .splice_null()
+            .build()
+            .unwrap();
+        assert!(validate(&section).is_empty());
+
+        section.table_id = 0xFD;
+        section.protocol_version = 1;
+        section.splice_command_type = 0x10;
+        section.splice_command = SpliceCommand::Unknown;
+        let issues = validate(&section);
+        assert_eq!(
+            codes(&section),
+            vec![
+                "invalid-table-id",
+                "unsupported-protocol-version",
+                "unknown-splice-command"
+            ]
+        );
+        assert_eq!(issues[0].location, "table_id");
+        assert_eq!(issues[2].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_unknown_type_and_cancelled_event() {
+        let mut descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .build()
+            .unwrap();
+        descriptor.segmentation_type_id = 0x99;
+        assert_eq!(
+            codes(&section_with(descriptor)),
+            vec!["unknown-segmentation-type"]
+        );
+
+        let descriptor = SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+            .cancel_event()
+            .build()
+            .unwrap();
+        assert!(validate(&section_with(descriptor)).is_empty());
+    }
+}
@@ -0,0 +1,1398 @@
+//! SCTE-35 XML: the `SpliceInfoSection` element of the SCTE 35 XML schema.
+//!
+//! [`to_xml`] and [`from_xml`] convert between a parsed section and its XML form in the
+//! [`NAMESPACE`] namespace, as carried in DASH event streams and ESAM messages.
+//! [`find_signals`] collects every cue in a larger document such as a DASH MPD, whether it
+//! is written out as a `SpliceInfoSection` element or as a base64 `Binary` element.
+//!
+//! Only content with an XML form is converted. Encrypted sections, `splice_schedule()`,
+//! audio, private and unknown descriptors, and component-level segmentation are rejected
+//! with an [`XmlError`] rather than silently dropped.
+//!
+//! The reader handles the subset of XML these documents use: elements, attributes, text,
+//! CDATA, comments, processing instructions and character references. Namespace prefixes
+//! are ignored.
+
+use crate::descriptors::{SegmentationDescriptor, SpliceDescriptor, TimeDescriptor};
+use crate::input::{InputFormat, decode_input};
+use crate::time::{BreakDuration, SpliceTime};
+use crate::types::{
+    BandwidthReservation, PrivateCommand, SpliceCommand, SpliceInfoSection, SpliceInsert,
+    SpliceInsertComponent, TimeSignal,
+};
+use crate::upid::SegmentationUpidType;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Namespace of the SCTE 35 XML schema.
+pub const NAMESPACE: &str = "http://www.scte.org/schemas/35";
+
+/// Descriptor identifier written for descriptors read from XML ("CUEI").
+const CUEI: u32 = 0x43554549;
+
+/// Deepest element nesting accepted by the parser; SCTE-35 XML itself needs only a few
+/// levels, even inside an MPD.
+const MAX_DEPTH: usize = 64;
+
+/// Error returned when a section has no XML form or a document is not valid SCTE-35 XML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlError {
+    /// A description of what is wrong
+    pub reason: String,
+}
+
+impl XmlError {
+    fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+
+    fn unsupported(what: &str) -> Self {
+        Self::new(format!("{what} has no SCTE-35 XML form"))
+    }
+}
+
+impl fmt::Display for XmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid SCTE-35 XML: {}", self.reason)
+    }
+}
+
+impl Error for XmlError {}
+
+/// A cue found by [`find_signals`].
+#[derive(Debug, PartialEq)]
+pub enum Signal {
+    /// A cue written out as a `SpliceInfoSection` element
+    Section(SpliceInfoSection),
+    /// The base64 text of a `Binary` element
+    Binary(String),
+}
+
+/// Writes a section as a `SpliceInfoSection` element.
+///
+/// Lengths and the CRC are not part of the XML form; they are recomputed when the section
+/// read back by [`from_xml`] is encoded.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::parse_splice_info_section;
+/// use scte35::xml::{from_xml, to_xml};
+///
+/// let bytes = scte35::input::decode_input(
+///     b"0xFC301600000000000000FFF00506FE423A35BD0000BB0C73F4",
+///     scte35::input::InputFormat::Hex,
+/// )?;
+/// let section = parse_splice_info_section(&bytes)?;
+///
+/// let xml = to_xml(&section)?;
+/// assert!(xml.contains(r#"<SpliceTime ptsTime="1111111101"/>"#));
+/// assert_eq!(from_xml(&xml)?.splice_command, section.splice_command);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn to_xml(section: &SpliceInfoSection) -> Result<String, XmlError> {
+    if section.encrypted_packet != 0 {
+        return Err(XmlError::unsupported("an encrypted section"));
+    }
+
+    let mut writer = XmlWriter::new();
+    writer.start(
+        "SpliceInfoSection",
+        &[
+            ("xmlns", NAMESPACE.to_string()),
+            ("sapType", section.sap_type.to_string()),
+            ("ptsAdjustment", section.pts_adjustment.to_string()),
+            ("protocolVersion", section.protocol_version.to_string()),
+            ("tier", section.tier.to_string()),
+        ],
+    );
+    write_command(&mut writer, &section.splice_command)?;
+    for descriptor in &section.splice_descriptors {
+        write_descriptor(&mut writer, descriptor)?;
+    }
+    writer.end();
+    Ok(writer.finish())
+}
+
+/// Reads the first `SpliceInfoSection` element of a document.
+///
+/// Header fields missing from the element take the values the builders use: `sapType` 3,
+/// `tier` 0xFFF and zero for the others. `cw_index` has no XML form and is always zero.
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::SpliceCommand;
+/// use scte35::xml::from_xml;
+///
+/// let section = from_xml(
+///     r#"<scte35:SpliceInfoSection xmlns:scte35="http://www.scte.org/schemas/35">
+///          <scte35:TimeSignal><scte35:SpliceTime ptsTime="900000"/></scte35:TimeSignal>
+///        </scte35:SpliceInfoSection>"#,
+/// )?;
+/// assert!(matches!(section.splice_command, SpliceCommand::TimeSignal(_)));
+/// assert_eq!(section.tier, 0xFFF);
+/// # Ok::<(), scte35::xml::XmlError>(())
+/// ```
+pub fn from_xml(text: &str) -> Result<SpliceInfoSection, XmlError> {
+    let root = parse_document(text)?;
+    let element = root
+        .find("SpliceInfoSection")
+        .ok_or_else(|| XmlError::new("no SpliceInfoSection element"))?;
+    read_section(element)
+}
+
+/// Finds every cue in a document, in document order.
+///
+/// Both forms used in DASH `EventStream` elements are recognised: a `SpliceInfoSection`
+/// element (scheme `urn:scte:scte35:2013:xml`) and a `Binary` element holding base64
+/// section bytes (scheme `urn:scte:scte35:2014:xml+bin`).
+///
+/// # Example
+///
+/// ```rust
+/// use scte35::xml::{Signal, find_signals};
+///
+/// let mpd = r#"<EventStream schemeIdUri="urn:scte:scte35:2014:xml+bin" timescale="90000">
+///   <Event presentationTime="1111111101" id="1">
+///     <Signal xmlns="http://www.scte.org/schemas/35"><Binary>/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==</Binary></Signal>
+///   </Event>
+/// </EventStream>"#;
+///
+/// let signals = find_signals(mpd)?;
+/// assert_eq!(signals, vec![Signal::Binary("/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==".to_string())]);
+/// # Ok::<(), scte35::xml::XmlError>(())
+/// ```
+pub fn find_signals(text: &str) -> Result<Vec<Signal>, XmlError> {
+    let root = parse_document(text)?;
+    let mut signals = Vec::new();
+    collect_signals(&root, &mut signals)?;
+    Ok(signals)
+}
+
+fn collect_signals(element: &Element, signals: &mut Vec<Signal>) -> Result<(), XmlError> {
+    match element.name.as_str() {
+        "SpliceInfoSection" => signals.push(Signal::Section(read_section(element)?)),
+        "Binary" => signals.push(Signal::Binary(element.text.trim().to_string())),
+        _ => {
+            for child in &element.children {
+                collect_signals(child, signals)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------------------
+// Writing
+// ---------------------------------------------------------------------------------------
+
+fn flag(value: bool) -> String {
+    value.to_string()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
+fn write_splice_time(writer: &mut XmlWriter, splice_time: &SpliceTime) {
+    match splice_time.pts_time {
+        Some(pts) if splice_time.time_specified_flag == 1 => {
+            writer.empty("SpliceTime", &[("ptsTime", pts.to_string())])
+        }
+        _ => writer.empty("SpliceTime", &[]),
+    }
+}
+
+fn write_command(writer: &mut XmlWriter, command: &SpliceCommand) -> Result<(), XmlError> {
+    match command {
+        SpliceCommand::SpliceNull => writer.empty("SpliceNull", &[]),
+        SpliceCommand::SpliceInsert(insert) => {
+            let cancel = insert.splice_event_cancel_indicator == 1;
+            let mut attributes = vec![
+                ("spliceEventId", insert.splice_event_id.to_string()),
+                ("spliceEventCancelIndicator", flag(cancel)),
+            ];
+            if cancel {
+                writer.empty("SpliceInsert", &attributes);
+                return Ok(());
+            }
+            attributes.extend([
+                (
+                    "outOfNetworkIndicator",
+                    flag(insert.out_of_network_indicator == 1),
+                ),
+                (
+                    "spliceImmediateFlag",
+                    flag(insert.splice_immediate_flag == 1),
+                ),
+                ("uniqueProgramId", insert.unique_program_id.to_string()),
+                ("availNum", insert.avail_num.to_string()),
+                ("availsExpected", insert.avails_expected.to_string()),
+            ]);
+            writer.start("SpliceInsert", &attributes);
+
+            if insert.program_splice_flag == 1 {
+                match &insert.splice_time {
+                    Some(splice_time) if insert.splice_immediate_flag == 0 => {
+                        writer.start("Program", &[]);
+                        write_splice_time(writer, splice_time);
+                        writer.end();
+                    }
+                    _ => writer.empty("Program", &[]),
+                }
+            } else {
+                for component in &insert.components {
+                    let tag = [("componentTag", component.component_tag.to_string())];
+                    match &component.splice_time {
+                        Some(splice_time) => {
+                            writer.start("Component", &tag);
+                            write_splice_time(writer, splice_time);
+                            writer.end();
+                        }
+                        None => writer.empty("Component", &tag),
+                    }
+                }
+            }
+
+            if insert.duration_flag == 1
+                && let Some(duration) = &insert.break_duration
+            {
+                writer.empty(
+                    "BreakDuration",
+                    &[
+                        ("autoReturn", flag(duration.auto_return == 1)),
+                        ("duration", duration.duration.to_string()),
+                    ],
+                );
+            }
+            writer.end();
+        }
+        SpliceCommand::TimeSignal(signal) => {
+            writer.start("TimeSignal", &[]);
+            write_splice_time(writer, &signal.splice_time);
+            writer.end();
+        }
+        SpliceCommand::BandwidthReservation(reservation) => writer.empty(
+            "BandwidthReservation",
+            &[("dwbwReservation", reservation.dwbw_reservation.to_string())],
+        ),
+        SpliceCommand::PrivateCommand(private) => {
+            writer.start(
+                "PrivateCommand",
+                &[("identifier", private.private_command_id.to_string())],
+            );
+            writer.text("PrivateBytes", &[], &hex(&private.private_bytes));
+            writer.end();
+        }
+        SpliceCommand::SpliceSchedule(_) | SpliceCommand::Unknown => {
+            return Err(XmlError::unsupported(command.name()));
+        }
+    }
+    Ok(())
+}
+
+fn write_descriptor(writer: &mut XmlWriter, descriptor: &SpliceDescriptor) -> Result<(), XmlError> {
+    match descriptor {
+        SpliceDescriptor::Segmentation(segmentation) => write_segmentation(writer, segmentation)?,
+        SpliceDescriptor::Avail(avail) => write_avail(writer, &avail.provider_avail_id)?,
+        SpliceDescriptor::Dtmf(dtmf) => write_dtmf(writer, dtmf.preroll, &dtmf.dtmf_chars),
+        // The parser keeps avail and DTMF descriptors as raw bytes
+        SpliceDescriptor::Unknown {
+            tag: 0x00, data, ..
+        } if data.starts_with(&CUEI.to_be_bytes()) => write_avail(writer, &data[4..])?,
+        SpliceDescriptor::Unknown {
+            tag: 0x01, data, ..
+        } if data.starts_with(&CUEI.to_be_bytes())
+            && data.len() == 6 + (data.get(5).copied().unwrap_or(0) >> 5) as usize =>
+        {
+            write_dtmf(writer, data[4], &data[6..])
+        }
+        SpliceDescriptor::Time(time) => {
+            let truncated = || XmlError::new("time_descriptor is truncated");
+            writer.empty(
+                "TimeDescriptor",
+                &[
+                    (
+                        "taiSeconds",
+                        time.tai_seconds_value().ok_or_else(truncated)?.to_string(),
+                    ),
+                    (
+                        "taiNs",
+                        time.tai_ns_value().ok_or_else(truncated)?.to_string(),
+                    ),
+                    (
+                        "utcOffset",
+                        time.utc_offset_value().ok_or_else(truncated)?.to_string(),
+                    ),
+                ],
+            );
+        }
+        SpliceDescriptor::Audio(_) => return Err(XmlError::unsupported("audio_descriptor")),
+        SpliceDescriptor::Private(_) | SpliceDescriptor::Unknown { .. } => {
+            return Err(XmlError::unsupported(&format!(
+                "descriptor tag 0x{:02X}",
+                descriptor.tag()
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn write_avail(writer: &mut XmlWriter, provider_avail_id: &[u8]) -> Result<(), XmlError> {
+    let id: [u8; 4] = provider_avail_id
+        .try_into()
+        .map_err(|_| XmlError::new("provider_avail_id is not 4 bytes long"))?;
+    writer.empty(
+        "AvailDescriptor",
+        &[("providerAvailId", u32::from_be_bytes(id).to_string())],
+    );
+    Ok(())
+}
+
+fn write_dtmf(writer: &mut XmlWriter, preroll: u8, chars: &[u8]) {
+    writer.empty(
+        "DTMFDescriptor",
+        &[
+            ("preroll", preroll.to_string()),
+            ("chars", String::from_utf8_lossy(chars).into_owned()),
+        ],
+    );
+}
+
+fn write_segmentation(
+    writer: &mut XmlWriter,
+    segmentation: &SegmentationDescriptor,
+) -> Result<(), XmlError> {
+    let cancel = segmentation.segmentation_event_cancel_indicator;
+    let mut attributes = vec![
+        (
+            "segmentationEventId",
+            segmentation.segmentation_event_id.to_string(),
+        ),
+        ("segmentationEventCancelIndicator", flag(cancel)),
+    ];
+    if !cancel {
+        if !segmentation.program_segmentation_flag {
+            return Err(XmlError::unsupported("component segmentation"));
+        }
+        if segmentation.segmentation_duration_flag
+            && let Some(duration) = segmentation.segmentation_duration
+        {
+            attributes.push(("segmentationDuration", duration.to_string()));
+        }
+    }
+    attributes.extend([
+        (
+            "segmentationTypeId",
+            segmentation.segmentation_type_id.to_string(),
+        ),
+        ("segmentNum", segmentation.segment_num.to_string()),
+        (
+            "segmentsExpected",
+            segmentation.segments_expected.to_string(),
+        ),
+    ]);
+    if let (Some(num), Some(expected)) = (
+        segmentation.sub_segment_num,
+        segmentation.sub_segments_expected,
+    ) {
+        attributes.push(("subSegmentNum", num.to_string()));
+        attributes.push(("subSegmentsExpected", expected.to_string()));
+    }
+
+    let restrictions = (!cancel && !segmentation.delivery_not_restricted_flag).then(|| {
+        [
+            (
+                "webDeliveryAllowedFlag",
+                flag(segmentation.web_delivery_allowed_flag.unwrap_or(false)),
+            ),
+            (
+                "noRegionalBlackoutFlag",
+                flag(segmentation.no_regional_blackout_flag.unwrap_or(false)),
+            ),
+            (
+                "archiveAllowedFlag",
+                flag(segmentation.archive_allowed_flag.unwrap_or(false)),
+            ),
+            (
+                "deviceRestrictions",
+                segmentation.device_restrictions.unwrap_or(0).to_string(),
+            ),
+        ]
+    });
+
+    // A MID UPID is written as one SegmentationUpid element per member
+    let upids: Vec<(u8, Vec<u8>)> = match segmentation.segmentation_upid_type {
+        SegmentationUpidType::MID => segmentation
+            .mid_upids()
+            .ok_or_else(|| XmlError::new("MID UPID is malformed"))?
+            .into_iter()
+            .map(|member| (u8::from(member.upid_type), member.upid))
+            .collect(),
+        SegmentationUpidType::NotUsed if segmentation.segmentation_upid.is_empty() => Vec::new(),
+        upid_type => vec![(u8::from(upid_type), segmentation.segmentation_upid.clone())],
+    };
+
+    if restrictions.is_none() && upids.is_empty() {
+        writer.empty("SegmentationDescriptor", &attributes);
+        return Ok(());
+    }
+    writer.start("SegmentationDescriptor", &attributes);
+    if let Some(restrictions) = &restrictions {
+        writer.empty("DeliveryRestrictions", restrictions);
+    }
+    for (upid_type, upid) in &upids {
+        writer.text(
+            "SegmentationUpid",
+            &[
+                ("segmentationUpidType", upid_type.to_string()),
+                ("segmentationUpidFormat", "hexbinary".to_string()),
+            ],
+            &hex(upid),
+        );
+    }
+    writer.end();
+    Ok(())
+}
+
+/// Writes indented XML, two spaces per level.
+pub(crate) struct XmlWriter {
+    out: String,
+    open: Vec<&'static str>,
+}
+
+impl XmlWriter {
+    pub(crate) fn new() -> Self {
+        Self {
+            out: String::new(),
+            open: Vec::new(),
+        }
+    }
+
+    fn tag(&mut self, name: &str, attributes: &[(&str, String)]) {
+        for _ in 0..self.open.len() {
+            self.out.push_str("  ");
+        }
+        self.out.push('<');
+        self.out.push_str(name);
+        for (attribute, value) in attributes {
+            self.out
+                .push_str(&format!(" {attribute}=\"{}\"", escape(value)));
+        }
+    }
+
+    /// Opens an element; close it with [`end`](Self::end).
+    pub(crate) fn start(&mut self, name: &'static str, attributes: &[(&str, String)]) {
+        self.tag(name, attributes);
+        self.out.push_str(">\n");
+        self.open.push(name);
+    }
+
+    /// Writes an element without content.
+    pub(crate) fn empty(&mut self, name: &str, attributes: &[(&str, String)]) {
+        self.tag(name, attributes);
+        self.out.push_str("/>\n");
+    }
+
+    /// Writes an element holding only text.
+    pub(crate) fn text(&mut self, name: &str, attributes: &[(&str, String)], text: &str) {
+        self.tag(name, attributes);
+        self.out.push_str(&format!(">{}</{name}>\n", escape(text)));
+    }
+
+    /// Closes the most recently opened element.
+    pub(crate) fn end(&mut self) {
+        let name = self.open.pop().expect("no open element");
+        for _ in 0..self.open.len() {
+            self.out.push_str("  ");
+        }
+        self.out.push_str(&format!("</{name}>\n"));
+    }
+
+    /// Returns the document, without a trailing line break.
+    pub(crate) fn finish(mut self) -> String {
+        debug_assert!(self.open.is_empty(), "unclosed elements: {:?}", self.open);
+        self.out.truncate(self.out.trim_end().len());
+        self.out
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// ---------------------------------------------------------------------------------------
+// Reading
+// ---------------------------------------------------------------------------------------
+
+fn read_section(element: &Element) -> Result<SpliceInfoSection, XmlError> {
+    let mut command = None;
+    let mut descriptors = Vec::new();
+    for child in &element.children {
+        match child.name.as_str() {
+            "SpliceNull"
+            | "SpliceInsert"
+            | "TimeSignal"
+            | "BandwidthReservation"
+            | "PrivateCommand" => {
+                if command.is_some() {
+                    return Err(XmlError::new("more than one splice command"));
+                }
+                command = Some(read_command(child)?);
+            }
+            "SpliceSchedule" => return Err(XmlError::new("splice_schedule is not supported")),
+            "SegmentationDescriptor" | "AvailDescriptor" | "DTMFDescriptor" | "TimeDescriptor" => {
+                descriptors.push(read_descriptor(child)?)
+            }
+            name => return Err(XmlError::new(format!("unsupported element <{name}>"))),
+        }
+    }
+    let (splice_command_type, splice_command) =
+        command.ok_or_else(|| XmlError::new("missing splice command"))?;
+
+    Ok(SpliceInfoSection {
+        table_id: 0xFC,
+        section_syntax_indicator: 0,
+        private_indicator: 0,
+        sap_type: element.bounded("sapType", 2)?.unwrap_or(3) as u8,
+        section_length: 0,
+        protocol_version: element.number("protocolVersion")?.unwrap_or(0),
+        encrypted_packet: 0,
+        encryption_algorithm: 0,
+        pts_adjustment: element.bounded("ptsAdjustment", 33)?.unwrap_or(0),
+        cw_index: 0,
+        tier: element.bounded("tier", 12)?.unwrap_or(0xFFF) as u16,
+        splice_command_length: 0,
+        splice_command_type,
+        splice_command,
+        descriptor_loop_length: 0,
+        splice_descriptors: descriptors,
+        alignment_stuffing_bits: Vec::new(),
+        e_crc_32: None,
+        crc_32: 0,
+    })
+}
+
+fn read_splice_time(element: &Element) -> Result<SpliceTime, XmlError> {
+    let pts_time = element.bounded("ptsTime", 33)?;
+    Ok(SpliceTime {
+        time_specified_flag: pts_time.is_some() as u8,
+        pts_time,
+    })
+}
+
+/// Reads a splice command and returns it with its splice_command_type.
+fn read_command(element: &Element) -> Result<(u8, SpliceCommand), XmlError> {
+    match element.name.as_str() {
+        "SpliceNull" => Ok((0x00, SpliceCommand::SpliceNull)),
+        "SpliceInsert" => Ok((0x05, SpliceCommand::SpliceInsert(read_insert(element)?))),
+        "TimeSignal" => {
+            let splice_time = match element.child("SpliceTime") {
+                Some(splice_time) => read_splice_time(splice_time)?,
+                None => SpliceTime {
+                    time_specified_flag: 0,
+                    pts_time: None,
+                },
+            };
+            Ok((0x06, SpliceCommand::TimeSignal(TimeSignal { splice_time })))
+        }
+        "BandwidthReservation" => Ok((
+            0x07,
+            SpliceCommand::BandwidthReservation(BandwidthReservation {
+                reserved: 0xFF,
+                dwbw_reservation: element.number("dwbwReservation")?.unwrap_or(0),
+            }),
+        )),
+        _ => {
+            let private_bytes = match element.child("PrivateBytes") {
+                Some(bytes) => read_hex(bytes)?,
+                None => Vec::new(),
+            };
+            let private_command_length = u8::try_from(private_bytes.len())
+                .map_err(|_| XmlError::new("PrivateBytes is longer than 255 bytes"))?;
+            Ok((
+                0xFF,
+                SpliceCommand::PrivateCommand(PrivateCommand {
+                    private_command_id: element.required("identifier")?,
+                    private_command_length,
+                    private_bytes,
+                }),
+            ))
+        }
+    }
+}
+
+fn read_insert(element: &Element) -> Result<SpliceInsert, XmlError> {
+    let cancel = element.flag("spliceEventCancelIndicator")?.unwrap_or(false);
+    let immediate = element.flag("spliceImmediateFlag")?.unwrap_or(false);
+
+    let program = element.child("Program");
+    let splice_time = program
+        .and_then(|program| program.child("SpliceTime"))
+        .map(read_splice_time)
+        .transpose()?;
+    let components = element
+        .children_named("Component")
+        .map(|component| {
+            Ok(SpliceInsertComponent {
+                component_tag: component.required("componentTag")?,
+                splice_time: component
+                    .child("SpliceTime")
+                    .map(read_splice_time)
+                    .transpose()?,
+            })
+        })
+        .collect::<Result<Vec<_>, XmlError>>()?;
+    let program_splice = program.is_some() || components.is_empty();
+    if !cancel && program_splice && !immediate && splice_time.is_none() {
+        return Err(XmlError::new(
+            "<SpliceInsert> needs a <Program><SpliceTime> unless spliceImmediateFlag is set",
+        ));
+    }
+    let component_count = u8::try_from(components.len())
+        .map_err(|_| XmlError::new("more than 255 <Component> elements"))?;
+
+    let break_duration = element
+        .child("BreakDuration")
+        .map(|duration| {
+            Ok::<_, XmlError>(BreakDuration {
+                auto_return: duration.flag("autoReturn")?.unwrap_or(true) as u8,
+                reserved: 0x3F,
+                duration: duration
+                    .bounded("duration", 33)?
+                    .ok_or_else(|| duration.missing("duration"))?,
+            })
+        })
+        .transpose()?;
+
+    Ok(SpliceInsert {
+        splice_event_id: element.required("spliceEventId")?,
+        splice_event_cancel_indicator: cancel as u8,
+        reserved: 0x7F,
+        out_of_network_indicator: element.flag("outOfNetworkIndicator")?.unwrap_or(false) as u8,
+        program_splice_flag: program_splice as u8,
+        duration_flag: break_duration.is_some() as u8,
+        splice_immediate_flag: immediate as u8,
+        reserved2: 0x0F,
+        splice_time: splice_time.filter(|_| !immediate),
+        component_count,
+        components,
+        break_duration,
+        unique_program_id: element.number("uniqueProgramId")?.unwrap_or(0),
+        avail_num: element.number("availNum")?.unwrap_or(0),
+        avails_expected: element.number("availsExpected")?.unwrap_or(0),
+    })
+}
+
+fn read_descriptor(element: &Element) -> Result<SpliceDescriptor, XmlError> {
+    match element.name.as_str() {
+        // Avail and DTMF descriptors are built the way the parser reads them, as raw bytes
+        "AvailDescriptor" => {
+            let mut data = CUEI.to_be_bytes().to_vec();
+            data.extend(element.required::<u32>("providerAvailId")?.to_be_bytes());
+            Ok(SpliceDescriptor::Unknown {
+                tag: 0x00,
+                length: data.len() as u8,
+                data,
+            })
+        }
+        "DTMFDescriptor" => {
+            let chars = element.attribute("chars").unwrap_or_default();
+            if chars.len() > 7 {
+                return Err(XmlError::new("DTMF chars are longer than 7 characters"));
+            }
+            let mut data = CUEI.to_be_bytes().to_vec();
+            data.push(element.number("preroll")?.unwrap_or(0));
+            data.push((chars.len() as u8) << 5 | 0x1F);
+            data.extend(chars.as_bytes());
+            Ok(SpliceDescriptor::Unknown {
+                tag: 0x01,
+                length: data.len() as u8,
+                data,
+            })
+        }
+        "TimeDescriptor" => {
+            let tai_seconds = element
+                .bounded("taiSeconds", 48)?
+                .ok_or_else(|| element.missing("taiSeconds"))?;
+            Ok(SpliceDescriptor::Time(TimeDescriptor {
+                identifier: CUEI,
+                tai_seconds: tai_seconds.to_be_bytes()[2..].to_vec(),
+                tai_ns: element.required::<u32>("taiNs")?.to_be_bytes().to_vec(),
+                utc_offset: element.required::<u16>("utcOffset")?.to_be_bytes().to_vec(),
+            }))
+        }
+        _ => read_segmentation(element).map(SpliceDescriptor::Segmentation),
+    }
+}
+
+fn read_segmentation(element: &Element) -> Result<SegmentationDescriptor, XmlError> {
+    let duration = element.bounded("segmentationDuration", 40)?;
+
+    let restrictions = element.child("DeliveryRestrictions");
+    let restriction = |name| {
+        restrictions
+            .map(|restrictions| restrictions.required::<Flag>(name).map(|flag| flag.0))
+            .transpose()
+    };
+    let device_restrictions = restrictions
+        .map(|restrictions| {
+            restrictions
+                .bounded("deviceRestrictions", 2)?
+                .ok_or_else(|| restrictions.missing("deviceRestrictions"))
+        })
+        .transpose()?
+        .map(|value| value as u8);
+
+    let mut upids = element
+        .children_named("SegmentationUpid")
+        .map(|upid| {
+            if let Some(format) = upid.attribute("segmentationUpidFormat")
+                && format != "hexbinary"
+            {
+                return Err(XmlError::new(format!(
+                    "segmentationUpidFormat '{format}' is not supported"
+                )));
+            }
+            Ok((
+                upid.required::<u8>("segmentationUpidType")?,
+                read_hex(upid)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, XmlError>>()?;
+    let (upid_type, upid) = match upids.len() {
+        0 => (0x00, Vec::new()),
+        1 => upids.remove(0),
+        _ => {
+            let mut mid = Vec::new();
+            for (upid_type, upid) in upids {
+                let length = u8::try_from(upid.len())
+                    .map_err(|_| XmlError::new("SegmentationUpid is longer than 255 bytes"))?;
+                mid.push(upid_type);
+                mid.push(length);
+                mid.extend(upid);
+            }
+            (0x0D, mid)
+        }
+    };
+    let upid_length = u8::try_from(upid.len())
+        .map_err(|_| XmlError::new("segmentation UPID is longer than 255 bytes"))?;
+
+    let sub_segment_num = element.number("subSegmentNum")?;
+    let sub_segments_expected = element.number("subSegmentsExpected")?;
+    Ok(SegmentationDescriptor::new(
+        element.required("segmentationEventId")?,
+        element
+            .flag("segmentationEventCancelIndicator")?
+            .unwrap_or(false),
+        true,
+        duration.is_some(),
+        restrictions.is_none(),
+        restriction("webDeliveryAllowedFlag")?,
+        restriction("noRegionalBlackoutFlag")?,
+        restriction("archiveAllowedFlag")?,
+        device_restrictions,
+        duration,
+        SegmentationUpidType::from(upid_type),
+        upid_length,
+        upid,
+        element.required("segmentationTypeId")?,
+        element.number("segmentNum")?.unwrap_or(0),
+        element.number("segmentsExpected")?.unwrap_or(0),
+        sub_segment_num,
+        sub_segments_expected,
+    ))
+}
+
+fn read_hex(element: &Element) -> Result<Vec<u8>, XmlError> {
+    let text = element.text.trim();
+    if text.is_empty() {
+        return Ok(Vec::new());
+    }
+    decode_input(text.as_bytes(), InputFormat::Hex)
+        .map_err(|e| XmlError::new(format!("<{}>: {}", element.name, e.reason)))
+}
+
+/// An `xs:boolean` attribute value.
+struct Flag(bool);
+
+impl FromStr for Flag {
+    type Err = ();
+
+    fn from_str(text: &str) -> Result<Self, ()> {
+        match text {
+            "true" | "1" => Ok(Flag(true)),
+            "false" | "0" => Ok(Flag(false)),
+            _ => Err(()),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Document model
+// ---------------------------------------------------------------------------------------
+
+#[derive(Debug, Default)]
+struct Element {
+    /// Local name, without a namespace prefix
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+impl Element {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// Finds this element or its first descendant with the given name.
+    fn find(&self, name: &str) -> Option<&Element> {
+        if self.name == name {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(name))
+    }
+
+    fn missing(&self, attribute: &str) -> XmlError {
+        XmlError::new(format!("<{}> is missing {attribute}", self.name))
+    }
+
+    fn number<T: FromStr>(&self, attribute: &str) -> Result<Option<T>, XmlError> {
+        self.attribute(attribute)
+            .map(|value| {
+                value.trim().parse().map_err(|_| {
+                    XmlError::new(format!("invalid {attribute} '{value}' on <{}>", self.name))
+                })
+            })
+            .transpose()
+    }
+
+    fn required<T: FromStr>(&self, attribute: &str) -> Result<T, XmlError> {
+        self.number(attribute)?
+            .ok_or_else(|| self.missing(attribute))
+    }
+
+    fn flag(&self, attribute: &str) -> Result<Option<bool>, XmlError> {
+        Ok(self.number::<Flag>(attribute)?.map(|flag| flag.0))
+    }
+
+    /// Reads an unsigned attribute that must fit in the given number of bits.
+    fn bounded(&self, attribute: &str, bits: u32) -> Result<Option<u64>, XmlError> {
+        let value = self.number::<u64>(attribute)?;
+        if let Some(value) = value
+            && value >> bits != 0
+        {
+            return Err(XmlError::new(format!(
+                "{attribute} {value} on <{}> exceeds {bits} bits",
+                self.name
+            )));
+        }
+        Ok(value)
+    }
+}
+
+fn parse_document(text: &str) -> Result<Element, XmlError> {
+    let mut parser = Parser {
+        text: text.trim_start_matches('\u{feff}'),
+        position: 0,
+        depth: 0,
+    };
+    parser.skip_misc()?;
+    let root = parser.element()?;
+    parser.skip_misc()?;
+    if !parser.rest().is_empty() {
+        return Err(XmlError::new("content after the root element"));
+    }
+    Ok(root)
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit_once(':').map_or(name, |(_, local)| local)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    /// Number of elements currently open
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skips to just after `end` and returns the text before it.
+    fn skip_past(&mut self, end: &str) -> Result<&'a str, XmlError> {
+        let rest = self.rest();
+        let index = rest
+            .find(end)
+            .ok_or_else(|| XmlError::new(format!("missing '{end}'")))?;
+        self.position += index + end.len();
+        Ok(&rest[..index])
+    }
+
+    /// Skips the declaration, comments, processing instructions and a DOCTYPE.
+    fn skip_misc(&mut self) -> Result<(), XmlError> {
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, XmlError> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return Err(XmlError::new(format!(
+                "expected a name at offset {}",
+                self.position
+            )));
+        }
+        self.position += end;
+        Ok(&rest[..end])
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), XmlError> {
+        if !self.rest().starts_with(token) {
+            return Err(XmlError::new(format!(
+                "expected '{token}' at offset {}",
+                self.position
+            )));
+        }
+        self.position += token.len();
+        Ok(())
+    }
+
+    fn element(&mut self) -> Result<Element, XmlError> {
+        self.expect("<")?;
+        let qualified = self.name()?;
+        let mut element = Element {
+            name: local_name(qualified).to_string(),
+            ..Element::default()
+        };
+
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            let name = self.name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = if self.rest().starts_with('\'') {
+                "'"
+            } else {
+                "\""
+            };
+            self.expect(quote)?;
+            let value = self.skip_past(quote)?;
+            if name != "xmlns" && !name.starts_with("xmlns:") {
+                element
+                    .attributes
+                    .push((local_name(name).to_string(), unescape(value)?));
+            }
+        }
+
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.position += 2;
+                let name = self.name()?;
+                if name != qualified {
+                    return Err(XmlError::new(format!(
+                        "expected </{qualified}>, found </{name}>"
+                    )));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(element);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.position += "<![CDATA[".len();
+                let text = self.skip_past("]]>")?;
+                element.text.push_str(text);
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with('<') {
+                if self.depth == MAX_DEPTH {
+                    return Err(XmlError::new(format!(
+                        "elements nested more than {MAX_DEPTH} deep"
+                    )));
+                }
+                self.depth += 1;
+                let child = self.element();
+                self.depth -= 1;
+                element.children.push(child?);
+            } else if rest.is_empty() {
+                return Err(XmlError::new(format!("unclosed element <{qualified}>")));
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                element.text.push_str(&unescape(&rest[..end])?);
+                self.position += end;
+            }
+        }
+    }
+}
+
+/// Replaces the predefined entities and character references.
+fn unescape(text: &str) -> Result<String, XmlError> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        let length = rest[start..]
+            .find(';')
+            .ok_or_else(|| XmlError::new("unterminated entity reference"))?;
+        let entity = &rest[start + 1..start + length];
+        let c = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(decimal) = entity.strip_prefix('#') {
+                    decimal.parse().ok()
+                } else {
+                    None
+                };
+                code.and_then(char::from_u32)
+                    .ok_or_else(|| XmlError::new(format!("unknown entity '&{entity};'")))?
+            }
+        };
+        unescaped.push(c);
+        rest = &rest[start + length + 1..];
+    }
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builders::{
+        SegmentationDescriptorBuilder, SpliceInfoSectionBuilder, SpliceInsertBuilder, Upid,
+    };
+    use crate::types::SegmentationType;
+    use std::time::Duration;
+
+    #[test]
+    fn test_round_trip() {
+        let insert = SpliceInsertBuilder::new(0x4800_008F)
+            .at_pts(Duration::from_secs(20))
+            .unwrap()
+            .duration(Duration::from_secs(30))
+            .build()
+            .unwrap();
+        let segmentation = SegmentationDescriptorBuilder::new(
+            7,
+            SegmentationType::ProviderPlacementOpportunityStart,
+        )
+        .duration(Duration::from_secs(30))
+        .unwrap()
+        .upid(Upid::Mid(vec![
+            Upid::AdId("ABCD0001000H".to_string()),
+            Upid::Uri("https://example.com/a?b&c".to_string()),
+        ]))
+        .unwrap()
+        .build()
+        .unwrap();
+        let section = SpliceInfoSectionBuilder::new()
+            .pts_adjustment(1234)
+            .splice_insert(insert)
+            .add_segmentation_descriptor(segmentation)
+            .build()
+            .unwrap();
+
+        let xml = to_xml(&section).unwrap();
+        assert!(xml.starts_with(r#"<SpliceInfoSection xmlns="http://www.scte.org/schemas/35""#));
+        assert_eq!(xml.matches("<SegmentationUpid ").count(), 2);
+
+        let read = from_xml(&xml).unwrap();
+        assert_eq!(read.splice_command, section.splice_command);
+        assert_eq!(read.splice_descriptors, section.splice_descriptors);
+        assert_eq!(read.pts_adjustment, 1234);
+        assert_eq!(to_xml(&read).unwrap(), xml);
+    }
+
+    #[test]
+    fn test_reads_prefixed_documents() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- from an ESAM response -->
+<scte35:SpliceInfoSection xmlns:scte35="http://www.scte.org/schemas/35" tier='4095'>
+  <scte35:TimeSignal>
+    <scte35:SpliceTime ptsTime="900000"/>
+  </scte35:TimeSignal>
+  <scte35:SegmentationDescriptor segmentationEventId="1" segmentationTypeId="52" segmentationDuration="2700000">
+    <scte35:SegmentationUpid segmentationUpidType="15"><![CDATA[68747470]]>3A2F2F</scte35:SegmentationUpid>
+  </scte35:SegmentationDescriptor>
+  <scte35:AvailDescriptor providerAvailId="&#49;2"/>
+</scte35:SpliceInfoSection>"#;
+
+        let section = from_xml(xml).unwrap();
+        assert_eq!(section.splice_command_type, 0x06);
+        let SpliceDescriptor::Segmentation(segmentation) = &section.splice_descriptors[0] else {
+            panic!("expected a segmentation descriptor");
+        };
+        assert_eq!(
+            segmentation.segmentation_type,
+            SegmentationType::ProviderPlacementOpportunityStart
+        );
+        assert_eq!(segmentation.segmentation_upid, b"http://");
+        assert!(segmentation.delivery_not_restricted_flag);
+        assert_eq!(
+            section.splice_descriptors[1].raw_bytes(),
+            Some(&[0x43, 0x55, 0x45, 0x49, 0, 0, 0, 12][..])
+        );
+
+        let signals = find_signals(xml).unwrap();
+        assert!(matches!(signals.as_slice(), [Signal::Section(_)]));
+    }
+
+    #[test]
+    fn test_errors() {
+        let reason = |xml: &str| from_xml(xml).unwrap_err().reason;
+        assert_eq!(reason("<Foo/>"), "no SpliceInfoSection element");
+        assert_eq!(reason("<SpliceInfoSection/>"), "missing splice command");
+        assert_eq!(
+            reason("<SpliceInfoSection><SpliceNull></SpliceInfoSection>"),
+            "expected </SpliceNull>, found </SpliceInfoSection>"
+        );
+        assert_eq!(
+            reason(r#"<SpliceInfoSection tier="4096"><SpliceNull/></SpliceInfoSection>"#),
+            "tier 4096 on <SpliceInfoSection> exceeds 12 bits"
+        );
+        assert_eq!(
+            reason(r#"<SpliceInfoSection><SpliceInsert spliceEventId="1"/></SpliceInfoSection>"#),
+            "<SpliceInsert> needs a <Program><SpliceTime> unless spliceImmediateFlag is set"
+        );
+        assert_eq!(
+            reason("<SpliceInfoSection><SpliceNull/><Foo/></SpliceInfoSection>"),
+            "unsupported element <Foo>"
+        );
+
+        let mut section = from_xml("<SpliceInfoSection><SpliceNull/></SpliceInfoSection>").unwrap();
+        section.encrypted_packet = 1;
+        assert_eq!(
+            to_xml(&section).unwrap_err().reason,
+            "an encrypted section has no SCTE-35 XML form"
+        );
+    }
+
+    #[test]
+    fn test_malformed_documents() {
+        let reason = |xml: &str| from_xml(xml).unwrap_err().reason;
+        assert_eq!(reason(""), "expected '<' at offset 0");
+        assert_eq!(
+            reason("<SpliceInfoSection><SpliceNull/>"),
+            "unclosed element <SpliceInfoSection>"
+        );
+        assert_eq!(
+            reason("<SpliceInfoSection><SpliceNull/></SpliceInfoSection><Extra/>"),
+            "content after the root element"
+        );
+        assert_eq!(
+            reason("<SpliceInfoSection tier=4095><SpliceNull/></SpliceInfoSection>"),
+            "expected '\"' at offset 24"
+        );
+        assert_eq!(
+            reason(r#"<SpliceInfoSection tier="4095><SpliceNull/></SpliceInfoSection>"#),
+            "missing '\"'"
+        );
+        assert_eq!(
+            reason("<SpliceInfoSection><!-- unterminated <SpliceNull/></SpliceInfoSection>"),
+            "missing '-->'"
+        );
+        assert_eq!(
+            reason("<SpliceInfoSection>< SpliceNull/></SpliceInfoSection>"),
+            "expected a name at offset 20"
+        );
+        assert_eq!(
+            reason(r#"<SpliceInfoSection tier="x"><SpliceNull/></SpliceInfoSection>"#),
+            "invalid tier 'x' on <SpliceInfoSection>"
+        );
+    }
+
+    #[test]
+    fn test_entities() {
+        assert_eq!(
+            unescape("&lt;a&gt; &amp; &quot;b&quot; &apos;c&apos; &#65;&#x42;").unwrap(),
+            "<a> & \"b\" 'c' AB"
+        );
+        // XML hex character references use a lower-case x only
+        assert_eq!(
+            unescape("&#X43;").unwrap_err().reason,
+            "unknown entity '&#X43;'"
+        );
+        assert_eq!(
+            unescape("&nbsp;").unwrap_err().reason,
+            "unknown entity '&nbsp;'"
+        );
+        assert_eq!(
+            unescape("&#xD800;").unwrap_err().reason,
+            "unknown entity '&#xD800;'"
+        );
+        assert_eq!(
+            unescape("a & b").unwrap_err().reason,
+            "unterminated entity reference"
+        );
+
+        let text = "https://example.com/?a=1&b=<2>\"'";
+        assert_eq!(unescape(&escape(text)).unwrap(), text);
+
+        // Entities are resolved in attribute values and element text alike
+        let section = from_xml(
+            r#"<SpliceInfoSection>
+  <PrivateCommand identifier="&#49;&#50;"><PrivateBytes>&#x41;B</PrivateBytes></PrivateCommand>
+</SpliceInfoSection>"#,
+        )
+        .unwrap();
+        let SpliceCommand::PrivateCommand(private) = &section.splice_command else {
+            panic!("expected a private command");
+        };
+        assert_eq!(private.private_command_id, 12);
+        assert_eq!(private.private_bytes, vec![0xAB]);
+    }
+
+    #[test]
+    fn test_cdata() {
+        let root = parse_document("<a><![CDATA[x < y & <b/>]]> &amp; <![CDATA[]]]]></a>").unwrap();
+        assert_eq!(root.text, "x < y & <b/> & ]]");
+        assert!(root.children.is_empty());
+
+        assert_eq!(
+            parse_document("<a><![CDATA[never closed</a>")
+                .unwrap_err()
+                .reason,
+            "missing ']]>'"
+        );
+    }
+
+    #[test]
+    fn test_nesting_limit() {
+        let nested = |depth: usize| format!("{}{}", "<a>".repeat(depth), "</a>".repeat(depth));
+        assert!(parse_document(&nested(MAX_DEPTH + 1)).is_ok());
+        assert_eq!(
+            parse_document(&nested(MAX_DEPTH + 2)).unwrap_err().reason,
+            "elements nested more than 64 deep"
+        );
+        // Deep enough to overflow the stack without the limit
+        assert!(find_signals(&nested(200_000)).is_err());
+    }
+
+    #[test]
+    fn test_rejects_what_has_no_xml_form() {
+        let reason = |xml: &str| from_xml(xml).unwrap_err().reason;
+        assert_eq!(
+            reason(r#"<SpliceInfoSection><SpliceSchedule/></SpliceInfoSection>"#),
+            "splice_schedule is not supported"
+        );
+        assert_eq!(
+            reason("<SpliceInfoSection><SpliceNull/><TimeSignal/></SpliceInfoSection>"),
+            "more than one splice command"
+        );
+        assert_eq!(
+            reason(
+                r#"<SpliceInfoSection><SpliceNull/>
+  <SegmentationDescriptor segmentationEventId="1" segmentationTypeId="52">
+    <SegmentationUpid segmentationUpidType="15" segmentationUpidFormat="text">x</SegmentationUpid>
+  </SegmentationDescriptor>
+</SpliceInfoSection>"#
+            ),
+            "segmentationUpidFormat 'text' is not supported"
+        );
+
+        let reason = |section: &SpliceInfoSection| to_xml(section).unwrap_err().reason;
+        let null = || from_xml("<SpliceInfoSection><SpliceNull/></SpliceInfoSection>").unwrap();
+
+        let mut section = null();
+        section.splice_command = SpliceCommand::Unknown;
+        assert_eq!(reason(&section), "Unknown has no SCTE-35 XML form");
+
+        let mut section = null();
+        section.splice_descriptors.push(SpliceDescriptor::Audio(
+            crate::descriptors::AudioDescriptor {
+                identifier: 0x4355_4549,
+                audio_components: vec![0],
+            },
+        ));
+        assert_eq!(reason(&section), "audio_descriptor has no SCTE-35 XML form");
+
+        let mut section = null();
+        section.splice_descriptors.push(SpliceDescriptor::Unknown {
+            tag: 0x10,
+            length: 4,
+            data: b"ABCD".to_vec(),
+        });
+        assert_eq!(
+            reason(&section),
+            "descriptor tag 0x10 has no SCTE-35 XML form"
+        );
+
+        let mut segmentation =
+            SegmentationDescriptorBuilder::new(1, SegmentationType::ProgramStart)
+                .build()
+                .unwrap();
+        segmentation.program_segmentation_flag = false;
+        let mut section = null();
+        section
+            .splice_descriptors
+            .push(SpliceDescriptor::Segmentation(segmentation));
+        assert_eq!(
+            reason(&section),
+            "component segmentation has no SCTE-35 XML form"
+        );
+    }
+}
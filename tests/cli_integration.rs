@@ -10,6 +10,10 @@ mod tests {
     const README_EXAMPLE_PAYLOAD: &str =
         "/DAvAAAAAAAA///wFAVIAACPf+/+c2nALv4AUsz1AAAAAAAKAAhDVUVJAAABNWLbowo=";
 
+    // Time signal with a MID UPID combining an Ad-ID and an EIDR
+    const MID_UPID_PAYLOAD: &str =
+        "/DA/AAAAAAAAAP/wAQZ/AC0CK0NVRUkAABI0f78NHAMMQUJDRDAwMDEwMDBICgwUeAAAEjRWeJq83vAwAQFcaXtu";
+
     #[test]
     fn test_cli_text_output_works() {
         let mut cmd = Command::cargo_bin("scte35").unwrap();
@@ -78,12 +82,649 @@ mod tests {
             .assert()
             .success()
             .stdout(predicate::str::contains(
-                "Parse SCTE-35 messages from base64-encoded payloads",
+                "Parse SCTE-35 messages from base64, hex or binary payloads",
             ))
-            .stdout(predicate::str::contains("Base64-encoded SCTE-35 payload"))
+            .stdout(predicate::str::contains("SCTE-35 payload"))
+            .stdout(predicate::str::contains("--input-format"))
+            .stdout(predicate::str::contains("--file"))
             .stdout(predicate::str::contains("Output format"));
     }
 
+    #[test]
+    fn test_cli_accepts_hex_and_url_safe_base64() {
+        let hex = "0xFC301600000000000000FFF00506FE423A35BD0000BB0C73F4";
+        for payload in [hex, "_DAWAAAAAAAAAP_wBQb-Qjo1vQAAuwxz9A"] {
+            Command::cargo_bin("scte35")
+                .unwrap()
+                .arg(payload)
+                .assert()
+                .success()
+                .stdout(predicate::str::contains("Splice Command: TimeSignal"));
+        }
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["--input-format", "hex", README_EXAMPLE_PAYLOAD])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Error decoding hex string"));
+    }
+
+    #[test]
+    fn test_cli_reads_file_and_stdin() {
+        let binary = data_encoding::BASE64
+            .decode(README_EXAMPLE_PAYLOAD.as_bytes())
+            .unwrap();
+        let path = std::env::temp_dir().join(format!("scte35-cli-{}.bin", std::process::id()));
+        std::fs::write(&path, &binary).unwrap();
+
+        let result = Command::cargo_bin("scte35")
+            .unwrap()
+            .arg("--file")
+            .arg(&path)
+            .assert();
+        std::fs::remove_file(&path).unwrap();
+        result
+            .success()
+            .stdout(predicate::str::contains("Splice Event ID: 0x4800008f"));
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .write_stdin(format!("{README_EXAMPLE_PAYLOAD}\n"))
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Splice Event ID: 0x4800008f"));
+    }
+
+    #[test]
+    fn test_cli_batch_ndjson_continues_past_failures() {
+        let input = format!("{README_EXAMPLE_PAYLOAD}\n\nnot a payload\n{MID_UPID_PAYLOAD}\n");
+        let output = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["-o", "ndjson"])
+            .write_stdin(input)
+            .output()
+            .unwrap();
+
+        assert!(!output.status.success(), "a failed line sets the exit code");
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let records: Vec<serde_json::Value> = stdout
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("each line should be JSON"))
+            .collect();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0]["line"], 1);
+        assert_eq!(records[0]["status"], "success");
+        assert_eq!(records[0]["data"]["splice_command"]["type"], "SpliceInsert");
+        assert_eq!(records[1]["line"], 3);
+        assert_eq!(records[1]["status"], "error");
+        assert_eq!(records[2]["line"], 4);
+        assert_eq!(records[2]["crc_validation"]["valid"], true);
+    }
+
+    #[test]
+    fn test_cli_batch_csv_columns() {
+        let input = format!("{README_EXAMPLE_PAYLOAD}\n{MID_UPID_PAYLOAD}\n");
+        let output = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["-o", "csv"])
+            .write_stdin(input.clone())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(
+            lines[0],
+            "line,status,event_id,command_type,pts,duration,segmentation_type,upid,crc,error"
+        );
+        assert_eq!(
+            lines[1],
+            "1,success,0x4800008f,SpliceInsert,1936310318,60.294,,,valid,"
+        );
+        assert!(
+            lines[2].starts_with("2,success,0x00001234,TimeSignal,,,Provider Advertisement Start,")
+        );
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["-o", "csv", "--columns", "event_id,crc"])
+            .write_stdin(input)
+            .assert()
+            .success()
+            .stdout("event_id,crc\n0x4800008f,valid\n0x00001234,valid\n");
+    }
+
+    #[test]
+    fn test_cli_batch_csv_reports_crc_of_unparsed_payloads() {
+        // The last CRC byte is changed, so the section fails to parse
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["-o", "csv", "--columns", "status,crc"])
+            .write_stdin("/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9Q==\nnot a payload\n")
+            .assert()
+            .stdout("status,crc\nerror,invalid\nerror,\n");
+    }
+
+    #[test]
+    fn test_cli_encode_round_trips_json_output() {
+        let json = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["-o", "json", README_EXAMPLE_PAYLOAD])
+            .output()
+            .unwrap();
+        assert!(json.status.success());
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .arg("encode")
+            .write_stdin(json.stdout)
+            .assert()
+            .success()
+            .stdout(format!("{README_EXAMPLE_PAYLOAD}\n"));
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .arg("encode")
+            .write_stdin("{\"table_id\": 252}")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Invalid splice_info_section"));
+    }
+
+    #[test]
+    fn test_cli_encode_time_signal_flags() {
+        let output = Command::cargo_bin("scte35")
+            .unwrap()
+            .args([
+                "encode",
+                "--time-signal",
+                "--pts",
+                "10s",
+                "--seg-type",
+                "0x34",
+                "--duration",
+                "30s",
+                "--upid",
+                "adid:ABCD0001000H",
+                "-o",
+                "hex",
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let hex = String::from_utf8(output.stdout).unwrap();
+        assert!(hex.starts_with("0xFC30"));
+
+        let output = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["-o", "json", hex.trim()])
+            .output()
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let data = &json["data"];
+        assert_eq!(data["splice_command"]["splice_time"]["pts_time"], 900_000);
+        let descriptor = &data["splice_descriptors"][0];
+        assert_eq!(descriptor["segmentation_type_id"], 0x34);
+        assert_eq!(descriptor["segmentation_duration"], 2_700_000);
+        assert_eq!(descriptor["upid_string"], "ABCD0001000H");
+        assert_eq!(json["crc_validation"]["valid"], true);
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["encode", "--time-signal", "--upid", "adid:bad"])
+            .assert()
+            .failure();
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args([
+                "encode",
+                "--time-signal",
+                "--seg-type",
+                "0x34",
+                "--upid",
+                "adid:bad",
+            ])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Error building message"));
+    }
+
+    #[test]
+    fn test_cli_explain_bit_level_dump() {
+        let output = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["explain", README_EXAMPLE_PAYLOAD])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+
+        let line = |name: &str| {
+            stdout
+                .lines()
+                .find(|line| line.split_whitespace().nth(2) == Some(name))
+                .unwrap_or_else(|| panic!("missing {name} in:\n{stdout}"))
+                .split_whitespace()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            line("table_id")[..5],
+            ["0", "8", "table_id", "11111100", "252"]
+        );
+        assert_eq!(line("splice_insert()")[..2], ["112", "160"]);
+        let pts = line("pts_time");
+        assert_eq!(pts[..3], ["167", "33", "pts_time"]);
+        assert!(pts.contains(&"21514.559089s"));
+        assert_eq!(line("CRC_32")[..2], ["368", "32"]);
+
+        // A corrupted CRC still produces the full dump, followed by the error
+        let mut buffer = data_encoding::BASE64
+            .decode(README_EXAMPLE_PAYLOAD.as_bytes())
+            .unwrap();
+        let last = buffer.len() - 1;
+        buffer[last] ^= 0xFF;
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["explain", &data_encoding::HEXUPPER.encode(&buffer)])
+            .assert()
+            .failure()
+            .stdout(predicate::str::contains("CRC_32"))
+            .stderr(predicate::str::contains("CRC validation failed"));
+    }
+
+    #[test]
+    fn test_cli_encode_rejects_oversized_tick_count() {
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["encode", "--time-signal", "--pts", "200000000000000t"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "tick count '200000000000000' is too large",
+            ));
+    }
+
+    #[test]
+    fn test_cli_diff_reports_field_changes() {
+        let encode = |pts: &str, upid: &str| {
+            let output = Command::cargo_bin("scte35")
+                .unwrap()
+                .args([
+                    "encode",
+                    "--time-signal",
+                    "--pts",
+                    pts,
+                    "--seg-type",
+                    "0x34",
+                ])
+                .args(["--event-id", "7", "--upid", upid])
+                .output()
+                .unwrap();
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        let old = encode("10s", "adid:ABCD0001000H");
+        let new = encode("12s", "adid:ABCD0002000H");
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["diff", &old, &new])
+            .assert()
+            .code(1)
+            .stdout(predicate::str::contains(
+                "~ splice_command.splice_time.pts_time: 900000 (10.000000s) -> 1080000 (12.000000s)",
+            ))
+            .stdout(predicate::str::contains(
+                "~ splice_descriptors[0].segmentation_upid: ABCD0001000H -> ABCD0002000H",
+            ))
+            .stdout(predicate::str::contains("crc_32"));
+
+        let output = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["diff", "--ignore-lengths", "-o", "json", &old, &new])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(1));
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["identical"], false);
+        let paths: Vec<_> = json["changes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|change| change["path"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "splice_command.splice_time.pts_time",
+                "splice_descriptors[0].segmentation_upid"
+            ]
+        );
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["diff", &old, &old])
+            .assert()
+            .success()
+            .stdout("");
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["diff", &old, "0xFC30"])
+            .assert()
+            .code(2)
+            .stderr(predicate::str::contains("Error parsing B"));
+    }
+
+    #[test]
+    fn test_cli_validate_rejects_empty_input() {
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .arg("validate")
+            .write_stdin("\n  \n")
+            .assert()
+            .code(2)
+            .stderr(predicate::str::contains("No payloads to validate"));
+    }
+
+    #[test]
+    fn test_cli_validate_fail_on_threshold() {
+        let output = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["encode", "--time-signal", "--seg-type", "0x24"])
+            .output()
+            .unwrap();
+        let deprecated = String::from_utf8(output.stdout).unwrap().trim().to_string();
+
+        // A deprecated segmentation type is a warning: it passes the default threshold
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["validate", &deprecated])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "payload 1: warning: splice_descriptors[0] [deprecated-segmentation-type]",
+            ));
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["validate", "--fail-on", "warning", &deprecated])
+            .assert()
+            .code(1);
+
+        let corrupted = "0xFC301600000000000000FFF00506FE423A35BD0000BB0C73F5";
+        let output = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["validate", "-o", "json", README_EXAMPLE_PAYLOAD, corrupted])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(1));
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["passed"], false);
+        assert_eq!(json["summary"]["inputs"], 2);
+        assert_eq!(json["summary"]["errors"], 1);
+        assert_eq!(json["inputs"][0]["issues"], serde_json::json!([]));
+        assert_eq!(json["inputs"][1]["issues"][0]["code"], "crc-mismatch");
+        assert_eq!(json["inputs"][1]["issues"][0]["severity"], "error");
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .arg("validate")
+            .write_stdin(format!("{README_EXAMPLE_PAYLOAD}\n\nnot a payload\n"))
+            .assert()
+            .code(1)
+            .stdout(predicate::str::contains("line 1: ok"))
+            .stdout(predicate::str::contains(
+                "line 3: error: input [decode-error]",
+            ))
+            .stdout(predicate::str::contains(
+                "2 inputs checked: 1 errors, 0 warnings, 0 info",
+            ));
+    }
+
+    /// Builds a 188-byte transport stream packet, padding with 0xFF.
+    fn ts_packet(pid: u16, counter: u8, adaptation: Option<&[u8]>, payload: &[u8]) -> Vec<u8> {
+        let control = if adaptation.is_some() { 0x30 } else { 0x10 };
+        let mut packet = vec![0x47, 0x40 | (pid >> 8) as u8, pid as u8, control | counter];
+        if let Some(adaptation) = adaptation {
+            packet.push(adaptation.len() as u8);
+            packet.extend_from_slice(adaptation);
+        }
+        packet.extend_from_slice(payload);
+        packet.resize(188, 0xFF);
+        packet
+    }
+
+    #[test]
+    fn test_cli_ts_lists_cues_and_stream_errors() {
+        let pat = [
+            0x00, 0x00, 0xB0, 0x0D, 0x00, 0x01, 0xC1, 0x00, 0x00, 0x00, 0x01, 0xF0, 0x00, 0, 0, 0,
+            0,
+        ];
+        // PMT: PCR on the AVC video PID 0x100, SCTE-35 (stream_type 0x86) on PID 0x1F4
+        let pmt = [
+            0x00, 0x02, 0xB0, 0x17, 0x00, 0x01, 0xC1, 0x00, 0x00, 0xE1, 0x00, 0xF0, 0x00, 0x1B,
+            0xE1, 0x00, 0xF0, 0x00, 0x86, 0xE1, 0xF4, 0xF0, 0x00, 0, 0, 0, 0,
+        ];
+        // PCR base 810000 (9s) and a PES header with PTS 900000 (10s)
+        let pcr = [0x10, 0x00, 0x06, 0x2E, 0x08, 0x7E, 0x00];
+        let pes = [
+            0x00, 0x00, 0x01, 0xE0, 0x00, 0x00, 0x80, 0x80, 0x05, 0x21, 0x00, 0x37, 0x77, 0x41,
+        ];
+        let mut section = vec![0x00];
+        section.extend(
+            data_encoding::BASE64
+                .decode(README_EXAMPLE_PAYLOAD.as_bytes())
+                .unwrap(),
+        );
+        let mut corrupted = section.clone();
+        *corrupted.last_mut().unwrap() ^= 0xFF;
+
+        let stream = [
+            ts_packet(0x0000, 0, None, &pat),
+            ts_packet(0x1000, 0, None, &pmt),
+            ts_packet(0x0100, 0, Some(&pcr), &pes),
+            ts_packet(0x01F4, 0, None, &section),
+            // Counter 1 is missing
+            ts_packet(0x01F4, 2, None, &corrupted),
+        ]
+        .concat();
+        let path = std::env::temp_dir().join(format!("scte35-cli-{}.ts", std::process::id()));
+        std::fs::write(&path, &stream).unwrap();
+
+        let text = Command::cargo_bin("scte35")
+            .unwrap()
+            .arg("ts")
+            .arg(&path)
+            .output()
+            .unwrap();
+        let json = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["ts", "-o", "json"])
+            .arg(&path)
+            .output()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(text.status.success());
+        let stdout = String::from_utf8(text.stdout).unwrap();
+        let rows: Vec<Vec<&str>> = stdout
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        assert_eq!(
+            rows[1][..7],
+            [
+                "3",
+                "564",
+                "0x01F4",
+                "10.000000s",
+                "9.000000s",
+                "splice_insert",
+                "@"
+            ]
+        );
+        assert!(
+            rows[1]
+                .join(" ")
+                .contains("out, event 0x4800008f, dur 60.3s")
+        );
+        assert_eq!(
+            rows[2][2..],
+            [
+                "0x01F4",
+                "-",
+                "-",
+                "continuity",
+                "error:",
+                "expected",
+                "1,",
+                "found",
+                "2"
+            ]
+        );
+        assert_eq!(rows[3][5..], ["CRC", "mismatch"]);
+        assert_eq!(
+            rows[4].join(" "),
+            "5 packets, 2 cues, 1 CRC failures, 1 continuity errors; SCTE-35 PIDs: 0x01F4"
+        );
+
+        let json: serde_json::Value = serde_json::from_slice(&json.stdout).unwrap();
+        assert_eq!(json["scte35_pids"], serde_json::json!([0x1F4]));
+        assert_eq!(json["cues"][0]["video_pts"], 900_000);
+        assert_eq!(json["cues"][0]["pcr"], 810_000 * 300);
+        assert_eq!(
+            json["cues"][0]["data"]["splice_command"]["type"],
+            "SpliceInsert"
+        );
+        assert_eq!(json["cues"][1]["crc_valid"], false);
+        assert_eq!(json["errors"][0]["type"], "continuity");
+    }
+
+    #[test]
+    fn test_cli_hls_timeline() {
+        let hex = data_encoding::HEXUPPER.encode(
+            &data_encoding::BASE64
+                .decode(README_EXAMPLE_PAYLOAD.as_bytes())
+                .unwrap(),
+        );
+        // The DATERANGE declares 30s but the splice_insert carries a 60.294s break_duration
+        let playlist = format!(
+            "#EXTM3U
+#EXT-X-TARGETDURATION:6
+#EXT-X-MEDIA-SEQUENCE:10
+#EXT-X-PROGRAM-DATE-TIME:2024-01-01T00:00:00Z
+#EXTINF:6.0,
+seg10.ts
+#EXT-X-DATERANGE:ID=\"splice-1\",START-DATE=\"2024-01-01T00:00:06Z\",DURATION=30.0,SCTE35-OUT=0x{hex}
+#EXT-X-CUE-OUT:30
+#EXTINF:6.0,
+seg11.ts
+#EXTINF:6.0,
+seg12.ts
+#EXT-X-CUE-IN
+#EXTINF:6.0,
+seg13.ts
+"
+        );
+        let path = std::env::temp_dir().join(format!("scte35-cli-{}.m3u8", std::process::id()));
+        std::fs::write(&path, playlist).unwrap();
+
+        let text = Command::cargo_bin("scte35")
+            .unwrap()
+            .arg("hls")
+            .arg(&path)
+            .output()
+            .unwrap();
+        let json = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["hls", "-o", "json"])
+            .arg(&path)
+            .output()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(text.status.success());
+        let stdout = String::from_utf8(text.stdout).unwrap();
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert!(lines[1].starts_with(
+            "      11       6.000s  2024-01-01T00:00:06Z     EXT-X-DATERANGE SCTE35-OUT   id=splice-1 duration=30.000s splice_insert @ 21514.559s: out, event 0x4800008f, dur 60.3s"
+        ));
+        assert_eq!(
+            lines[2].trim(),
+            "! tag duration 30.000s does not match decoded break_duration 60.294s"
+        );
+        assert_eq!(
+            lines[4],
+            "      13      18.000s  2024-01-01T00:00:18Z     EXT-X-CUE-IN"
+        );
+        assert_eq!(lines[5].trim(), "break length 12.000s (declared 30.000s)");
+        assert_eq!(lines[6], "3 cues, 1 duration mismatches");
+
+        let json: serde_json::Value = serde_json::from_slice(&json.stdout).unwrap();
+        let cues = json["cues"].as_array().unwrap();
+        assert_eq!(cues[0]["duration_mismatch"], true);
+        assert_eq!(cues[0]["data"]["splice_command"]["type"], "SpliceInsert");
+        assert_eq!(cues[1]["tag"], "EXT-X-CUE-OUT");
+        assert_eq!(cues[1]["status"], serde_json::Value::Null);
+        assert_eq!(cues[2]["break"]["length"], 12.0);
+        assert_eq!(json["summary"]["duration_mismatches"], 1);
+    }
+
+    #[test]
+    fn test_cli_convert_between_formats() {
+        let time_signal = "/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==";
+
+        // Line-oriented input is streamed; bad lines are reported and skipped
+        let hls = Command::cargo_bin("scte35")
+            .unwrap()
+            .args([
+                "convert",
+                "--from",
+                "base64",
+                "--to",
+                "hls",
+                "--start-date",
+                "2024-01-01T00:00:00Z",
+            ])
+            .write_stdin(format!("{README_EXAMPLE_PAYLOAD}\nnot base64!\n"))
+            .output()
+            .unwrap();
+        assert_eq!(hls.status.code(), Some(1));
+        let stdout = String::from_utf8(hls.stdout).unwrap();
+        assert!(stdout.starts_with(
+            "#EXT-X-DATERANGE:ID=\"1207959695\",START-DATE=\"2024-01-01T00:00:00Z\",PLANNED-DURATION=60.294,SCTE35-OUT=0xFC302F"
+        ));
+        assert_eq!(stdout.lines().count(), 1);
+        let stderr = String::from_utf8(hls.stderr).unwrap();
+        assert!(stderr.starts_with("line 2: Invalid base64 input:"));
+        assert!(stderr.contains("1 of 2 inputs could not be converted"));
+
+        let xml = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["convert", "--from", "base64", "--to", "xml", time_signal])
+            .output()
+            .unwrap();
+        assert!(xml.status.success());
+        let xml = String::from_utf8(xml.stdout).unwrap();
+        assert!(xml.contains(r#"<SpliceTime ptsTime="1111111101"/>"#));
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["convert", "--from", "xml", "--to", "base64"])
+            .write_stdin(xml)
+            .assert()
+            .success()
+            .stdout(format!("{time_signal}\n"));
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["convert", "--from", "hls", "--to", "hex"])
+            .write_stdin("#EXTM3U\n#EXT-X-CUE-IN\n")
+            .assert()
+            .code(1)
+            .stderr("No cues found in hls input\n");
+    }
+
     #[test]
     fn test_cli_version_works() {
         let mut cmd = Command::cargo_bin("scte35").unwrap();
@@ -126,4 +767,84 @@ mod tests {
                 .contains("Error decoding base64 string")
         );
     }
+
+    #[test]
+    fn test_cli_renders_mid_upid_members() {
+        let mut cmd = Command::cargo_bin("scte35").unwrap();
+        let output = cmd
+            .arg(MID_UPID_PAYLOAD)
+            .output()
+            .expect("Failed to execute CLI command");
+
+        assert!(output.status.success(), "CLI command should succeed");
+        let stdout = String::from_utf8(output.stdout).expect("Output should be valid UTF-8");
+        assert!(stdout.contains("UPID: MID (2 members)"));
+        assert!(stdout.contains("[0] Ad Identifier (0x03): ABCD0001000H"));
+        assert!(stdout.contains(
+            "[1] EIDR (Entertainment Identifier Registry) (0x0a): 10.5240/0000-1234-5678-9ABC-DEF0-X"
+        ));
+
+        let mut cmd = Command::cargo_bin("scte35").unwrap();
+        let output = cmd
+            .args(["-o", "json", MID_UPID_PAYLOAD])
+            .output()
+            .expect("Failed to execute CLI command");
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+        let members = &json["data"]["splice_descriptors"][0]["mid_upids"];
+        assert_eq!(members[0]["upid_type"]["value"], 3);
+        assert_eq!(members[0]["upid_string"], "ABCD0001000H");
+        assert_eq!(members[1]["upid_type"]["value"], 10);
+        assert_eq!(
+            members[1]["upid_string"],
+            "10.5240/0000-1234-5678-9ABC-DEF0-X"
+        );
+    }
+
+    #[test]
+    fn test_cli_summary_mode() {
+        let time_signal = "/DAWAAAAAAAAAP/wBQb+Qjo1vQAAuwxz9A==";
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["--summary", README_EXAMPLE_PAYLOAD])
+            .assert()
+            .success()
+            .stdout("splice_insert @ 21514.559s: out, event 0x4800008f, dur 60.3s\n");
+
+        // One line per payload; failures are reported in place and set the exit code
+        let output = Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["-s", "-v", "--color", "never"])
+            .write_stdin(format!("{time_signal}\n\nnot a cue\n"))
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(1));
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(lines[0], "time_signal @ 12345.679s");
+        assert_eq!(lines[1], "  crc 0xbb0c73f4 valid");
+        assert!(lines.last().unwrap().starts_with("line 3: Error"));
+        assert!(!stdout.contains('\x1b'));
+
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .args(["-s", "--color", "always", time_signal])
+            .assert()
+            .success()
+            .stdout("\x1b[1mtime_signal @ 12345.679s\x1b[0m\n");
+
+        // Field dump names command and segmentation types next to their numbers
+        Command::cargo_bin("scte35")
+            .unwrap()
+            .arg(time_signal)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Splice Command Type: 0x06 (TimeSignal)",
+            ))
+            .stdout(predicate::str::contains(
+                "PTS Time: 1111111101 (12345.678900s)",
+            ));
+    }
 }